@@ -0,0 +1,117 @@
+//! Times `parse_bytes` on the fixtures and on large synthetic trees, and
+//! record decoding on its own. Run with `cargo bench`; pass a name to only
+//! run the benchmarks containing it, e.g. `cargo bench -- records`.
+//!
+//! There is no benchmark framework in the dependency tree, so each benchmark
+//! runs for a fixed time and reports the mean time per iteration.
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use common::{rec, vsrn, Builder, Data};
+use ds_store_parser::{Decode, DsStoreParser};
+
+const WARM_UP: Duration = Duration::from_millis(200);
+const MEASURE: Duration = Duration::from_secs(1);
+
+fn bench(filter: Option<&str>, name: &str, mut f: impl FnMut()) {
+    if filter.is_some_and(|filter| !name.contains(filter)) {
+        return;
+    }
+
+    let start = Instant::now();
+    while start.elapsed() < WARM_UP {
+        f();
+    }
+
+    let mut iterations = 0u32;
+    let start = Instant::now();
+    while start.elapsed() < MEASURE {
+        f();
+        iterations += 1;
+    }
+
+    let per_iteration = start.elapsed() / iterations;
+    println!("{:<32} {:>12.2?}/iter ({} iterations)", name, per_iteration, iterations);
+}
+
+// Entries the way Finder describes a folder in icon view: a location and a
+// comment for some of them.
+fn folder(entries: usize) -> Vec<common::Rec> {
+    let mut records = Vec::new();
+
+    for i in 0..entries {
+        let name = format!("IMG_{:05}.jpg", i);
+        let mut iloc = Vec::new();
+        iloc.extend_from_slice(&(i as i32 % 10 * 100).to_be_bytes());
+        iloc.extend_from_slice(&(i as i32 / 10 * 100).to_be_bytes());
+        iloc.extend_from_slice(&[0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00]);
+
+        records.push(rec(&name, b"Iloc", Data::Blob(iloc)));
+        if i % 4 == 0 {
+            records.push(rec(&name, b"cmmt", Data::Ustr(format!("Comment on {}", name))));
+        }
+        records.push(vsrn(&name));
+    }
+
+    records
+}
+
+fn main() {
+    // `cargo bench` passes `--bench`; anything else is a name filter.
+    let filter = std::env::args().skip(1).find(|arg| !arg.starts_with("--"));
+    let filter = filter.as_deref();
+    let parser = DsStoreParser::new();
+
+    let fixtures: [(&str, &[u8]); 3] = [
+        ("parse/small", include_bytes!("../tests/fixtures/icon_view.DS_Store")),
+        ("parse/medium", include_bytes!("../tests/fixtures/list_view.DS_Store")),
+        ("parse/nested", include_bytes!("../tests/fixtures/nested.DS_Store")),
+    ];
+
+    for (name, buf) in fixtures {
+        bench(filter, name, || {
+            black_box(parser.parse_bytes(black_box(buf)).unwrap());
+        });
+    }
+
+    // A thousand entries, 2250 records in 66 pages of a two level tree.
+    let large = Builder::new().records(folder(1000)).per_node(64).build();
+    bench(filter, "parse/large", || {
+        black_box(parser.parse_bytes(black_box(&large)).unwrap());
+    });
+
+    let names_only = DsStoreParser::new().with_decode(Decode::Names);
+    bench(filter, "parse/large/names", || {
+        black_box(names_only.parse_bytes(black_box(&large)).unwrap());
+    });
+
+    // The last entry, so every node is read.
+    bench(filter, "contains/large", || {
+        assert!(black_box(parser.contains(black_box(&large), "IMG_00999.jpg").unwrap()));
+    });
+
+    // The records of a single leaf follow its mode and record count, and
+    // block addresses don't count the 4 byte file prefix.
+    let single_leaf = Builder::new().records(folder(200)).build();
+    let record_count = parser.parse_bytes_with_report(&single_leaf).unwrap().1.record_count;
+    let root_node = parser.read_master_block(&single_leaf).unwrap().root_node as usize;
+    let address = parser.read_buddy_header(&single_leaf).unwrap().block_addresses[root_node];
+    let first_record = (address as usize & !0x1f) + 4 + 8;
+
+    bench(filter, "records/decode", || {
+        let mut offset = first_record;
+        for _ in 0..record_count {
+            let (record, end) = parser.read_record(black_box(&single_leaf), offset).unwrap();
+            black_box(record);
+            offset = end;
+        }
+    });
+
+    bench(filter, "records/recover", || {
+        black_box(parser.recover(black_box(&single_leaf)));
+    });
+}
@@ -0,0 +1,20 @@
+#![no_main]
+
+use ds_store_parser::DsStoreParser;
+use libfuzzer_sys::fuzz_target;
+
+// Any input has to produce a result or an error, never a panic.
+fuzz_target!(|data: &[u8]| {
+    let parser = DsStoreParser::new();
+
+    if let Ok(ds_store) = parser.parse_bytes(data) {
+        for (_, record) in ds_store.iter() {
+            let _ = record.window_settings();
+            let _ = record.icon_view_settings();
+            let _ = record.list_view_settings();
+        }
+    }
+
+    let _ = parser.parse_lenient(data);
+    let _ = parser.recover(data);
+});
@@ -0,0 +1,92 @@
+#!/usr/bin/env python3
+# Writes src/unicode_tables.rs from the Unicode database that ships with
+# Python. Only the Basic Multilingual Plane is covered, and Hangul syllables
+# are composed algorithmically instead. Case folding follows str.casefold,
+# i.e. the C and F mappings of CaseFolding.txt.
+
+import sys
+import unicodedata
+
+
+def rust_char(cp):
+    return "'\\u{%04x}'" % cp
+
+
+def rust_str(value):
+    return '"%s"' % "".join("\\u{%04x}" % ord(c) for c in value)
+
+
+def main():
+    lines = [
+        "// Generated by scripts/unicode_tables.py from Unicode %s. Do not edit."
+        % unicodedata.unidata_version,
+        "",
+        "// Canonical combining classes of the Basic Multilingual Plane as",
+        "// (first, last, class) ranges. Everything else has class 0.",
+        "pub(crate) const COMBINING_CLASSES: &[(char, char, u8)] = &[",
+    ]
+
+    ranges = []
+    for cp in range(0x80, 0x10000):
+        if 0xD800 <= cp <= 0xDFFF:
+            continue
+        ccc = unicodedata.combining(chr(cp))
+        if not ccc:
+            continue
+        if ranges and ranges[-1][1] == cp - 1 and ranges[-1][2] == ccc:
+            ranges[-1][1] = cp
+        else:
+            ranges.append([cp, cp, ccc])
+
+    for first, last, ccc in ranges:
+        lines.append("    (%s, %s, %d)," % (rust_char(first), rust_char(last), ccc))
+
+    lines += [
+        "];",
+        "",
+        "// Canonical decompositions as (character, first, second, composes),",
+        "// sorted by character. `second` is NUL for singletons, and `composes`",
+        "// is false for characters NFC never produces.",
+        "pub(crate) const DECOMPOSITIONS: &[(char, char, char, bool)] = &[",
+    ]
+
+    for cp in range(0x80, 0x10000):
+        if 0xD800 <= cp <= 0xDFFF or 0xAC00 <= cp <= 0xD7A3:
+            continue
+        decomposition = unicodedata.decomposition(chr(cp))
+        if not decomposition or decomposition.startswith("<"):
+            continue
+        parts = [int(part, 16) for part in decomposition.split()]
+        if len(parts) == 1:
+            parts.append(0)
+        composes = parts[1] != 0 and unicodedata.normalize(
+            "NFC", chr(parts[0]) + chr(parts[1])
+        ) == chr(cp)
+        lines.append(
+            "    (%s, %s, %s, %s),"
+            % (rust_char(cp), rust_char(parts[0]), rust_char(parts[1]),
+               "true" if composes else "false")
+        )
+
+    lines += [
+        "];",
+        "",
+        "// Full case folding as (character, folded), sorted by character, e.g.",
+        "// `ß` to `ss` and both sigmas to `σ`. Characters not listed fold to",
+        "// themselves.",
+        "pub(crate) const CASE_FOLDING: &[(char, &str)] = &[",
+    ]
+
+    for cp in range(0x41, 0x10000):
+        if 0xD800 <= cp <= 0xDFFF:
+            continue
+        folded = chr(cp).casefold()
+        if folded != chr(cp):
+            lines.append("    (%s, %s)," % (rust_char(cp), rust_str(folded)))
+
+    lines.append("];")
+    sys.stdout.write("\n".join(lines) + "\n")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,64 @@
+// AppleDouble files (`._name`) hold the Finder metadata macOS can't store
+// natively on the target filesystem. They are easily mistaken for DS_Store
+// files, so the parser recognizes them to report a precise error.
+
+const MAGIC: u32 = 0x0005_1607;
+const FINDER_INFO: u32 = 9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AppleDoubleEntry {
+    pub id: u32,
+    pub offset: u32,
+    pub length: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppleDouble {
+    pub version: u32,
+    pub entries: Vec<AppleDoubleEntry>,
+}
+
+fn u32_at(buf: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(buf.get(offset..offset + 4)?.try_into().unwrap()))
+}
+
+impl AppleDouble {
+    // The header is the magic, a version, 16 filler bytes and the entry
+    // count, followed by 12 byte entry descriptors.
+    pub fn parse(buf: &[u8]) -> Option<Self> {
+        if u32_at(buf, 0)? != MAGIC {
+            return None;
+        }
+
+        let version = u32_at(buf, 4)?;
+        let count = u16::from_be_bytes(buf.get(24..26)?.try_into().unwrap()) as usize;
+        let mut entries = Vec::new();
+
+        for i in 0..count {
+            let base = 26 + i * 12;
+            entries.push(
+                AppleDoubleEntry {
+                    id: u32_at(buf, base)?,
+                    offset: u32_at(buf, base + 4)?,
+                    length: u32_at(buf, base + 8)?,
+                }
+            );
+        }
+
+        Some(Self { version, entries })
+    }
+
+    // The file type and creator codes from the Finder info entry.
+    pub fn type_and_creator(&self, buf: &[u8]) -> Option<([u8; 4], [u8; 4])> {
+        let entry = self.entries.iter().find(|e| e.id == FINDER_INFO)?;
+
+        if entry.length < 8 {
+            return None;
+        }
+
+        let start = entry.offset as usize;
+        let info = buf.get(start..start + 8)?;
+
+        Some((info[0..4].try_into().unwrap(), info[4..8].try_into().unwrap()))
+    }
+}
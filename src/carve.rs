@@ -0,0 +1,104 @@
+// Best-effort parsing of B-tree nodes carved out of a disk image without the
+// file header or the allocator block. Nodes are found by scanning, so stale
+// copies and fragments of other files can show up next to current records.
+
+use crate::ds_store::DsStore;
+use crate::error::DsStoreError;
+use crate::parser::{decompressed, DsStoreParser};
+use crate::record::{Record, Source};
+use crate::report::{ParseReport, Warning, WarningCode};
+
+// The name of the root of a carved tree, in place of the `DSDB` the table
+// of contents would give it.
+pub const RECONSTRUCTED: &str = "(reconstructed)";
+
+// Internal nodes start with the id of their rightmost child. Files with
+// anywhere near this many blocks don't exist.
+const MAX_BLOCK_ID: u32 = 0xffff;
+
+fn u32_at(buf: &[u8], offset: usize) -> Option<u32> {
+    let bytes = buf.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_be_bytes(bytes.try_into().unwrap()))
+}
+
+impl DsStoreParser {
+    // Parses `buf` without looking for the signature, header or allocator:
+    // every stretch that reads as a node full of plausible records is taken
+    // as one, and its records become the children of a root named
+    // `RECONSTRUCTED`. Fails only if no node is found.
+    pub fn parse_carved(&self, buf: &[u8]) -> Result<(DsStore, ParseReport), DsStoreError> {
+        let buf = &*decompressed(buf)?;
+
+        if buf.is_empty() {
+            return Err(DsStoreError::Empty);
+        }
+
+        let mut root = DsStore::new(RECONSTRUCTED);
+        let mut report = ParseReport::default();
+        let mut offset = 0;
+
+        while offset + 8 <= buf.len() {
+            match self.carve_node(buf, offset) {
+                Some((records, end)) => {
+                    debug!("Carved node at 0x{:x} with {} records", offset, records.len());
+                    report.node_count += 1;
+                    report.bytes_parsed += end - offset;
+                    root.add_records(records);
+                    offset = end;
+                }
+                None => offset += 1,
+            }
+        }
+
+        if report.node_count == 0 {
+            return Err(DsStoreError::NothingCarved { length: buf.len() });
+        }
+
+        report.record_count = root.children().len();
+        report.allocated_length = buf.len();
+        report.warnings.push(
+            Warning::new(
+                WarningCode::Reconstructed,
+                format!(
+                    "Reconstructed from {} B-tree nodes found without a file header; some \
+                     records may be stale",
+                    report.node_count
+                )
+            )
+        );
+
+        Ok((root, report))
+    }
+
+    // The records of the node at `offset` and its end, if it has at least one
+    // record and all of them look like something Finder wrote.
+    fn carve_node(&self, buf: &[u8], offset: usize) -> Option<(Vec<Record>, usize)> {
+        let mode = u32_at(buf, offset)?;
+        let count = u32_at(buf, offset + 4)? as usize;
+
+        // The smallest record is 12 bytes, plus the child id in internal nodes.
+        if mode > MAX_BLOCK_ID || count == 0 || count > (buf.len() - offset) / 12 {
+            return None;
+        }
+
+        let mut records = Vec::with_capacity(count);
+        let mut cursor = offset + 8;
+
+        for _ in 0..count {
+            if mode != 0 {
+                cursor += 4;
+            }
+
+            match self.read_record(buf, cursor) {
+                Ok((mut record, next)) if Self::plausible(buf, cursor, &record) => {
+                    record.source = Source::Carved;
+                    records.push(record);
+                    cursor = next;
+                }
+                _ => return None,
+            }
+        }
+
+        Some((records, cursor))
+    }
+}
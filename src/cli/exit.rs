@@ -0,0 +1,28 @@
+use ds_store_parser::DsStoreError;
+
+// 2 is taken by clap for usage errors.
+pub const ERROR: u8 = 1;
+pub const NOT_DS_STORE: u8 = 3;
+pub const NOT_FOUND: u8 = 4;
+
+pub const HELP: &str = "\
+Exit status:
+  0  at least one file was parsed
+  1  no file could be read or parsed, or the output failed
+  2  invalid command line usage
+  3  no file could be parsed, and the first is not a DS_Store file
+  4  no file has the entry given to --contains
+
+Files that fail are reported and skipped. The status of the first one is
+only used when every file failed.";
+
+pub fn code(e: &DsStoreError) -> u8 {
+    match e {
+        DsStoreError::BadSignature { .. }
+        | DsStoreError::BadMagic { .. }
+        | DsStoreError::Empty
+        | DsStoreError::TooShort { .. }
+        | DsStoreError::AppleDouble { .. } => NOT_DS_STORE,
+        _ => ERROR,
+    }
+}
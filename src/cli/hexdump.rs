@@ -0,0 +1,51 @@
+const WIDTH: usize = 16;
+const CONTEXT: usize = 64;
+
+// A `hexdump -C` style dump of the bytes around `offset`, with the row
+// holding it marked by `>`. Offsets past the end show the last bytes of
+// the input instead.
+pub fn around(buf: &[u8], offset: usize) -> String {
+    let mut dump = String::new();
+
+    if buf.is_empty() {
+        return dump;
+    }
+
+    let start = match offset < buf.len() {
+        true => offset.saturating_sub(CONTEXT / 2) / WIDTH * WIDTH,
+        false => ((buf.len() - 1) / WIDTH * WIDTH).saturating_sub(CONTEXT - WIDTH),
+    };
+    let end = (start + CONTEXT).min(buf.len());
+
+    for row in (start..end).step_by(WIDTH) {
+        let bytes = &buf[row..(row + WIDTH).min(end)];
+
+        let hex = bytes
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let ascii = bytes
+            .iter()
+            .map(|&b| match b.is_ascii_graphic() || b == b' ' {
+                true => b as char,
+                false => '.',
+            })
+            .collect::<String>();
+
+        let marker = match (row..row + WIDTH).contains(&offset) {
+            true => '>',
+            false => ' ',
+        };
+
+        dump.push_str(&format!("{} {:08x}  {:<47}  |{}|\n", marker, row, hex, ascii));
+    }
+
+    if offset >= buf.len() {
+        dump.push_str(
+            &format!("  offset 0x{:x} is past the end of the input at 0x{:x}\n", offset, buf.len())
+        );
+    }
+
+    dump
+}
@@ -0,0 +1,5 @@
+pub mod exit;
+pub mod hexdump;
+pub mod jobs;
+pub mod terminal;
+pub mod walk;
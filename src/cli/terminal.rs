@@ -0,0 +1,51 @@
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+mod sys {
+    use std::ffi::c_ulong;
+
+    #[cfg(target_os = "linux")]
+    const TIOCGWINSZ: c_ulong = 0x5413;
+    #[cfg(target_os = "macos")]
+    const TIOCGWINSZ: c_ulong = 0x4008_7468;
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct WinSize {
+        rows: u16,
+        columns: u16,
+        x_pixels: u16,
+        y_pixels: u16,
+    }
+
+    unsafe extern "C" {
+        fn ioctl(fd: i32, request: c_ulong, ...) -> i32;
+    }
+
+    pub fn columns() -> Option<usize> {
+        let mut size = WinSize::default();
+
+        // SAFETY: TIOCGWINSZ only writes a `struct winsize` through the
+        // pointer, which `WinSize` mirrors.
+        let result = unsafe { ioctl(1, TIOCGWINSZ, &mut size as *mut WinSize) };
+
+        match (result, size.columns) {
+            (0, columns) if columns > 0 => Some(columns as usize),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+mod sys {
+    pub fn columns() -> Option<usize> {
+        None
+    }
+}
+
+// The width of the terminal stdout is attached to, preferring `COLUMNS` if
+// set. Returns `None` when output goes to a file or pipe.
+pub fn width() -> Option<usize> {
+    match std::env::var("COLUMNS").ok().and_then(|c| c.parse().ok()) {
+        Some(columns) => Some(columns),
+        None => sys::columns(),
+    }
+}
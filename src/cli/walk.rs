@@ -0,0 +1,65 @@
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+// Collects every file named `.DS_Store` below `root`, sorted by path so the
+// results for one directory stay together. Directories that can't be read
+// are reported and skipped; symlinks are never followed.
+pub fn find_ds_store_files(root: &Path) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                eprintln!("WARNING: Skipping {}: {}.", dir.display(), e);
+                continue;
+            }
+        };
+
+        for entry in entries {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    eprintln!("WARNING: Skipping entry in {}: {}.", dir.display(), e);
+                    continue;
+                }
+            };
+
+            let file_type = match entry.file_type() {
+                Ok(file_type) => file_type,
+                Err(_) => continue,
+            };
+
+            if file_type.is_dir() {
+                pending.push(entry.path());
+            } else if file_type.is_file() && entry.file_name() == ".DS_Store" {
+                found.push(entry.path());
+            }
+        }
+    }
+
+    found.sort();
+    found
+}
+
+// The directory holding `file` relative to `base`, or as given if it isn't
+// below `base`. Components are joined with `/` on every platform, and the
+// result is empty for files directly in `base`.
+pub fn relative_dir(file: &Path, base: &Path) -> String {
+    let dir = file.parent().unwrap_or(Path::new(""));
+    let dir = dir.strip_prefix(base).unwrap_or(dir);
+
+    let mut parts = Vec::new();
+
+    for component in dir.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_string_lossy()),
+            Component::ParentDir => parts.push("..".into()),
+            Component::RootDir => parts.push("".into()),
+            Component::CurDir | Component::Prefix(_) => {}
+        }
+    }
+
+    parts.join("/")
+}
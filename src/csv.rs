@@ -0,0 +1,49 @@
+use std::io::{self, Write};
+
+use crate::ds_store::DsStore;
+use crate::record::value_to_string;
+
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn write_csv_header<W: Write>(w: &mut W, with_file: bool) -> io::Result<()> {
+    if with_file {
+        write!(w, "file,")?;
+    }
+
+    writeln!(w, "path,structure_id,data_type,value,source")
+}
+
+impl DsStore {
+    pub fn write_csv<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write_csv_header(w, false)?;
+        self.write_csv_rows(w, None)
+    }
+
+    // Writes the records without a header, prefixing each row with `file`
+    // when rows from several DS_Store files end up in the same table.
+    pub fn write_csv_rows<W: Write>(&self, w: &mut W, file: Option<&str>) -> io::Result<()> {
+        for (path, record) in self.iter() {
+            if let Some(file) = file {
+                write!(w, "{},", escape(file))?;
+            }
+
+            writeln!(
+                w,
+                "{},{},{},{},{}",
+                escape(&path),
+                escape(&record.structure_id_str()),
+                escape(&String::from_utf8_lossy(record.data_type().tag())),
+                escape(&value_to_string(record.value())),
+                record.source().as_str(),
+            )?;
+        }
+
+        Ok(())
+    }
+}
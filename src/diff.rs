@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::ds_store::{DsStore, FindOptions};
+use crate::record::{value_to_string, Value};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Added { path: String, structure_id: [u8; 4], value: Value },
+    Removed { path: String, structure_id: [u8; 4], value: Value },
+    Changed { path: String, structure_id: [u8; 4], old: Value, new: Value },
+}
+
+// Records keyed by path, as compared under `options`, and structure id,
+// next to the path as stored. Should a file hold the same key twice, the
+// last record wins.
+fn flatten(
+    ds_store: &DsStore,
+    options: FindOptions
+) -> BTreeMap<(String, [u8; 4]), (String, &Value)> {
+    ds_store
+        .iter()
+        .map(|(path, record)| {
+            ((options.normalize(&path), record.structure_id()), (path, record.value()))
+        })
+        .collect()
+}
+
+impl DsStore {
+    // Lists the records added, removed or changed in `other` compared to
+    // `self`, ordered by path and structure id.
+    pub fn diff(&self, other: &DsStore) -> Vec<Change> {
+        self.diff_with(other, FindOptions::default())
+    }
+
+    // Like `diff`, but paths equal under the name comparison of `options`
+    // are the same entry, e.g. `README.md` and `readme.md` with
+    // `ignore_case`. Changes show the path as stored in `other`, or in
+    // `self` for removed records. `substring` is ignored.
+    pub fn diff_with(&self, other: &DsStore, options: FindOptions) -> Vec<Change> {
+        let old = flatten(self, options);
+        let new = flatten(other, options);
+        let mut changes = Vec::new();
+
+        let mut keys = old.keys().chain(new.keys()).collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+
+        for key in keys {
+            let structure_id = key.1;
+
+            match (old.get(key), new.get(key)) {
+                (Some((_, old)), Some((path, new))) if old != new => changes.push(
+                    Change::Changed {
+                        path: path.clone(),
+                        structure_id,
+                        old: (*old).clone(),
+                        new: (*new).clone(),
+                    }
+                ),
+                (Some((path, old)), None) => changes.push(
+                    Change::Removed { path: path.clone(), structure_id, value: (*old).clone() }
+                ),
+                (None, Some((path, new))) => changes.push(
+                    Change::Added { path: path.clone(), structure_id, value: (*new).clone() }
+                ),
+                _ => {}
+            }
+        }
+
+        changes
+    }
+}
+
+// One line per change, marked with `+`, `-` or `~`.
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added { path, structure_id, value } => write!(
+                f,
+                "+ {} [{}] {}",
+                path,
+                String::from_utf8_lossy(structure_id),
+                value_to_string(value)
+            ),
+            Self::Removed { path, structure_id, value } => write!(
+                f,
+                "- {} [{}] {}",
+                path,
+                String::from_utf8_lossy(structure_id),
+                value_to_string(value)
+            ),
+            Self::Changed { path, structure_id, old, new } => write!(
+                f,
+                "~ {} [{}] {} -> {}",
+                path,
+                String::from_utf8_lossy(structure_id),
+                value_to_string(old),
+                value_to_string(new)
+            ),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt::Write;
+
+use crate::ds_store::DsStore;
+
+fn escape(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+impl DsStore {
+    // Renders the tree as a Graphviz digraph. Nodes with children are drawn
+    // as boxes, leaf entries as ellipses.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph ds_store {\n");
+        let mut stack = vec![(self, None::<usize>)];
+        let mut next_id = 0;
+
+        while let Some((node, parent)) = stack.pop() {
+            let id = next_id;
+            next_id += 1;
+
+            let shape = match node.children.is_empty() && parent.is_some() {
+                true => "ellipse",
+                false => "box",
+            };
+            let _ = writeln!(out, "    n{} [label=\"{}\", shape={}];", id, escape(&node.name), shape);
+
+            if let Some(parent) = parent {
+                let _ = writeln!(out, "    n{} -> n{};", parent, id);
+            }
+
+            for child in node.children.iter().rev() {
+                stack.push((child, Some(id)));
+            }
+        }
+
+        out.push_str("}\n");
+        out
+    }
+}
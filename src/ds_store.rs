@@ -0,0 +1,585 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::hash::{BuildHasher, RandomState};
+
+use anstyle::{AnsiColor, Style};
+
+use crate::normalize::{case_fold, nfc};
+use crate::record::{is_known_structure_id, Record, Value};
+
+// Blobs `DsStore::redact_names` keeps: fixed layouts of coordinates, window
+// rectangles, background colors, dates and sizes that can't hold a name.
+const REDACTED_BLOBS_KEPT: &[&[u8; 4]] = &[
+    b"BKGD", b"Iloc", b"fwi0", b"lg1S", b"logS", b"moDD", b"modD", b"ph1S", b"phyS",
+];
+
+pub struct DsStore {
+    pub(crate) name: String,
+    pub(crate) children: Vec<DsStore>,
+    pub(crate) records: Vec<Record>,
+    pub(crate) indent_length: usize,
+}
+
+// How `DsStore::find_with` and `DsStore::diff_with` compare entry names.
+// The default is an exact, case sensitive match. `nfc` treats composed and
+// decomposed forms of the same characters as equal, e.g. the `é` of
+// `café.txt` written as one character or as `e` and a combining accent.
+// `ignore_case` compares the Unicode case folding of names.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FindOptions {
+    pub substring: bool,
+    pub ignore_case: bool,
+    pub nfc: bool,
+}
+
+impl FindOptions {
+    // The form of `name` that is compared. Folding comes first because it
+    // can leave decomposed characters, e.g. `ǰ` folds to `j` and a caron.
+    pub fn normalize(&self, name: &str) -> String {
+        let name = match self.ignore_case {
+            true => case_fold(name),
+            false => name.to_string(),
+        };
+
+        match self.nfc {
+            true => nfc(&name),
+            false => name,
+        }
+    }
+
+    pub fn matches(&self, name: &str, pattern: &str) -> bool {
+        let (name, pattern) = (self.normalize(name), self.normalize(pattern));
+
+        match self.substring {
+            true => name.contains(&pattern),
+            false => name == pattern,
+        }
+    }
+}
+
+// Controls what `DsStore::display` adds next to each entry name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DisplayOptions {
+    pub show_ids: bool,
+    pub show_offsets: bool,
+    pub show_sources: bool,
+    // Draw `tree` style connectors instead of indenting with spaces.
+    pub connectors: bool,
+    // Entries below this level are left out and their parents marked with
+    // `…`. The root's children are at level 1.
+    pub max_depth: Option<usize>,
+    // Color names with ANSI escapes: folders bold blue, recovered entries
+    // dim red.
+    pub color: bool,
+}
+
+pub struct Display<'a> {
+    ds_store: &'a DsStore,
+    options: DisplayOptions,
+}
+
+pub struct RecordIter<'a> {
+    stack: Vec<(String, &'a DsStore)>,
+    current: Option<(String, std::slice::Iter<'a, Record>)>,
+}
+
+impl DsStore {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            children: vec![],
+            records: vec![],
+            indent_length: 4,
+        }
+    }
+
+    pub(crate) fn from_record(record: Record) -> Self {
+        Self {
+            name: record.name.clone(),
+            children: vec![],
+            records: vec![record],
+            indent_length: 4,
+        }
+    }
+
+    pub fn push_child(&mut self, child: DsStore) {
+        self.children.push(child);
+    }
+
+    // Nests the entries of `subtree`, typically parsed from the `.DS_Store`
+    // inside the folder `name`, below this node's first child named `name`.
+    // A child is added if no record names the folder.
+    pub fn graft(&mut self, name: &str, subtree: DsStore) {
+        let index = match self.children.iter().position(|c| c.name == name) {
+            Some(index) => index,
+            None => {
+                let mut child = Self::new(name);
+                child.indent_length = self.indent_length;
+                self.children.push(child);
+                self.children.len() - 1
+            }
+        };
+
+        self.children[index].children.extend(subtree.children);
+    }
+
+    // Appends one child per record, e.g. for records returned by
+    // `DsStoreParser::recover`.
+    pub fn add_records(&mut self, records: Vec<Record>) {
+        for record in records {
+            let mut child = Self::from_record(record);
+            child.indent_length = self.indent_length;
+            self.children.push(child);
+        }
+    }
+
+    // Keeps only the records matching `keep`. Nodes left without records or
+    // children are removed.
+    pub fn retain_records<F: FnMut(&Record) -> bool>(&mut self, mut keep: F) {
+        self.retain_records_with(&mut keep);
+    }
+
+    // Keeps only the recovered records of entries without any live record,
+    // i.e. files that were deleted rather than merely rewritten.
+    pub fn retain_deleted(&mut self) {
+        let live = self
+            .iter()
+            .filter(|(_, r)| !r.is_recovered())
+            .map(|(_, r)| r.name.clone())
+            .collect::<BTreeSet<_>>();
+
+        self.retain_records(|r| r.is_recovered() && !live.contains(&r.name));
+    }
+
+    fn retain_records_with<F: FnMut(&Record) -> bool>(&mut self, keep: &mut F) {
+        self.records.retain(|r| keep(r));
+
+        for child in self.children.iter_mut() {
+            child.retain_records_with(keep);
+        }
+
+        self.children.retain(|c| !c.records.is_empty() || !c.children.is_empty());
+    }
+
+    // Places the entries below `prefix`, e.g. the directory the file was
+    // found in: `a.txt` becomes `prefix/a.txt` and the folder's own `.`
+    // entry becomes `prefix`. Record names are left as stored.
+    pub fn prefix_paths(&mut self, prefix: &str) {
+        let prefix = prefix.trim_end_matches('/');
+
+        if prefix.is_empty() {
+            return;
+        }
+
+        for child in self.children.iter_mut() {
+            child.name = match child.name.as_str() {
+                "." => prefix.into(),
+                name => format!("{}/{}", prefix, name),
+            };
+        }
+    }
+
+    // Replaces every entry name below the root with a hash of it, keeping
+    // structure ids and the shape of the tree. Equal names get equal hashes,
+    // but the key is random and thrown away afterwards, so the names can't be
+    // brute forced from the output. The folder's own `.` entry is kept.
+    //
+    // Text values are hashed the same way, since `ptbN` and `ptbL` hold the
+    // name and location a trashed file came from and comments can mention
+    // anything. Records with unknown structure ids are dropped, as there's no
+    // telling what their payload holds, and so are blobs other than those in
+    // `REDACTED_BLOBS_KEPT`: `pict` aliases and the `bwsp`, `icvp` and `lsvp`
+    // plists embed volume names and absolute paths.
+    pub fn redact_names(&mut self) {
+        let key = RandomState::new();
+        let redact = |name: &mut String| {
+            if name != "." {
+                *name = format!("{:016x}", key.hash_one(name.as_str()));
+            }
+        };
+        let redact_records = |records: &mut Vec<Record>| {
+            records.retain(|r| match &r.value {
+                Value::Unknown { .. } => false,
+                Value::Blob(_) => REDACTED_BLOBS_KEPT.contains(&&r.structure_id),
+                _ => is_known_structure_id(&r.structure_id),
+            });
+
+            for record in records.iter_mut() {
+                redact(&mut record.name);
+
+                if let Value::Ustr(value) = &mut record.value {
+                    redact(value);
+                }
+            }
+        };
+
+        let mut stack = self.children.iter_mut().collect::<Vec<_>>();
+
+        while let Some(node) = stack.pop() {
+            redact(&mut node.name);
+            redact_records(&mut node.records);
+            stack.extend(node.children.iter_mut());
+        }
+
+        redact_records(&mut self.records);
+    }
+
+    // Merges siblings with the same name, so each entry has one node holding
+    // all of its records. The parser puts every record on a node of its own.
+    // Merged nodes keep the position of the first one.
+    pub fn group_records(&mut self) {
+        let mut stack = vec![self];
+
+        while let Some(node) = stack.pop() {
+            let mut index = HashMap::<String, usize>::new();
+            let mut grouped = Vec::<DsStore>::with_capacity(node.children.len());
+
+            for mut child in node.children.drain(..) {
+                match index.get(&child.name) {
+                    Some(&i) => {
+                        grouped[i].records.append(&mut child.records);
+                        grouped[i].children.append(&mut child.children);
+                    }
+                    None => {
+                        index.insert(child.name.clone(), grouped.len());
+                        grouped.push(child);
+                    }
+                }
+            }
+
+            node.children = grouped;
+            stack.extend(node.children.iter_mut());
+        }
+    }
+
+    // Rebuilds the tree from the `/` separated components of every entry
+    // name, so `a/b` ends up below an `a` node, which is created if no
+    // record names it. Records with the same name share one node, and
+    // entries keep the order they first appear in.
+    pub fn into_implied_tree(self) -> DsStore {
+        let indent_length = self.indent_length;
+
+        // Nodes are built in an arena first, where children always come
+        // after their parents.
+        let mut nodes = vec![Some(Self::new(&self.name))];
+        let mut child_lists = vec![Vec::<usize>::new()];
+        let mut index = HashMap::<(usize, String), usize>::new();
+
+        let mut stack = vec![self];
+        let mut records = Vec::new();
+
+        while let Some(mut node) = stack.pop() {
+            records.append(&mut node.records);
+            stack.extend(node.children.into_iter().rev());
+        }
+
+        for record in records {
+            let mut components = record.name
+                .split('/')
+                .filter(|c| !c.is_empty())
+                .map(String::from)
+                .collect::<Vec<_>>();
+
+            // Names like `` or `/` have no components but are still entries.
+            if components.is_empty() {
+                components.push(record.name.clone());
+            }
+
+            let mut parent = 0;
+            for component in components {
+                parent = match index.get(&(parent, component.clone())) {
+                    Some(&child) => child,
+                    None => {
+                        let child = nodes.len();
+                        nodes.push(Some(Self::new(&component)));
+                        child_lists.push(vec![]);
+                        child_lists[parent].push(child);
+                        index.insert((parent, component), child);
+                        child
+                    }
+                };
+            }
+
+            if let Some(node) = nodes[parent].as_mut() {
+                node.records.push(record);
+            }
+        }
+
+        // Assemble from the back so every node's children are complete
+        // before it is moved into its parent.
+        for i in (0..nodes.len()).rev() {
+            let children = child_lists[i]
+                .iter()
+                .filter_map(|&c| nodes[c].take())
+                .collect();
+
+            if let Some(node) = nodes[i].as_mut() {
+                node.children = children;
+                node.indent_length = indent_length;
+            }
+        }
+
+        nodes[0].take().unwrap()
+    }
+
+    // Whether this entry is a folder, as far as the tree shows: entries
+    // with children are folders, everything else is taken to be a file.
+    pub fn is_folder(&self) -> bool {
+        !self.children.is_empty()
+    }
+
+    // Sets the indentation used per tree level for this node and everything
+    // below it.
+    pub fn set_indent_length(&mut self, indent_length: usize) {
+        let mut stack = vec![self];
+
+        while let Some(node) = stack.pop() {
+            node.indent_length = indent_length;
+            stack.extend(node.children.iter_mut());
+        }
+    }
+
+    // Sorts the children of every node by name, ignoring case. Entries with
+    // equal names keep their on-disk order.
+    pub fn sort_children(&mut self) {
+        let mut stack = vec![self];
+
+        while let Some(node) = stack.pop() {
+            node.children.sort_by_cached_key(|c| c.name.to_lowercase());
+            stack.extend(node.children.iter_mut());
+        }
+    }
+
+    pub fn indent_length(&self) -> usize {
+        self.indent_length
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn children(&self) -> &[DsStore] {
+        &self.children
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    // Yields every record in document order along with the path of the node
+    // holding it. The root's own name is not part of the path.
+    pub fn iter(&self) -> RecordIter<'_> {
+        let mut stack = Vec::new();
+
+        for child in self.children.iter().rev() {
+            stack.push((child.name.clone(), child));
+        }
+
+        RecordIter {
+            stack,
+            current: Some((String::new(), self.records.iter())),
+        }
+    }
+
+    // Calls `f` for every node in pre-order: a node comes before its
+    // children, and siblings are visited in stored order. The path holds the
+    // names leading to the node, so the root is visited with an empty one.
+    pub fn walk(&self, f: &mut impl FnMut(&[&str], &DsStore)) {
+        let mut path = Vec::<&str>::new();
+        let mut stack = vec![(self, 0)];
+
+        while let Some((node, depth)) = stack.pop() {
+            if depth > 0 {
+                path.truncate(depth - 1);
+                path.push(&node.name);
+            }
+
+            f(&path, node);
+
+            for child in node.children.iter().rev() {
+                stack.push((child, depth + 1));
+            }
+        }
+    }
+
+    // Every record whose entry name is exactly `name`.
+    pub fn find(&self, name: &str) -> Vec<&Record> {
+        self.find_with(name, FindOptions::default())
+    }
+
+    pub fn find_with(&self, pattern: &str, options: FindOptions) -> Vec<&Record> {
+        self.iter()
+            .map(|(_, record)| record)
+            .filter(|record| options.matches(&record.name, pattern))
+            .collect()
+    }
+
+    // Unique record paths in the order they first appear.
+    pub fn paths(&self) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let mut paths = Vec::new();
+
+        for (path, _) in self.iter() {
+            if seen.insert(path.clone()) {
+                paths.push(path);
+            }
+        }
+
+        paths
+    }
+
+    // Every entry name in the document, live or recovered, sorted and
+    // without repeats.
+    pub fn unique_names(&self) -> BTreeSet<String> {
+        self.iter()
+            .map(|(_, record)| record.name.clone())
+            .collect()
+    }
+
+    pub fn print(&self) {
+        print!("{}", self);
+    }
+
+    pub fn display(&self, options: DisplayOptions) -> Display<'_> {
+        Display { ds_store: self, options }
+    }
+
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: DisplayOptions) -> fmt::Result {
+        if options.max_depth == Some(0) && !self.children.is_empty() {
+            return writeln!(f, "{} …", self.name);
+        }
+
+        writeln!(f, "{}", self.name)?;
+
+        // Walk with an explicit stack so deeply nested trees can't overflow
+        // the call stack. Each entry carries the prefix its children inherit,
+        // whether it is the last of its siblings and its level.
+        let mut stack = Vec::<(&DsStore, String, bool, usize)>::new();
+        self.push_children(&mut stack, String::new(), 1);
+
+        while let Some((node, prefix, last, depth)) = stack.pop() {
+            let (lead, child_prefix) = match (options.connectors, last) {
+                (false, _) => {
+                    let indent = prefix.len() + self.indent_length;
+                    (format!("{:1$}", "", indent), format!("{:1$}", "", indent))
+                }
+                (true, false) => (format!("{}├── ", prefix), format!("{}│   ", prefix)),
+                (true, true) => (format!("{}└── ", prefix), format!("{}    ", prefix)),
+            };
+
+            let truncated = options.max_depth == Some(depth) && !node.children.is_empty();
+            node.render_line(f, &lead, options, truncated)?;
+
+            if !truncated {
+                node.push_children(&mut stack, child_prefix, depth + 1);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push_children<'a>(
+        &'a self,
+        stack: &mut Vec<(&'a DsStore, String, bool, usize)>,
+        prefix: String,
+        depth: usize
+    ) {
+        for (i, child) in self.children.iter().enumerate().rev() {
+            stack.push((child, prefix.clone(), i + 1 == self.children.len(), depth));
+        }
+    }
+
+    fn render_line(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        lead: &str,
+        options: DisplayOptions,
+        truncated: bool
+    ) -> fmt::Result {
+        let recovered = !self.records.is_empty() && self.records.iter().all(Record::is_recovered);
+
+        let style = match (options.color, recovered, self.is_folder()) {
+            (false, _, _) => Style::new(),
+            (true, true, _) => Style::new().fg_color(Some(AnsiColor::Red.into())).dimmed(),
+            (true, false, true) => Style::new().fg_color(Some(AnsiColor::Blue.into())).bold(),
+            (true, false, false) => Style::new(),
+        };
+
+        write!(f, "{}", lead)?;
+        write!(f, "{}{}{}", style.render(), self.name, style.render_reset())?;
+
+        if options.show_ids && !self.records.is_empty() {
+            let ids = self.records
+                .iter()
+                .map(|r| r.structure_id_str())
+                .collect::<Vec<_>>();
+            write!(f, " [{}]", ids.join(", "))?;
+        }
+
+        if options.show_offsets && !self.records.is_empty() {
+            let offsets = self.records
+                .iter()
+                .map(|r| format!("0x{:x}", r.offset()))
+                .collect::<Vec<_>>();
+            write!(f, " @ {}", offsets.join(", "))?;
+        }
+
+        if options.show_sources && !self.records.is_empty() {
+            let sources = self.records
+                .iter()
+                .map(|r| r.source().as_str())
+                .collect::<Vec<_>>();
+            write!(f, " from {}", sources.join(", "))?;
+        }
+
+        if recovered {
+            write!(f, " (recovered)")?;
+        }
+
+        match (truncated, self.children.is_empty() || options.connectors) {
+            (true, _) => writeln!(f, " …"),
+            (false, true) => writeln!(f),
+            (false, false) => writeln!(f, ":")
+        }
+    }
+}
+
+// Renders the indented tree. The alternate form (`{:#}`) also lists the
+// structure ids of each entry's records.
+impl fmt::Display for DsStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = DisplayOptions {
+            show_ids: f.alternate(),
+            ..DisplayOptions::default()
+        };
+        self.render(f, options)
+    }
+}
+
+impl fmt::Display for Display<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.ds_store.render(f, self.options)
+    }
+}
+
+impl<'a> Iterator for RecordIter<'a> {
+    type Item = (String, &'a Record);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((path, records)) = self.current.as_mut()
+                && let Some(record) = records.next()
+            {
+                return Some((path.clone(), record));
+            }
+
+            let (path, node) = self.stack.pop()?;
+
+            for child in node.children.iter().rev() {
+                self.stack.push((format!("{}/{}", path, child.name), child));
+            }
+
+            self.current = Some((path, node.records.iter()));
+        }
+    }
+}
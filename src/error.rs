@@ -0,0 +1,221 @@
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum DsStoreError {
+    BadSignature { offset: usize, expected: u8, found: u8 },
+    BadMagic { offset: usize, expected: Vec<u8>, found: Vec<u8> },
+    Empty,
+    TooShort { length: usize },
+    Truncated { length: usize },
+    AppleDouble { entries: usize },
+    InvalidGzip { reason: &'static str },
+    InvalidZip { reason: &'static str },
+    OffsetOutOfRange { offset: usize },
+    BlockOutOfRange { block_id: usize, offset: usize, size: usize },
+    RootOffsetMismatch { a: usize, b: usize },
+    InvalidRootOffset { offset: usize },
+    TooManyBlocks { count: usize, max: usize },
+    InvalidUtf8,
+    InvalidName { offset: usize, name: String },
+    TreeCycle { offset: usize },
+    TreeTooDeep { offset: usize },
+    UnknownBlock { block_id: usize },
+    MissingDsdb,
+    UnknownDataType { offset: usize, tag: [u8; 4] },
+    DuplicateRecord { name: String, structure_id: [u8; 4] },
+    TooLargeToWrite { size: usize, max: usize },
+    NotDecoded { name: String, structure_id: [u8; 4] },
+    TrailingData { length: usize, allocated_length: usize },
+    NothingCarved { length: usize },
+    Io(io::Error),
+}
+
+impl fmt::Display for DsStoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadSignature { offset, expected, found } => write!(
+                f,
+                "Leading word does not match a DS_Store file: expected byte 0x{:02x} at offset {}, got 0x{:02x}",
+                expected, offset, found
+            ),
+            Self::BadMagic { offset, expected, found } => write!(
+                f,
+                "Input does not have the {:?} magic of a DS_Store file, found {:?} (first \
+                 difference at offset {})",
+                String::from_utf8_lossy(expected), String::from_utf8_lossy(found), offset
+            ),
+            Self::Empty => write!(f, "Input is empty"),
+            Self::Truncated { length } => write!(
+                f,
+                "Input of {} bytes ends inside the file header",
+                length
+            ),
+            Self::TooShort { length } => write!(
+                f,
+                "Input of {} bytes is shorter than the file signature",
+                length
+            ),
+            Self::AppleDouble { entries } => write!(
+                f,
+                "Input is an AppleDouble file with {} entries, not a DS_Store file",
+                entries
+            ),
+            Self::InvalidGzip { reason } => write!(
+                f,
+                "Input looks gzip-compressed but could not be decompressed: {}",
+                reason
+            ),
+            Self::InvalidZip { reason } => write!(
+                f,
+                "Failed to read zip archive: {}",
+                reason
+            ),
+            Self::OffsetOutOfRange { offset } => write!(
+                f,
+                "Failed to parse block at offset 0x{:x}. Offset out of range",
+                offset
+            ),
+            Self::BlockOutOfRange { block_id, offset, size } => write!(
+                f,
+                "Block {} at offset 0x{:x} (size 0x{:x}) lies outside the file",
+                block_id, offset, size
+            ),
+            Self::RootOffsetMismatch { a, b } => write!(
+                f,
+                "Root block offsets do not match: 0x{:x} != 0x{:x}",
+                a, b
+            ),
+            Self::InvalidRootOffset { offset } => write!(
+                f,
+                "Root block offset 0x{:x} points into the file header",
+                offset
+            ),
+            Self::TooManyBlocks { count, max } => write!(
+                f,
+                "Allocator lists {} blocks but the file only has room for {} block addresses",
+                count, max
+            ),
+            Self::InvalidUtf8 => write!(f, "Root node name contains illegal UTF-8 sequence"),
+            Self::InvalidName { offset, name } => write!(
+                f,
+                "Record at offset 0x{:x} has a name that is not valid UTF-16: {:?}",
+                offset, name
+            ),
+            Self::TreeCycle { offset } => write!(
+                f,
+                "B-tree node at offset 0x{:x} is referenced more than once",
+                offset
+            ),
+            Self::TreeTooDeep { offset } => write!(
+                f,
+                "B-tree node at offset 0x{:x} is nested too deeply",
+                offset
+            ),
+            Self::UnknownBlock { block_id } => write!(
+                f,
+                "Block {} is referenced but not allocated",
+                block_id
+            ),
+            Self::MissingDsdb => write!(f, "Table of contents has no DSDB entry"),
+            Self::UnknownDataType { offset, tag } => write!(
+                f,
+                "Unknown record data type {:?} at offset 0x{:x}",
+                String::from_utf8_lossy(tag), offset
+            ),
+            Self::DuplicateRecord { name, structure_id } => write!(
+                f,
+                "Entry {:?} has more than one {} record",
+                name, String::from_utf8_lossy(structure_id)
+            ),
+            Self::TooLargeToWrite { size, max } => write!(
+                f,
+                "Records take {} bytes, more than the {} that fit into a single B-tree node",
+                size, max
+            ),
+            Self::NotDecoded { name, structure_id } => write!(
+                f,
+                "The {} record of {:?} was not decoded and can't be written",
+                String::from_utf8_lossy(structure_id), name
+            ),
+            Self::TrailingData { length, allocated_length } => write!(
+                f,
+                "Input has {} bytes after the end of the last allocated block at 0x{:x}",
+                length - allocated_length, allocated_length
+            ),
+            Self::NothingCarved { length } => write!(
+                f,
+                "No B-tree node found in {} bytes of carved data",
+                length
+            ),
+            Self::Io(e) => write!(f, "I/O error: {}", e),
+        }
+    }
+}
+
+impl DsStoreError {
+    // The name of the variant, e.g. `OffsetOutOfRange`, for tools that
+    // handle some errors differently.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::BadSignature { .. } => "BadSignature",
+            Self::BadMagic { .. } => "BadMagic",
+            Self::Empty => "Empty",
+            Self::TooShort { .. } => "TooShort",
+            Self::Truncated { .. } => "Truncated",
+            Self::AppleDouble { .. } => "AppleDouble",
+            Self::InvalidGzip { .. } => "InvalidGzip",
+            Self::InvalidZip { .. } => "InvalidZip",
+            Self::OffsetOutOfRange { .. } => "OffsetOutOfRange",
+            Self::BlockOutOfRange { .. } => "BlockOutOfRange",
+            Self::RootOffsetMismatch { .. } => "RootOffsetMismatch",
+            Self::InvalidRootOffset { .. } => "InvalidRootOffset",
+            Self::TooManyBlocks { .. } => "TooManyBlocks",
+            Self::InvalidUtf8 => "InvalidUtf8",
+            Self::InvalidName { .. } => "InvalidName",
+            Self::TreeCycle { .. } => "TreeCycle",
+            Self::TreeTooDeep { .. } => "TreeTooDeep",
+            Self::UnknownBlock { .. } => "UnknownBlock",
+            Self::MissingDsdb => "MissingDsdb",
+            Self::UnknownDataType { .. } => "UnknownDataType",
+            Self::DuplicateRecord { .. } => "DuplicateRecord",
+            Self::TooLargeToWrite { .. } => "TooLargeToWrite",
+            Self::NotDecoded { .. } => "NotDecoded",
+            Self::TrailingData { .. } => "TrailingData",
+            Self::NothingCarved { .. } => "NothingCarved",
+            Self::Io(_) => "Io",
+        }
+    }
+
+    // The offset in the input the error points at, if it has one.
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Self::BadSignature { offset, .. }
+            | Self::BadMagic { offset, .. }
+            | Self::OffsetOutOfRange { offset }
+            | Self::BlockOutOfRange { offset, .. }
+            | Self::InvalidRootOffset { offset }
+            | Self::InvalidName { offset, .. }
+            | Self::TreeCycle { offset }
+            | Self::TreeTooDeep { offset }
+            | Self::UnknownDataType { offset, .. } => Some(*offset),
+            Self::TrailingData { allocated_length, .. } => Some(*allocated_length),
+            _ => None,
+        }
+    }
+}
+
+impl std::error::Error for DsStoreError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for DsStoreError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
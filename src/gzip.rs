@@ -0,0 +1,74 @@
+// Gzip (RFC 1952) decoding for archived captures.
+
+use crate::error::DsStoreError;
+use crate::inflate::{crc32, inflate};
+
+const MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+const FHCRC: u8 = 0x02;
+const FEXTRA: u8 = 0x04;
+const FNAME: u8 = 0x08;
+const FCOMMENT: u8 = 0x10;
+
+pub(crate) fn is_gzip(buf: &[u8]) -> bool {
+    buf.starts_with(&MAGIC)
+}
+
+fn invalid(reason: &'static str) -> DsStoreError {
+    DsStoreError::InvalidGzip { reason }
+}
+
+pub(crate) fn decompress(buf: &[u8]) -> Result<Vec<u8>, DsStoreError> {
+    if buf.len() < 18 || !is_gzip(buf) {
+        return Err(invalid("missing gzip header"));
+    }
+
+    if buf[2] != 8 {
+        return Err(invalid("unsupported compression method"));
+    }
+
+    let flags = buf[3];
+    let mut cursor = 10;
+
+    if flags & FEXTRA != 0 {
+        let length = match buf.get(cursor..cursor + 2) {
+            Some(length) => u16::from_le_bytes([length[0], length[1]]) as usize,
+            None => return Err(invalid("truncated header")),
+        };
+        cursor += 2 + length;
+    }
+
+    for flag in [FNAME, FCOMMENT] {
+        if flags & flag != 0 {
+            match buf.get(cursor..).and_then(|rest| rest.iter().position(|b| *b == 0)) {
+                Some(end) => cursor += end + 1,
+                None => return Err(invalid("truncated header")),
+            }
+        }
+    }
+
+    if flags & FHCRC != 0 {
+        cursor += 2;
+    }
+
+    let data = match buf.get(cursor..) {
+        Some(data) => data,
+        None => return Err(invalid("truncated header")),
+    };
+
+    let (out, position) = inflate(data).map_err(invalid)?;
+
+    let trailer = match data.get(position..position + 8) {
+        Some(trailer) => trailer,
+        None => return Err(invalid("missing trailer")),
+    };
+
+    let crc = u32::from_le_bytes(trailer[0..4].try_into().unwrap());
+    let size = u32::from_le_bytes(trailer[4..8].try_into().unwrap());
+
+    if crc32(&out) != crc || out.len() as u32 != size {
+        return Err(invalid("checksum mismatch"));
+    }
+
+    Ok(out)
+}
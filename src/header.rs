@@ -0,0 +1,46 @@
+// The fixed 36 byte header at the start of every file. Offsets are relative
+// to the end of the 4 byte file prefix, which is part of `magic`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bud1Header {
+    pub magic: [u8; 8],
+    pub root_block_offset: u32,
+    pub root_block_size: u32,
+    // Must repeat `root_block_offset`.
+    pub root_block_offset_check: u32,
+    pub unknown: [u8; 16],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TocEntry {
+    pub name: String,
+    pub block_id: u32,
+}
+
+// The buddy allocator's bookkeeping stored in the root block: the address of
+// every allocated block, the table of contents naming well known blocks and
+// one free list per block size (index n holds free blocks of 2^n bytes).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuddyHeader {
+    pub block_addresses: Vec<u32>,
+    pub toc: Vec<TocEntry>,
+    pub free_lists: Vec<Vec<u32>>,
+}
+
+impl BuddyHeader {
+    pub fn toc_block(&self, name: &str) -> Option<u32> {
+        self.toc
+            .iter()
+            .find(|e| e.name == name)
+            .map(|e| e.block_id)
+    }
+}
+
+// The `DSDB` master block describing the records B-tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MasterBlock {
+    pub root_node: u32,
+    pub levels: u32,
+    pub records: u32,
+    pub nodes: u32,
+    pub page_size: u32,
+}
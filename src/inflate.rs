@@ -0,0 +1,323 @@
+// DEFLATE (RFC 1951) decompression shared by the gzip and zip readers.
+
+// DS_Store files are small. Anything that inflates past this is not one and
+// would otherwise let a tiny input exhaust memory.
+const MAX_OUTPUT: usize = 256 << 20;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31,
+    35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2,
+    3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193,
+    257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6,
+    7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13,
+];
+// The order code length code lengths are stored in.
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+// Inflates a raw DEFLATE stream, returning the data and the number of input
+// bytes the stream took up.
+pub(crate) fn inflate(data: &[u8]) -> Result<(Vec<u8>, usize), &'static str> {
+    let mut inflater = Inflater {
+        bits: BitReader { data, position: 0, bit: 0 },
+        out: Vec::new(),
+    };
+    inflater.inflate()?;
+
+    let consumed = inflater.bits.byte_position();
+    Ok((inflater.out, consumed))
+}
+
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xedb8_8320,
+                _ => crc >> 1,
+            };
+        }
+    }
+
+    !crc
+}
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    bit: u8,
+}
+
+impl BitReader<'_> {
+    fn bits(&mut self, count: u8) -> Result<u32, &'static str> {
+        let mut value = 0u32;
+
+        for i in 0..count {
+            let byte = match self.data.get(self.position) {
+                Some(byte) => *byte,
+                None => return Err("unexpected end of data"),
+            };
+
+            value |= (((byte >> self.bit) & 1) as u32) << i;
+            self.bit += 1;
+
+            if self.bit == 8 {
+                self.bit = 0;
+                self.position += 1;
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn align(&mut self) {
+        if self.bit != 0 {
+            self.bit = 0;
+            self.position += 1;
+        }
+    }
+
+    fn byte_position(&self) -> usize {
+        match self.bit {
+            0 => self.position,
+            _ => self.position + 1,
+        }
+    }
+}
+
+// A canonical Huffman code as the number of codes per length and the symbols
+// ordered by code.
+struct Huffman {
+    counts: [u16; 16],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Self, &'static str> {
+        let mut counts = [0u16; 16];
+        for length in lengths {
+            counts[*length as usize] += 1;
+        }
+        counts[0] = 0;
+
+        // Reject codes that assign more codes to a length than exist.
+        let mut left = 1i32;
+        for count in counts.iter().skip(1) {
+            left = (left << 1) - *count as i32;
+            if left < 0 {
+                return Err("over-subscribed Huffman code");
+            }
+        }
+
+        let mut offsets = [0u16; 16];
+        for length in 1..15 {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+
+        let mut symbols = vec![0u16; lengths.len()];
+        for (symbol, length) in lengths.iter().enumerate() {
+            if *length != 0 {
+                symbols[offsets[*length as usize] as usize] = symbol as u16;
+                offsets[*length as usize] += 1;
+            }
+        }
+
+        Ok(Self { counts, symbols })
+    }
+
+    fn decode(&self, bits: &mut BitReader<'_>) -> Result<u16, &'static str> {
+        let mut code = 0i32;
+        let mut first = 0i32;
+        let mut index = 0i32;
+
+        for length in 1..16 {
+            code |= bits.bits(1)? as i32;
+            let count = self.counts[length] as i32;
+
+            if code - first < count {
+                return match self.symbols.get((index + code - first) as usize) {
+                    Some(symbol) => Ok(*symbol),
+                    None => Err("invalid Huffman code"),
+                };
+            }
+
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+
+        Err("invalid Huffman code")
+    }
+}
+
+struct Inflater<'a> {
+    bits: BitReader<'a>,
+    out: Vec<u8>,
+}
+
+impl Inflater<'_> {
+    fn inflate(&mut self) -> Result<(), &'static str> {
+        loop {
+            let last = self.bits.bits(1)? == 1;
+
+            match self.bits.bits(2)? {
+                0 => self.stored()?,
+                1 => {
+                    let (literals, distances) = fixed_codes()?;
+                    self.codes(&literals, &distances)?;
+                }
+                2 => {
+                    let (literals, distances) = self.dynamic_codes()?;
+                    self.codes(&literals, &distances)?;
+                }
+                _ => return Err("invalid block type"),
+            }
+
+            if last {
+                return Ok(());
+            }
+        }
+    }
+
+    fn stored(&mut self) -> Result<(), &'static str> {
+        self.bits.align();
+
+        let start = self.bits.position;
+        let header = match self.bits.data.get(start..start + 4) {
+            Some(header) => header,
+            None => return Err("unexpected end of data"),
+        };
+
+        let length = u16::from_le_bytes([header[0], header[1]]);
+        let complement = u16::from_le_bytes([header[2], header[3]]);
+
+        if length != !complement {
+            return Err("corrupt stored block length");
+        }
+
+        let data = match self.bits.data.get(start + 4..start + 4 + length as usize) {
+            Some(data) => data,
+            None => return Err("unexpected end of data"),
+        };
+
+        self.push(data)?;
+        self.bits.position = start + 4 + length as usize;
+
+        Ok(())
+    }
+
+    fn dynamic_codes(&mut self) -> Result<(Huffman, Huffman), &'static str> {
+        let literal_count = self.bits.bits(5)? as usize + 257;
+        let distance_count = self.bits.bits(5)? as usize + 1;
+        let code_length_count = self.bits.bits(4)? as usize + 4;
+
+        if literal_count > 286 || distance_count > 30 {
+            return Err("too many codes");
+        }
+
+        let mut code_lengths = [0u8; 19];
+        for i in CODE_LENGTH_ORDER.iter().take(code_length_count) {
+            code_lengths[*i] = self.bits.bits(3)? as u8;
+        }
+        let code_length_code = Huffman::new(&code_lengths)?;
+
+        let mut lengths = Vec::<u8>::with_capacity(literal_count + distance_count);
+        while lengths.len() < literal_count + distance_count {
+            let symbol = code_length_code.decode(&mut self.bits)?;
+
+            let (value, repeat) = match symbol {
+                0..=15 => (symbol as u8, 1),
+                16 => match lengths.last() {
+                    Some(previous) => (*previous, 3 + self.bits.bits(2)? as usize),
+                    None => return Err("repeat without previous length"),
+                },
+                17 => (0, 3 + self.bits.bits(3)? as usize),
+                _ => (0, 11 + self.bits.bits(7)? as usize),
+            };
+
+            if lengths.len() + repeat > literal_count + distance_count {
+                return Err("too many code lengths");
+            }
+            lengths.extend(std::iter::repeat_n(value, repeat));
+        }
+
+        if lengths[256] == 0 {
+            return Err("missing end of block code");
+        }
+
+        Ok(
+            (
+                Huffman::new(&lengths[..literal_count])?,
+                Huffman::new(&lengths[literal_count..])?,
+            )
+        )
+    }
+
+    fn codes(&mut self, literals: &Huffman, distances: &Huffman) -> Result<(), &'static str> {
+        loop {
+            let symbol = literals.decode(&mut self.bits)? as usize;
+
+            match symbol {
+                0..=255 => self.push(&[symbol as u8])?,
+                256 => return Ok(()),
+                _ => {
+                    let index = symbol - 257;
+                    if index >= LENGTH_BASE.len() {
+                        return Err("invalid length code");
+                    }
+                    let length = LENGTH_BASE[index] as usize
+                        + self.bits.bits(LENGTH_EXTRA[index])? as usize;
+
+                    let index = distances.decode(&mut self.bits)? as usize;
+                    if index >= DISTANCE_BASE.len() {
+                        return Err("invalid distance code");
+                    }
+                    let distance = DISTANCE_BASE[index] as usize
+                        + self.bits.bits(DISTANCE_EXTRA[index])? as usize;
+
+                    if distance > self.out.len() {
+                        return Err("distance before start of output");
+                    }
+
+                    // Copies may overlap their own output, so go byte by byte.
+                    let start = self.out.len() - distance;
+                    for i in 0..length {
+                        let byte = self.out[start + i];
+                        self.push(&[byte])?;
+                    }
+                }
+            }
+        }
+    }
+
+    fn push(&mut self, data: &[u8]) -> Result<(), &'static str> {
+        if self.out.len() + data.len() > MAX_OUTPUT {
+            return Err("decompressed data too large");
+        }
+
+        self.out.extend_from_slice(data);
+        Ok(())
+    }
+}
+
+fn fixed_codes() -> Result<(Huffman, Huffman), &'static str> {
+    let mut lengths = [0u8; 288];
+    lengths[0..144].fill(8);
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths[280..288].fill(8);
+
+    Ok((Huffman::new(&lengths)?, Huffman::new(&[5; 30])?))
+}
@@ -0,0 +1,42 @@
+// Reading input from files and readers. Everything else in the crate only
+// works on byte slices, so builds without the `fs` feature don't touch the
+// filesystem.
+
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+use crate::ds_store::DsStore;
+use crate::error::DsStoreError;
+use crate::mmap::MappedFile;
+use crate::parser::DsStoreParser;
+
+impl DsStoreParser {
+    pub fn parse(&self, file: &str) -> Result<DsStore, DsStoreError> {
+        let file = File::open(file)?;
+        self.parse_reader(BufReader::new(file))
+    }
+
+    /// Like `parse`, but maps the file instead of copying it into memory.
+    /// Falls back to reading the file where mapping isn't possible.
+    ///
+    /// # Safety
+    ///
+    /// The same as for `MappedFile::open`: the file must not be truncated or
+    /// modified, by this or any other process, until this returns. Finder
+    /// replaces .DS_Store files rather than rewriting them in place.
+    pub unsafe fn parse_mmap<P: AsRef<Path>>(&self, path: P) -> Result<DsStore, DsStoreError> {
+        // SAFETY: the mapping only lives for this parse, nothing borrowed from
+        // it outlives the call, and the caller upholds the file contract.
+        let mapped = unsafe { MappedFile::open(path)? };
+        self.parse_bytes(&mapped)
+    }
+
+    pub fn parse_reader<R: Read>(&self, mut reader: R) -> Result<DsStore, DsStoreError> {
+        let mut buf = Vec::<u8>::new();
+
+        reader.read_to_end(&mut buf)?;
+
+        self.parse_bytes(&buf)
+    }
+}
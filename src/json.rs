@@ -0,0 +1,256 @@
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::ds_store::DsStore;
+use crate::error::DsStoreError;
+use crate::properties::Background;
+use crate::record::{to_hex, Record, Value};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    pub fn object(entries: Vec<(&str, JsonValue)>) -> Self {
+        Self::Object(
+            entries
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect()
+        )
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    write!(f, "\"")?;
+
+    for c in value.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+
+    write!(f, "\"")
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => write!(f, "null"),
+            Self::Bool(value) => write!(f, "{}", value),
+            Self::Integer(value) => write!(f, "{}", value),
+            Self::Float(value) if value.is_finite() => write!(f, "{}", value),
+            Self::Float(_) => write!(f, "null"),
+            Self::String(value) => write_string(f, value),
+            Self::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+            Self::Object(entries) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+impl Value {
+    pub(crate) fn to_json_value(&self) -> JsonValue {
+        match self {
+            Value::Long(value) => JsonValue::Integer(*value as i64),
+            Value::Short(value) => JsonValue::Integer(*value as i64),
+            Value::Bool(value) => JsonValue::Bool(*value),
+            Value::Blob(value) => JsonValue::String(to_hex(value)),
+            Value::Ustr(value) => JsonValue::String(value.clone()),
+            Value::Type(value) => JsonValue::String(String::from_utf8_lossy(value).into()),
+            Value::Comp(value) => JsonValue::Integer(*value),
+            Value::Dutc { raw, unix_secs } => JsonValue::object(vec![
+                ("raw", JsonValue::Integer(*raw)),
+                ("unix_secs", JsonValue::Integer(*unix_secs)),
+            ]),
+            Value::Skipped => JsonValue::Null,
+            Value::Unknown { id, raw } => JsonValue::object(vec![
+                ("id", JsonValue::String(String::from_utf8_lossy(id).into())),
+                ("raw", JsonValue::String(to_hex(raw))),
+            ]),
+        }
+    }
+}
+
+impl Record {
+    pub(crate) fn to_json_value(&self) -> JsonValue {
+        JsonValue::object(vec![
+            ("name", JsonValue::String(self.name.clone())),
+            ("structure_id", JsonValue::String(self.structure_id_str())),
+            (
+                "data_type",
+                JsonValue::String(String::from_utf8_lossy(self.data_type.tag()).into())
+            ),
+            ("value", self.value.to_json_value()),
+            ("recovered", JsonValue::Bool(self.is_recovered())),
+            ("source", JsonValue::String(self.source.as_str().into())),
+        ])
+    }
+}
+
+fn coordinate(coordinate: Option<i32>) -> JsonValue {
+    match coordinate {
+        Some(coordinate) => JsonValue::Integer(coordinate as i64),
+        None => JsonValue::Null,
+    }
+}
+
+impl Record {
+    // The value as the property accessors interpret it, e.g. the coordinates
+    // of an `Iloc` blob.
+    fn decoded_json_value(&self) -> Option<JsonValue> {
+        if let Some(location) = self.icon_location() {
+            return Some(JsonValue::object(vec![
+                ("x", coordinate(location.x)),
+                ("y", coordinate(location.y)),
+            ]));
+        }
+
+        if let Some(window) = self.window_info() {
+            return Some(JsonValue::object(vec![
+                ("top", JsonValue::Integer(window.top as i64)),
+                ("left", JsonValue::Integer(window.left as i64)),
+                ("bottom", JsonValue::Integer(window.bottom as i64)),
+                ("right", JsonValue::Integer(window.right as i64)),
+                ("view", JsonValue::String(String::from_utf8_lossy(&window.view).into())),
+            ]));
+        }
+
+        if let Some(background) = self.background() {
+            return Some(match background {
+                Background::Default => JsonValue::object(vec![
+                    ("kind", JsonValue::String("default".into())),
+                ]),
+                Background::Color { r, g, b } => JsonValue::object(vec![
+                    ("kind", JsonValue::String("color".into())),
+                    ("r", JsonValue::Integer(r as i64)),
+                    ("g", JsonValue::Integer(g as i64)),
+                    ("b", JsonValue::Integer(b as i64)),
+                ]),
+                Background::Picture { blob_len } => JsonValue::object(vec![
+                    ("kind", JsonValue::String("picture".into())),
+                    ("blob_len", JsonValue::Integer(blob_len as i64)),
+                ]),
+            });
+        }
+
+        let size = self.logical_size().or(self.physical_size());
+
+        self.modified()
+            .map(JsonValue::Integer)
+            .or(size.map(|size| JsonValue::Integer(size as i64)))
+            .or(self.expanded().map(JsonValue::Bool))
+            .or(self.view_settings_version().map(|version| JsonValue::Integer(version as i64)))
+            .or(self.extension().map(|extension| JsonValue::String(extension.into())))
+    }
+
+    fn to_value(&self) -> JsonValue {
+        let mut value = self.to_json_value();
+        let decoded = self.decoded_json_value();
+
+        if let (JsonValue::Object(fields), Some(decoded)) = (&mut value, decoded) {
+            fields.push(("decoded".to_string(), decoded));
+        }
+
+        value
+    }
+}
+
+impl DsStore {
+    pub(crate) fn to_json_value(&self) -> JsonValue {
+        self.tree_json_value(Record::to_json_value)
+    }
+
+    fn tree_json_value(&self, record: fn(&Record) -> JsonValue) -> JsonValue {
+        JsonValue::object(vec![
+            ("name", JsonValue::String(self.name.clone())),
+            (
+                "records",
+                JsonValue::Array(self.records.iter().map(record).collect())
+            ),
+            (
+                "children",
+                JsonValue::Array(
+                    self.children.iter().map(|c| c.tree_json_value(record)).collect()
+                )
+            ),
+        ])
+    }
+
+    // The same tree as `to_json`, as a value to embed in other documents.
+    // Records the property accessors understand also have a `decoded` field.
+    pub fn to_value(&self) -> JsonValue {
+        self.tree_json_value(Record::to_value)
+    }
+
+    pub fn to_json(&self) -> String {
+        self.to_json_value().to_string()
+    }
+
+    // Writes one JSON object per record and line, in the same order as
+    // `iter`, so large trees can be streamed without building one document.
+    pub fn write_ndjson<W: Write>(&self, w: &mut W, file: Option<&str>) -> io::Result<()> {
+        for (path, record) in self.iter() {
+            let mut entries = Vec::new();
+
+            if let Some(file) = file {
+                entries.push(("file".to_string(), JsonValue::String(file.into())));
+            }
+            entries.push(("path".to_string(), JsonValue::String(path)));
+
+            if let JsonValue::Object(fields) = record.to_json_value() {
+                entries.extend(fields);
+            }
+
+            writeln!(w, "{}", JsonValue::Object(entries))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl DsStoreError {
+    // The error as `{"error": kind, "offset": offset, "message": message}`,
+    // without `offset` for errors that don't point into the input.
+    pub fn to_json_value(&self) -> JsonValue {
+        let mut entries = vec![("error", JsonValue::String(self.kind().into()))];
+
+        if let Some(offset) = self.offset() {
+            entries.push(("offset", JsonValue::Integer(offset as i64)));
+        }
+        entries.push(("message", JsonValue::String(self.to_string())));
+
+        JsonValue::object(entries)
+    }
+}
@@ -0,0 +1,59 @@
+#[macro_use]
+mod log;
+
+mod apple_double;
+mod carve;
+mod csv;
+mod diff;
+mod dot;
+mod ds_store;
+mod error;
+#[cfg(feature = "gzip")]
+mod gzip;
+mod header;
+#[cfg(any(feature = "gzip", feature = "zip"))]
+mod inflate;
+#[cfg(feature = "fs")]
+mod input;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "fs")]
+mod mmap;
+mod normalize;
+mod parser;
+#[cfg(feature = "plist")]
+mod plist;
+mod properties;
+mod record;
+mod recover;
+mod report;
+mod summary;
+mod table;
+mod unicode_tables;
+mod writer;
+#[cfg(feature = "zip")]
+mod zip;
+
+pub use apple_double::{AppleDouble, AppleDoubleEntry};
+pub use carve::RECONSTRUCTED;
+pub use csv::write_csv_header;
+pub use diff::Change;
+pub use ds_store::{Display, DisplayOptions, DsStore, FindOptions, RecordIter};
+pub use error::DsStoreError;
+pub use header::{Bud1Header, BuddyHeader, MasterBlock, TocEntry};
+#[cfg(feature = "json")]
+pub use json::JsonValue;
+pub use log::{set_log_level, LogLevel};
+#[cfg(feature = "fs")]
+pub use mmap::MappedFile;
+pub use parser::{Decode, DsStoreParser};
+#[cfg(feature = "plist")]
+pub use plist::PlistValue;
+pub use properties::{image_extension, Background, IconLocation, ViewStyle, WindowInfo};
+#[cfg(feature = "plist")]
+pub use properties::{IconViewSettings, ListViewColumn, ListViewSettings, WindowSettings};
+pub use record::{DataType, Record, Source, Value, KNOWN_STRUCTURE_IDS};
+pub use report::{ParseReport, Warning, WarningCode};
+pub use summary::Summary;
+#[cfg(feature = "zip")]
+pub use zip::{ZipArchive, ZipEntry};
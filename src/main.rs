@@ -1,281 +1,975 @@
-use std::io::{BufReader, Read};
-use std::fs::File;
-use anyhow::Result;
-use clap::Parser;
+mod cli;
+
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use anstream::{AutoStream, ColorChoice};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use ds_store_parser::{
+    image_extension, write_csv_header, Decode, DisplayOptions, DsStore, DsStoreError,
+    DsStoreParser, FindOptions, set_log_level, LogLevel, MappedFile, ParseReport, Warning,
+    KNOWN_STRUCTURE_IDS,
+};
+#[cfg(feature = "json")]
+use ds_store_parser::JsonValue;
+#[cfg(feature = "zip")]
+use ds_store_parser::{ZipArchive, ZipEntry};
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    #[cfg(feature = "json")]
+    Json,
+    #[cfg(feature = "json")]
+    Ndjson,
+    Tree,
+    Csv,
+    Table,
+    Paths,
+    Dot,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ErrorFormat {
+    Text,
+    #[cfg(feature = "json")]
+    Json,
+}
 
-static BYTE_SIZE: usize = 8;
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum DecodeLevel {
+    Names,
+    Positions,
+    All,
+}
+
+impl From<DecodeLevel> for Decode {
+    fn from(level: DecodeLevel) -> Self {
+        match level {
+            DecodeLevel::Names => Self::Names,
+            DecodeLevel::Positions => Self::Positions,
+            DecodeLevel::All => Self::All,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ColorWhen {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Show the records added (+), removed (-) or changed (~) between two files
+    Diff {
+        /// The older DS_Store file
+        a: String,
+
+        /// The newer DS_Store file
+        b: String,
+
+        /// Treat names differing only in case as the same entry
+        #[arg(long)]
+        fold_case: bool,
+
+        /// Treat composed and decomposed forms of the same accented name as
+        /// the same entry
+        #[arg(long)]
+        nfc: bool,
+    },
+}
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+#[command(after_help = cli::exit::HELP)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Path to a DS_Store file, or - to read it from stdin. Can be given
+    /// multiple times
+    #[arg(
+        short,
+        long,
+        required_unless_present_any = ["recursive", "zip", "follow_names"],
+        num_args = 1..
+    )]
+    file: Vec<String>,
+
+    /// Parse every .DS_Store file found below this directory
+    #[arg(short, long, value_name = "DIR")]
+    recursive: Option<PathBuf>,
+
+    /// Parse the .DS_Store file in this directory and those of the folders
+    /// it names, nesting their entries into one tree
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["file", "recursive", "zip"])]
+    follow_names: Option<PathBuf>,
+
+    /// Prefix every entry with the directory its DS_Store file is in,
+    /// relative to this directory, e.g. with the one given to --recursive
+    #[arg(long, value_name = "DIR")]
+    base: Option<PathBuf>,
+
+    /// Parse every .DS_Store file inside this zip archive
+    #[arg(long, value_name = "ARCHIVE")]
+    zip: Option<PathBuf>,
+
+    /// Print the structure id of each record next to its name
+    #[arg(long)]
+    show_ids: bool,
+
+    /// Print the file offset each record was read from next to its name
+    #[arg(long)]
+    show_offsets: bool,
+
+    /// Print where each record was read from (live, free-space or carved)
+    /// next to its name
+    #[arg(long)]
+    show_sources: bool,
+
+    /// Only show records with this structure id, e.g. Iloc. Can be given
+    /// multiple times
+    #[arg(short, long = "type", value_name = "ID", value_parser = parse_structure_id)]
+    types: Vec<[u8; 4]>,
+
+    /// Only show records whose entry name contains this text
+    #[arg(long, value_name = "TEXT")]
+    grep: Option<String>,
+
+    /// Only check whether each file has an entry with exactly this name.
+    /// Files that do are printed, and the exit status is 4 if none does
+    #[arg(long, value_name = "NAME", conflicts_with = "follow_names")]
+    contains: Option<String>,
+
+    /// Match --grep case insensitively
+    #[arg(short, long, visible_alias = "fold-case", requires = "grep")]
+    ignore_case: bool,
+
+    /// Match --grep against composed and decomposed forms of accented names
+    /// alike
+    #[arg(long, requires = "grep")]
+    nfc: bool,
+
+    /// Also list records recovered from free space in the file
+    #[arg(long)]
+    recover: bool,
+
+    /// Only list entries recovered from free space that have no live
+    /// records left, i.e. deleted files. Implies --recover
+    #[arg(long)]
+    only_recovered: bool,
+
+    /// Print aggregate statistics instead of the records themselves
+    #[arg(long)]
+    summary: bool,
+
+    /// Print the number of records instead of the records themselves
+    #[arg(long, conflicts_with = "summary")]
+    count: bool,
+
+    /// With --count, also print the number of B-tree nodes read
+    #[arg(long, requires = "count")]
+    nodes: bool,
+
+    /// Keep the records read before an error in a damaged file instead of
+    /// failing
+    #[arg(long)]
+    lenient: bool,
+
+    /// Scan input without a DS_Store signature, e.g. carved from a disk
+    /// image, for B-tree nodes. The result is labelled as reconstructed
+    #[arg(long)]
+    allow_missing_sig: bool,
+
+    /// Fail on entries with more than one record of the same structure id,
+    /// a leading word other than 00 00 00 01 and bytes after the last
+    /// allocated block, instead of warning about them
+    #[arg(long, conflicts_with = "lenient")]
+    strict: bool,
+
+    /// Fail on entry names that are not valid UTF-16 instead of replacing
+    /// the invalid parts with �
+    #[arg(long)]
+    strict_names: bool,
+
+    /// Sort entries by name instead of keeping the on-disk order
+    #[arg(long)]
+    sort: bool,
+
+    /// Show each entry once with all of its records instead of once per
+    /// record
+    #[arg(long)]
+    group: bool,
+
+    /// Nest entries below the folders their names imply, e.g. a/b below a
+    #[arg(long)]
+    implied_tree: bool,
+
+    /// Write a copy of the input with every entry name replaced by a hash
+    /// to this file, leaving out blobs that can hold paths. The copy holds
+    /// every other record, so options that filter or rearrange the records
+    /// can't be combined with it
+    #[arg(
+        long,
+        value_name = "OUTPUT",
+        conflicts_with_all = [
+            "types", "grep", "recover", "only_recovered", "group", "implied_tree", "base",
+            "sort", "follow_names",
+        ]
+    )]
+    redact: Option<PathBuf>,
+
+    /// Write the background picture stored in each file to this directory
+    #[arg(long, value_name = "DIR")]
+    extract_images: Option<PathBuf>,
+
+    /// Print parse statistics and the parse steps to stderr. Give twice to
+    /// also trace every value read
+    #[arg(short, long, action = ArgAction::Count)]
+    verbose: u8,
+
+    /// Don't print warnings about files that were parsed anyway
     #[arg(short, long)]
-    file: String
+    quiet: bool,
+
+    /// When a file fails to parse at a known offset, print a hex dump of the
+    /// bytes around it
+    #[arg(long)]
+    dump_hex: bool,
+
+    /// Memory map input files instead of reading them into memory
+    #[arg(long)]
+    mmap: bool,
+
+    /// Which record values to decode. names only reads entry names and
+    /// structure ids, positions also icon positions. Leaving values out
+    /// speeds up scans of many files
+    #[arg(long, value_enum, value_name = "SET", default_value_t = DecodeLevel::All)]
+    decode: DecodeLevel,
+
+    /// Number of files to parse in parallel
+    #[arg(short, long, value_name = "N", default_value_t = 1)]
+    jobs: usize,
+
+    /// Number of spaces to indent each tree level with
+    #[arg(long, value_name = "N", default_value_t = 4)]
+    indent: usize,
+
+    /// Only print entries up to this many levels below the root, marking
+    /// entries with hidden children with …
+    #[arg(long, value_name = "N")]
+    max_depth: Option<usize>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Color the tree output. auto colors it when stdout is a terminal and
+    /// NO_COLOR is not set
+    #[arg(long, value_enum, value_name = "WHEN", default_value_t = ColorWhen::Auto)]
+    color: ColorWhen,
+
+    /// How to print errors about files that could not be parsed. json prints
+    /// one object per error and line
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        value_name = "FORMAT",
+        default_value_t = ErrorFormat::Text
+    )]
+    error_format: ErrorFormat,
 }
 
-struct DsStore {
-    name: String,
-    children: Vec<DsStore>,
-    indet_length: usize,
+fn parse_structure_id(id: &str) -> Result<[u8; 4], String> {
+    match KNOWN_STRUCTURE_IDS.contains(&id) {
+        true => Ok(id.as_bytes().try_into().unwrap()),
+        false => Err(
+            format!("unknown structure id, expected one of: {}", KNOWN_STRUCTURE_IDS.join(", "))
+        ),
+    }
 }
 
-struct DsStoreParser {
-    file_signature: Vec<u8>,
-    record_terminator: Vec<u8>,
-    block_size: usize,
-    root_offset_location: usize,
-    root_offset_location_check: usize,
-    index_padding: usize,
+fn read_input(file: &str) -> io::Result<Vec<u8>> {
+    match file {
+        "-" => {
+            let mut buf = Vec::new();
+            io::stdin().lock().read_to_end(&mut buf)?;
+            Ok(buf)
+        }
+        file => fs::read(file),
+    }
 }
 
-impl DsStore {
-    pub fn print(&self) {
-        println!("{}", self.name);
+// Something to parse: a file on disk or stdin, an entry of the --zip
+// archive or the directory given to --follow-names.
+enum Input<'a> {
+    File(&'a str),
+    Followed { label: String, dir: &'a Path },
+    #[cfg(feature = "zip")]
+    ZipEntry { label: String, archive: &'a ZipArchive<'a>, entry: &'a ZipEntry },
+}
 
-        for child in self.children.iter() {
-            child.print_recurse(self.indet_length);
+impl Input<'_> {
+    fn label(&self) -> &str {
+        match self {
+            Self::File(file) => file,
+            Self::Followed { label, .. } => label,
+            #[cfg(feature = "zip")]
+            Self::ZipEntry { label, .. } => label,
         }
     }
+}
 
-    fn print_recurse(&self, indent: usize) {
-        print!("{:<1$}", " ", indent);
-        print!("{}", self.name);
+// A failed parse, with the bytes around the failure if --dump-hex is given.
+struct Failure {
+    error: DsStoreError,
+    dump: Option<String>,
+}
 
-        match self.children.len() {
-            0 => println!(""),
-            _ => println!(":")
-        };
+impl From<DsStoreError> for Failure {
+    fn from(error: DsStoreError) -> Self {
+        Self { error, dump: None }
+    }
+}
 
-        for child in self.children.iter() {
-            child.print_recurse(indent + self.indet_length);
+// Reads `file`, or maps it with --mmap, and hands its bytes to `f`.
+fn with_file<T>(
+    args: &Args,
+    file: &str,
+    f: impl FnOnce(&[u8]) -> Result<T, Failure>
+) -> Result<T, Failure> {
+    let mapped;
+    let read;
+    let buf: &[u8] = match (args.mmap, file) {
+        (true, file) if file != "-" => {
+            // SAFETY: the mapping is dropped before this returns. Like
+            // `parse_mmap`, it relies on the file not being truncated meanwhile.
+            mapped = unsafe { MappedFile::open(file) }.map_err(DsStoreError::from)?;
+            &mapped
         }
+        _ => {
+            read = read_input(file).map_err(DsStoreError::from)?;
+            &read
+        }
+    };
+
+    f(buf)
+}
+
+fn parse_file(
+    args: &Args,
+    dss_parser: &DsStoreParser,
+    file: &str
+) -> Result<(DsStore, ParseReport), Failure> {
+    with_file(args, file, |buf| parse_buffer(args, dss_parser, buf))
+}
+
+// Prints the inputs with an entry called `name`, one per line, and fails
+// with NOT_FOUND if there are none. Files that can't be parsed are reported
+// and skipped; their status is only used when no file could be checked.
+fn contains(
+    args: &Args,
+    dss_parser: &DsStoreParser,
+    inputs: &[Input],
+    name: &str,
+    multiple: bool
+) -> ExitCode {
+    let check = |buf: &[u8]| dss_parser.contains(buf, name).map_err(Failure::from);
+    let results = cli::jobs::map_ordered(inputs, args.jobs, |input| match input {
+        Input::File(file) => with_file(args, file, check),
+        Input::Followed { label, .. } => with_file(args, label, check),
+        #[cfg(feature = "zip")]
+        Input::ZipEntry { archive, entry, .. } => archive
+            .read(entry)
+            .map_err(Failure::from)
+            .and_then(|data| check(&data)),
+    });
+
+    let mut status = None;
+    let mut checked = false;
+    let mut found = false;
+    let mut out = io::stdout().lock();
+
+    for (input, result) in inputs.iter().zip(results) {
+        let file = input.label();
+
+        match result {
+            Ok(true) => {
+                checked = true;
+                found = true;
+                if let Err(e) = writeln!(out, "{}", file) {
+                    if e.kind() == io::ErrorKind::BrokenPipe {
+                        break;
+                    }
+                    eprintln!("ERROR: {}. Aborting.", e);
+                    return ExitCode::from(cli::exit::ERROR);
+                }
+            }
+            Ok(false) => checked = true,
+            Err(Failure { error, .. }) => {
+                status.get_or_insert(cli::exit::code(&error));
+                let line = match multiple {
+                    true => format!("ERROR: {}: {}. Skipping.", file, error),
+                    false => format!("ERROR: {}. Aborting.", error),
+                };
+                print_error(args.error_format, Some(file), &error, line);
+            }
+        }
+    }
+
+    match (found, checked, status) {
+        (true, _, _) => ExitCode::SUCCESS,
+        (false, false, Some(status)) => ExitCode::from(status),
+        (false, _, _) => ExitCode::from(cli::exit::NOT_FOUND),
     }
 }
 
-impl DsStoreParser {
-    pub fn new() -> Self {
-        Self {
-            file_signature: vec![
-                0x00, 0x00, 0x00, 0x01,
-                0x42, 0x75, 0x64, 0x31,
-            ],
-            record_terminator: vec![
-                0x76, 0x53, 0x72, 0x6e,
-                0x6c, 0x6f, 0x6e, 0x67,
-                0x00, 0x00, 0x00, 0x01,
-            ],
-            block_size: 0x04,
-            root_offset_location: 0x08,
-            root_offset_location_check: 0x10,
-            index_padding: 0x100,
+// Parses `dir/.DS_Store`, then the `.DS_Store` of every folder entry that
+// exists on disk, and so on, nesting each file's entries below the entry
+// naming its folder. Folders without one stay leaves, and files that fail
+// to parse are reported and left out. Only the first file has to parse.
+fn follow_names(
+    args: &Args,
+    dss_parser: &DsStoreParser,
+    dir: &Path
+) -> Result<(DsStore, ParseReport), Failure> {
+    let file = dir.join(".DS_Store");
+    let (root, mut report) = parse_file(args, dss_parser, &file.to_string_lossy())?;
+
+    // Every parsed file with its directory and its parent's index, in the
+    // order found.
+    let mut parsed = vec![(None, dir.to_path_buf(), root)];
+    let mut next = 0;
+
+    while next < parsed.len() {
+        let mut seen = HashSet::new();
+        let names = parsed[next].2
+            .children()
+            .iter()
+            .map(|c| c.name().to_string())
+            .filter(|name| seen.insert(name.clone()))
+            .collect::<Vec<_>>();
+
+        for name in names {
+            // Names come from the file, so they must not leave the folder.
+            if name == "." || name == ".." || name.is_empty() || name.contains('/') {
+                continue;
+            }
+
+            let sub_dir = parsed[next].1.join(&name);
+            let file = sub_dir.join(".DS_Store");
+            let is_dir = fs::symlink_metadata(&sub_dir).is_ok_and(|m| m.is_dir());
+
+            if !is_dir || !file.is_file() {
+                continue;
+            }
+
+            let label = file.to_string_lossy().to_string();
+
+            match parse_file(args, dss_parser, &label) {
+                Ok((subtree, sub_report)) => {
+                    report.record_count += sub_report.record_count;
+                    report.node_count += sub_report.node_count;
+                    report.bytes_parsed += sub_report.bytes_parsed;
+                    for warning in sub_report.warnings {
+                        let message = format!("{}: {}", label, warning);
+                        report.warnings.push(Warning::new(warning.code(), message));
+                    }
+                    parsed.push((Some((next, name)), sub_dir, subtree));
+                }
+                Err(Failure { error, .. }) if !args.quiet => {
+                    eprintln!("WARNING: {}: {}. Skipping.", label, error);
+                }
+                Err(_) => {}
+            }
         }
+
+        next += 1;
     }
 
-    pub fn parse(&self, file: &str) -> Result<DsStore, String> {
-        let file = File::open(file).expect("Unable to open file".into());
-        let mut reader = BufReader::new(file);
-        let mut buf = Vec::<u8>::new();
+    // Children come after their parents, so grafting from the back moves
+    // every subtree only once it is complete.
+    while let Some((parent, _, subtree)) = parsed.pop() {
+        match parent {
+            Some((index, name)) => parsed[index].2.graft(&name, subtree),
+            None => return Ok((subtree, report)),
+        }
+    }
 
-        reader.read_to_end(&mut buf).expect("Failed to read file into buffer".into());
+    unreachable!("the first file has no parent")
+}
+
+fn parse_buffer(
+    args: &Args,
+    dss_parser: &DsStoreParser,
+    buf: &[u8]
+) -> Result<(DsStore, ParseReport), Failure> {
+    let parsed = match (args.lenient, args.strict) {
+        (true, _) => dss_parser.parse_lenient(buf),
+        (_, true) => dss_parser.parse_strict(buf),
+        _ => dss_parser.parse_bytes_with_report(buf),
+    };
 
-        if !self.confirm_signature(&buf) {
-            return Err("Signature does not match a DS_Store file".into());
+    let parsed = match parsed {
+        Err(DsStoreError::BadMagic { .. } | DsStoreError::TooShort { .. })
+            if args.allow_missing_sig => dss_parser.parse_carved(buf),
+        parsed => parsed,
+    };
+
+    let (mut ds_store, report) = match parsed {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            let dump = match (args.dump_hex, error.offset()) {
+                (true, Some(offset)) => Some(cli::hexdump::around(buf, offset)),
+                _ => None,
+            };
+            return Err(Failure { error, dump });
         }
+    };
+
+    if args.recover || args.only_recovered {
+        ds_store.add_records(dss_parser.recover(buf));
+    }
 
-        let root_offset = self.block_to_usize(&buf, self.root_offset_location)?
-            + self.block_size;
+    if args.only_recovered {
+        ds_store.retain_deleted();
+    }
 
-        let root_offset_check = self.block_to_usize(&buf, self.root_offset_location_check)?
-            + self.block_size;
+    if !args.types.is_empty() {
+        ds_store.retain_records(|r| args.types.contains(&r.structure_id()));
+    }
 
-        if root_offset != root_offset_check {
-            return Err(
-                format!(
-                    "Root block offsets do not match: 0x{:x} != 0x{:x}",
-                    root_offset, root_offset_check
-                )
-            );
+    if let Some(pattern) = &args.grep {
+        let options = FindOptions { substring: true, ignore_case: args.ignore_case, nfc: args.nfc };
+        ds_store.retain_records(|r| options.matches(r.name(), pattern));
+    }
+
+    Ok((ds_store, report))
+}
+
+// Prints a parse error as `line`, or as JSON with --error-format json.
+fn print_error(format: ErrorFormat, file: Option<&str>, error: &DsStoreError, line: String) {
+    match format {
+        ErrorFormat::Text => eprintln!("{}", line),
+        #[cfg(feature = "json")]
+        ErrorFormat::Json => {
+            let mut value = error.to_json_value();
+
+            if let (JsonValue::Object(fields), Some(file)) = (&mut value, file) {
+                fields.insert(0, ("file".into(), JsonValue::String(file.into())));
+            }
+
+            eprintln!("{}", value);
         }
+    }
+}
 
-        let entry_count = self.block_to_usize(&buf, root_offset)?;
-        let mut entry_indices = Vec::<usize>::new();
+// Warnings are shown unless --quiet is given, the statistics only with
+// --verbose.
+fn print_report(args: &Args, file: &str, report: &ParseReport) {
+    if args.verbose > 0 {
+        eprintln!(
+            "{}: {} records in {} nodes, {} bytes parsed",
+            file, report.record_count, report.node_count, report.bytes_parsed
+        );
+    }
 
-        for i in 0..entry_count {
-            entry_indices.push(
-                self.block_to_usize(
-                    &buf,
-                    root_offset + self.block_size + (self.block_size * (i + 1))
-                )?
-            );
+    if args.quiet {
+        return;
+    }
+
+    for warning in report.warnings.iter() {
+        eprintln!("WARNING: {}: {}.", file, warning);
+    }
+}
+
+// anstream checks whether stdout is a terminal and the NO_COLOR and
+// CLICOLOR_FORCE variables.
+fn use_color(when: ColorWhen) -> bool {
+    match when {
+        ColorWhen::Auto => AutoStream::choice(&io::stdout()) != ColorChoice::Never,
+        ColorWhen::Always => true,
+        ColorWhen::Never => false,
+    }
+}
+
+// `file` is only set when several files are printed in one run, in which
+// case every result is labelled with the path it came from.
+fn print_ds_store(args: &Args, file: Option<&str>, ds_store: &DsStore) -> io::Result<()> {
+    let mut out = io::stdout().lock();
+
+    if args.summary {
+        if let Some(file) = file {
+            writeln!(out, "{}:", file)?;
         }
+        return write!(out, "{}", ds_store.summary());
+    }
 
-        let root_content_offset = root_offset +
-            ((self.block_size * self.index_padding) % root_offset) +
-            (2 * self.block_size);
-
-        let root_id = self.block_to_usize(
-            &buf,
-            root_content_offset + (self.block_size * 2) + 1
-        )?;
-
-        let root_name: String = match str::from_utf8(&buf[
-            root_content_offset + self.block_size + 1
-            ..
-            root_content_offset + (self.block_size * 2) + 1
-        ]) {
-            Ok(raw_name) => raw_name.into(),
-            Err(_) => {
-                return Err("Root node name contains illegal UTF-8 sequence".into());
+    match args.format {
+        Format::Text | Format::Tree => {
+            if let Some(file) = file {
+                writeln!(out, "{}:", file)?;
             }
+            let options = DisplayOptions {
+                show_ids: args.show_ids,
+                show_offsets: args.show_offsets,
+                show_sources: args.show_sources,
+                connectors: args.format == Format::Tree,
+                max_depth: args.max_depth,
+                color: use_color(args.color),
+            };
+            write!(out, "{}", ds_store.display(options))?;
+        }
+        #[cfg(feature = "json")]
+        Format::Json => match file {
+            Some(file) => writeln!(
+                out,
+                "{{\"file\":{},\"ds_store\":{}}}",
+                ds_store_parser::JsonValue::String(file.into()),
+                ds_store.to_json()
+            )?,
+            None => writeln!(out, "{}", ds_store.to_json())?,
+        },
+        #[cfg(feature = "json")]
+        Format::Ndjson => ds_store.write_ndjson(&mut out, file)?,
+        Format::Csv => match file {
+            Some(file) => ds_store.write_csv_rows(&mut out, Some(file))?,
+            None => ds_store.write_csv(&mut out)?,
+        },
+        Format::Table => {
+            if let Some(file) = file {
+                writeln!(out, "{}:", file)?;
+            }
+            let width = cli::terminal::width().unwrap_or(usize::MAX);
+            ds_store.write_table(&mut out, width)?;
+        }
+        Format::Dot => write!(out, "{}", ds_store.to_dot())?,
+        Format::Paths => {
+            for path in ds_store.paths() {
+                match file {
+                    Some(file) => writeln!(out, "{}: {}", file, path)?,
+                    None => writeln!(out, "{}", path)?,
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_count(
+    args: &Args,
+    file: Option<&str>,
+    ds_store: &DsStore,
+    report: &ParseReport
+) -> io::Result<()> {
+    let mut out = io::stdout().lock();
+
+    if let Some(file) = file {
+        write!(out, "{}: ", file)?;
+    }
+
+    // Counted after --type, --grep and --recover, like the records printed.
+    let records = ds_store.iter().count();
+
+    match args.nodes {
+        true => writeln!(out, "{} records in {} nodes", records, report.node_count),
+        false => writeln!(out, "{}", records),
+    }
+}
+
+// Writes every `pict` payload to `dir` as `picture-<n>.<extension>`,
+// numbered across all inputs. Empty or unrecognizable payloads are skipped.
+fn extract_images(
+    dir: &Path,
+    file: &str,
+    ds_store: &DsStore,
+    extracted: &mut usize
+) -> io::Result<()> {
+    for (path, record) in ds_store.iter() {
+        let picture = match record.picture() {
+            Some(picture) => picture,
+            None => continue,
         };
 
-        let (index_offset, _) = self.entry_index_to_entry_data(entry_indices[root_id]);
-        let entry_id = self.block_to_usize(&buf, index_offset)?;
-        let (entry_offset, _) = self.entry_index_to_entry_data(entry_indices[entry_id]);
+        let extension = match (picture.is_empty(), image_extension(picture)) {
+            (false, Some(extension)) => extension,
+            (true, _) => {
+                eprintln!("WARNING: {}: Skipping empty picture of {:?}.", file, path);
+                continue;
+            }
+            (false, None) => {
+                eprintln!(
+                    "WARNING: {}: Skipping picture of {:?} in an unknown format.",
+                    file, path
+                );
+                continue;
+            }
+        };
 
-        let mut root_node = DsStore {
-            name: root_name,
-            children: vec![],
-            indet_length: 4,
+        *extracted += 1;
+        let target = dir.join(format!("picture-{}.{}", extracted, extension));
+        fs::write(&target, picture)?;
+        eprintln!("{}: {:?} -> {}", file, path, target.display());
+    }
+
+    Ok(())
+}
+
+fn diff(
+    dss_parser: &DsStoreParser,
+    a: &str,
+    b: &str,
+    options: FindOptions,
+    error_format: ErrorFormat
+) -> ExitCode {
+    let mut parsed = Vec::new();
+
+    for file in [a, b] {
+        let result = match read_input(file) {
+            Ok(buf) => dss_parser.parse_bytes(&buf),
+            Err(e) => Err(e.into()),
         };
 
-        let ds_store_tree = self.generate_ds_store_tree(
-            &buf,
-            entry_offset
-        )?;
+        match result {
+            Ok(ds_store) => parsed.push(ds_store),
+            Err(msg) => {
+                let line = format!("ERROR: {}: {}. Aborting.", file, msg);
+                print_error(error_format, Some(file), &msg, line);
+                return ExitCode::from(cli::exit::code(&msg));
+            }
+        }
+    }
+
+    let mut out = io::stdout().lock();
+    for change in parsed[0].diff_with(&parsed[1], options) {
+        if let Err(e) = writeln!(out, "{}", change) {
+            if e.kind() == io::ErrorKind::BrokenPipe {
+                break;
+            }
+            eprintln!("ERROR: {}. Aborting.", e);
+            return ExitCode::from(cli::exit::ERROR);
+        }
+    }
+
+    ExitCode::SUCCESS
+}
 
-        for node in ds_store_tree {
-            root_node.children.push(node);
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    set_log_level(
+        match args.verbose {
+            0 => LogLevel::Off,
+            1 => LogLevel::Debug,
+            _ => LogLevel::Trace,
         }
+    );
 
-        Ok(root_node)
+    if let Some(Command::Diff { a, b, fold_case, nfc }) = &args.command {
+        let options = FindOptions { substring: false, ignore_case: *fold_case, nfc: *nfc };
+        return diff(&DsStoreParser::new(), a, b, options, args.error_format);
     }
 
-    fn generate_ds_store_tree(
-        &self,
-        buf: &Vec<u8>,
-        mut offset: usize
-    ) -> Result<Vec<DsStore>, String> {
-        let mut result = Vec::<DsStore>::new();
-        let mode = self.block_to_usize(&buf, offset)?;
+    #[cfg(not(feature = "zip"))]
+    if args.zip.is_some() {
+        eprintln!("ERROR: This build does not support zip archives. Aborting.");
+        return ExitCode::from(cli::exit::ERROR);
+    }
 
-        if mode != 0 {
-            panic!("Dev was too lazy for this.");
+    #[cfg(feature = "zip")]
+    let zip_buf = match args.zip.as_ref().map(fs::read) {
+        Some(Ok(buf)) => buf,
+        Some(Err(e)) => {
+            eprintln!("ERROR: {}. Aborting.", e);
+            return ExitCode::from(cli::exit::ERROR);
         }
+        None => vec![],
+    };
+    #[cfg(feature = "zip")]
+    let archive = match args.zip.is_some() {
+        true => match ZipArchive::parse(&zip_buf) {
+            Ok(archive) => Some(archive),
+            Err(e) => {
+                let file = args.zip.as_ref().map(|zip| zip.to_string_lossy());
+                let line = format!("ERROR: {}. Aborting.", e);
+                print_error(args.error_format, file.as_deref(), &e, line);
+                return ExitCode::from(cli::exit::code(&e));
+            }
+        },
+        false => None,
+    };
+
+    let found = match &args.recursive {
+        Some(dir) => cli::walk::find_ds_store_files(dir)
+            .iter()
+            .map(|path| path.to_string_lossy().into())
+            .collect::<Vec<String>>(),
+        None => vec![],
+    };
 
-        let record_count = self.block_to_usize(&buf, offset + self.block_size)?;
-
-        for _ in 0..record_count {
-            let record_size = self.block_to_usize(
-                &buf,
-                offset + (self.block_size * 2)
-            )?;
-
-            let record_utf16 = &buf[
-                offset + (self.block_size * 3 )
-                ..
-                offset + (self.block_size * 3) + record_size * 2
-            ];
-
-            let utf16_packets = record_utf16
-                .chunks(2)
-                .map(|e| u16::from_be_bytes(e.try_into().unwrap()))
-                .collect::<Vec<_>>();
-
-            let record = String::from_utf16_lossy(&utf16_packets);
-            result.push(
-                DsStore {
-                    name: record,
-                    children: vec![],
-                    indet_length: 4,
+    let mut inputs = args.file
+        .iter()
+        .chain(&found)
+        .map(|file| Input::File(file))
+        .collect::<Vec<_>>();
+
+    if let Some(dir) = &args.follow_names {
+        let label = dir.join(".DS_Store").to_string_lossy().to_string();
+        inputs.push(Input::Followed { label, dir });
+    }
+
+    // Entries are labelled with their path inside the archive, so results
+    // are grouped by archive path.
+    #[cfg(feature = "zip")]
+    if let (Some(zip), Some(archive)) = (&args.zip, &archive) {
+        for entry in archive.entries().iter().filter(|e| e.is_ds_store()) {
+            inputs.push(
+                Input::ZipEntry {
+                    label: format!("{}/{}", zip.display(), entry.name),
+                    archive,
+                    entry,
                 }
             );
+        }
+    }
 
-            let mut end_of_record = false;
-            while !end_of_record {
-                if buf.len() < offset + (self.block_size * 2) {
-                    return Ok(result);
-                }
+    let multiple = inputs.len() > 1 || args.recursive.is_some() || args.zip.is_some();
 
-                let pattern = &buf[
-                    offset
-                    ..
-                    offset + (self.block_size * 2)
-                ];
-
-                end_of_record = true;
-                for (a, b) in self.record_terminator.iter().zip(pattern) {
-                    if a != b {
-                        offset += 1;
-                        end_of_record = false;
-                        break;
-                    }
-                }
-            }
+    if multiple && args.format == Format::Csv && !args.summary && !args.count {
+        let _ = write_csv_header(&mut io::stdout().lock(), true);
+    }
 
-            offset += self.block_size;
-        }
+    let writes = args.redact.is_some() || args.extract_images.is_some();
+    if args.decode != DecodeLevel::All && writes {
+        eprintln!("ERROR: --redact and --extract-images need --decode all. Aborting.");
+        return ExitCode::from(cli::exit::ERROR);
+    }
 
-        Ok(result)
+    if multiple && args.redact.is_some() {
+        eprintln!("ERROR: --redact takes a single input file. Aborting.");
+        return ExitCode::from(cli::exit::ERROR);
     }
 
-    pub fn confirm_signature(&self, buf: &Vec<u8>) -> bool {
-        if buf.len() < self.file_signature.len() {
-            println!("Input file is shorten then file signature");
-            return false;
-        }
+    if let Some(dir) = &args.extract_images
+        && let Err(e) = fs::create_dir_all(dir)
+    {
+        eprintln!("ERROR: {}: {}. Aborting.", dir.display(), e);
+        return ExitCode::from(cli::exit::ERROR);
+    }
 
-        for (a, b) in self.file_signature.iter().zip(buf) {
-            if a != b {
-                println!(
-                    "Failure during signature check: Expected byte 0x{:x}, got 0x{:x}",
-                    a, b,
-                );
-                return false;
+    let dss_parser = DsStoreParser::new()
+        .with_strict_names(args.strict_names)
+        .with_decode(args.decode.into());
+
+    if let Some(name) = &args.contains {
+        return contains(&args, &dss_parser, &inputs, name, multiple);
+    }
+
+    // Like the parse errors themselves, the status of a failed file is only
+    // used if every file failed.
+    let mut status = None;
+    let mut parsed = false;
+    let mut extracted = 0;
+
+    // Results are collected before printing so parallel runs keep the
+    // output in input order.
+    let results = cli::jobs::map_ordered(&inputs, args.jobs, |input| match input {
+        Input::File(file) => parse_file(&args, &dss_parser, file),
+        Input::Followed { dir, .. } => follow_names(&args, &dss_parser, dir),
+        #[cfg(feature = "zip")]
+        Input::ZipEntry { archive, entry, .. } => archive
+            .read(entry)
+            .map_err(Failure::from)
+            .and_then(|data| parse_buffer(&args, &dss_parser, &data)),
+    });
+
+    for (input, result) in inputs.iter().zip(results) {
+        let file = input.label();
+        let (mut ds_store, report) = match result {
+            Ok((ds_store, report)) => {
+                parsed = true;
+                print_report(&args, file, &report);
+                (ds_store, report)
+            }
+            Err(Failure { error: msg, dump }) => {
+                status.get_or_insert(cli::exit::code(&msg));
+                let line = match multiple {
+                    true => format!("ERROR: {}: {}. Skipping.", file, msg),
+                    false => format!("ERROR: {}. Aborting.", msg),
+                };
+                print_error(args.error_format, Some(file), &msg, line);
+                if let (Some(dump), ErrorFormat::Text) = (dump, args.error_format) {
+                    eprint!("{}", dump);
+                }
+                continue;
             }
+        };
+
+        if args.implied_tree {
+            ds_store = ds_store.into_implied_tree();
         }
 
-        true
-    }
+        if args.group {
+            ds_store.group_records();
+        }
 
-    fn entry_index_to_entry_data(&self, entry_index: usize) -> (usize, usize) {
-        let offset = ((entry_index >> 0x5) << 0x5) + self.block_size;
-        let size = 1 << (entry_index & 0x1f);
-        (offset, size)
-    }
+        if let (Some(base), Input::File(file)) = (&args.base, input)
+            && *file != "-"
+        {
+            ds_store.prefix_paths(&cli::walk::relative_dir(Path::new(file), base));
+        }
 
-    fn block_to_usize(&self, buf: &Vec<u8>, offset: usize) -> Result<usize, String> {
-        if buf.len() < (offset + self.block_size) {
-            return Err(
-                format!(
-                    "Failed to parse block at offset 0x{:x}. Offset out of range",
-                    offset
-                )
-            );
+        ds_store.set_indent_length(args.indent);
+
+        if args.sort {
+            ds_store.sort_children();
         }
 
-        let mut block: usize = 0x00000000;
+        if let Some(output) = &args.redact {
+            ds_store.redact_names();
+
+            let written = fs::File::create(output)
+                .map_err(DsStoreError::from)
+                .and_then(|mut out| dss_parser.write(&ds_store, &mut out));
 
-        for i in 0..self.block_size {
-            block ^= buf[offset + i] as usize;
-            block <<= BYTE_SIZE;
+            if let Err(e) = written {
+                eprintln!("ERROR: {}: {}. Aborting.", output.display(), e);
+                return ExitCode::from(cli::exit::ERROR);
+            }
         }
 
-        block >>= BYTE_SIZE;
-        Ok(block)
-    }
-}
+        if let Some(dir) = &args.extract_images
+            && let Err(e) = extract_images(dir, file, &ds_store, &mut extracted)
+        {
+            eprintln!("ERROR: {}. Aborting.", e);
+            return ExitCode::from(cli::exit::ERROR);
+        }
 
-fn main() {
-    let args = Args::parse();
+        let label = multiple.then_some(file);
+        let printed = match args.count {
+            true => print_count(&args, label, &ds_store, &report),
+            false => print_ds_store(&args, label, &ds_store),
+        };
 
-    let dss_parser = DsStoreParser::new();
-    let ds_store = match dss_parser.parse(&args.file) {
-        Ok(ds_store) => ds_store,
-        Err(msg) => {
-            eprintln!("ERROR: {}. Aborting.", msg);
-            return;
+        if let Err(e) = printed {
+            if e.kind() == io::ErrorKind::BrokenPipe {
+                return ExitCode::SUCCESS;
+            }
+            eprintln!("ERROR: {}. Aborting.", e);
+            return ExitCode::from(cli::exit::ERROR);
         }
-    };
+    }
 
-    ds_store.print();
+    match (parsed, status) {
+        (false, Some(code)) => ExitCode::from(code),
+        _ => ExitCode::SUCCESS,
+    }
 }
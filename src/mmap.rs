@@ -0,0 +1,118 @@
+use std::fs::{self, File};
+use std::io;
+use std::ops::Deref;
+use std::path::Path;
+
+#[cfg(all(unix, target_pointer_width = "64"))]
+mod sys {
+    use std::ffi::c_void;
+    use std::fs::File;
+    use std::os::unix::io::AsRawFd;
+
+    const PROT_READ: i32 = 0x1;
+    const MAP_PRIVATE: i32 = 0x2;
+
+    unsafe extern "C" {
+        fn mmap(addr: *mut c_void, len: usize, prot: i32, flags: i32, fd: i32, offset: i64) -> *mut c_void;
+        fn munmap(addr: *mut c_void, len: usize) -> i32;
+    }
+
+    pub fn map(file: &File, len: usize) -> Option<*const u8> {
+        // SAFETY: a fresh read-only private mapping of an open descriptor;
+        // the result is checked against MAP_FAILED before use.
+        let ptr = unsafe {
+            mmap(std::ptr::null_mut(), len, PROT_READ, MAP_PRIVATE, file.as_raw_fd(), 0)
+        };
+
+        match ptr as isize {
+            -1 => None,
+            _ => Some(ptr as *const u8),
+        }
+    }
+
+    pub fn unmap(ptr: *const u8, len: usize) {
+        // SAFETY: `ptr` and `len` come from a successful `map` call and are
+        // unmapped exactly once.
+        unsafe {
+            munmap(ptr as *mut c_void, len);
+        }
+    }
+}
+
+#[cfg(not(all(unix, target_pointer_width = "64")))]
+mod sys {
+    use std::fs::File;
+
+    pub fn map(_: &File, _: usize) -> Option<*const u8> {
+        None
+    }
+
+    pub fn unmap(_: *const u8, _: usize) {}
+}
+
+enum Contents {
+    Mapped { ptr: *const u8, len: usize },
+    Buffered(Vec<u8>),
+}
+
+// A read-only view of a whole file, memory mapped where the platform allows
+// it and read into memory otherwise. Like any mapping, the contents are only
+// stable as long as no other process modifies the file, which is why `open`
+// is unsafe.
+pub struct MappedFile {
+    contents: Contents,
+}
+
+// SAFETY: the mapping is private and read-only, so sharing it between
+// threads is no different from sharing a `&[u8]`.
+unsafe impl Send for MappedFile {}
+unsafe impl Sync for MappedFile {}
+
+impl MappedFile {
+    /// Maps the file at `path`, or reads it where mapping isn't possible.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be truncated or modified, by this or any other
+    /// process, while the `MappedFile` is alive. Even a private mapping
+    /// reads its pages from the file, so a rewrite can change bytes behind
+    /// live `&[u8]` borrows and a truncation makes reading the lost pages
+    /// fault with SIGBUS.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path.as_ref())?;
+        let len = file.metadata()?.len() as usize;
+
+        // Empty files can't be mapped.
+        if len > 0
+            && let Some(ptr) = sys::map(&file, len)
+        {
+            return Ok(Self { contents: Contents::Mapped { ptr, len } });
+        }
+
+        Ok(Self { contents: Contents::Buffered(fs::read(path)?) })
+    }
+
+    pub fn is_mapped(&self) -> bool {
+        matches!(self.contents, Contents::Mapped { .. })
+    }
+}
+
+impl Deref for MappedFile {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match &self.contents {
+            // SAFETY: the mapping stays valid until `drop`.
+            Contents::Mapped { ptr, len } => unsafe { std::slice::from_raw_parts(*ptr, *len) },
+            Contents::Buffered(buf) => buf,
+        }
+    }
+}
+
+impl Drop for MappedFile {
+    fn drop(&mut self) {
+        if let Contents::Mapped { ptr, len } = self.contents {
+            sys::unmap(ptr, len);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+// Unicode normalization form C and case folding, for comparing names. HFS+
+// stores names decomposed, so an `é` read from a Mac is often `e` followed by
+// a combining accent, while most other systems write the single composed
+// character.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::unicode_tables::{CASE_FOLDING, COMBINING_CLASSES, DECOMPOSITIONS};
+
+// Hangul syllables are composed from their jamo arithmetically rather than
+// through the tables.
+const S_BASE: u32 = 0xac00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11a7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+fn combining_class(c: char) -> u8 {
+    let found = COMBINING_CLASSES.binary_search_by(|(first, last, _)| {
+        match (c < *first, c > *last) {
+            (true, _) => std::cmp::Ordering::Greater,
+            (_, true) => std::cmp::Ordering::Less,
+            _ => std::cmp::Ordering::Equal,
+        }
+    });
+
+    match found {
+        Ok(i) => COMBINING_CLASSES[i].2,
+        Err(_) => 0,
+    }
+}
+
+fn decompose(c: char, out: &mut Vec<char>) {
+    let s_index = (c as u32).wrapping_sub(S_BASE);
+
+    if s_index < S_COUNT {
+        out.push(char::from_u32(L_BASE + s_index / N_COUNT).unwrap());
+        out.push(char::from_u32(V_BASE + s_index % N_COUNT / T_COUNT).unwrap());
+        if !s_index.is_multiple_of(T_COUNT) {
+            out.push(char::from_u32(T_BASE + s_index % T_COUNT).unwrap());
+        }
+        return;
+    }
+
+    match DECOMPOSITIONS.binary_search_by_key(&c, |(composed, ..)| *composed) {
+        Ok(i) => {
+            let (_, first, second, _) = DECOMPOSITIONS[i];
+            decompose(first, out);
+            if second != '\0' {
+                decompose(second, out);
+            }
+        }
+        Err(_) => out.push(c),
+    }
+}
+
+fn compose(first: char, second: char) -> Option<char> {
+    static PAIRS: OnceLock<HashMap<(char, char), char>> = OnceLock::new();
+
+    let l_index = (first as u32).wrapping_sub(L_BASE);
+    let v_index = (second as u32).wrapping_sub(V_BASE);
+    if l_index < L_COUNT && v_index < V_COUNT {
+        return char::from_u32(S_BASE + (l_index * V_COUNT + v_index) * T_COUNT);
+    }
+
+    let s_index = (first as u32).wrapping_sub(S_BASE);
+    let t_index = (second as u32).wrapping_sub(T_BASE);
+    if s_index < S_COUNT && s_index.is_multiple_of(T_COUNT) && t_index > 0 && t_index < T_COUNT {
+        return char::from_u32(first as u32 + t_index);
+    }
+
+    let pairs = PAIRS.get_or_init(|| {
+        DECOMPOSITIONS
+            .iter()
+            .filter(|(.., composes)| *composes)
+            .map(|(composed, first, second, _)| ((*first, *second), *composed))
+            .collect()
+    });
+
+    pairs.get(&(first, second)).copied()
+}
+
+pub(crate) fn nfc(value: &str) -> String {
+    let mut chars = Vec::with_capacity(value.len());
+    for c in value.chars() {
+        decompose(c, &mut chars);
+    }
+
+    // Combining marks are put in canonical order, keeping the order of marks
+    // of the same class.
+    let mut start = 0;
+    while start < chars.len() {
+        let end = chars[start..]
+            .iter()
+            .position(|c| combining_class(*c) == 0)
+            .map_or(chars.len(), |length| start + length);
+        chars[start..end].sort_by_key(|c| combining_class(*c));
+        start = end + 1;
+    }
+
+    let mut out = Vec::<char>::with_capacity(chars.len());
+    let mut starter = None;
+    let mut last_class = 0;
+
+    for c in chars {
+        let class = combining_class(c);
+
+        // A mark combines with the last starter unless a mark of the same or
+        // a higher class sits between them.
+        if let Some(i) = starter
+            && (out.len() == i + 1 || (last_class != 0 && last_class < class))
+            && let Some(composed) = compose(out[i], c)
+        {
+            out[i] = composed;
+            continue;
+        }
+
+        if class == 0 {
+            starter = Some(out.len());
+        }
+        last_class = class;
+        out.push(c);
+    }
+
+    out.into_iter().collect()
+}
+
+// Folds `value` for caseless comparison. Unlike `to_lowercase`, this maps
+// `ß` to `ss` and a final `ς` to `σ`, so names differing only in those
+// still match.
+pub(crate) fn case_fold(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match CASE_FOLDING.binary_search_by_key(&c, |(original, _)| *original) {
+            Ok(i) => out.push_str(CASE_FOLDING[i].1),
+            Err(_) => out.push(c),
+        }
+    }
+
+    out
+}
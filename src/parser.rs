@@ -0,0 +1,982 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use crate::apple_double::AppleDouble;
+use crate::ds_store::DsStore;
+use crate::error::DsStoreError;
+use crate::header::{Bud1Header, BuddyHeader, MasterBlock, TocEntry};
+use crate::record::{is_known_structure_id, DataType, Record, Source, Value};
+use crate::report::{ParseReport, Walk, Warning, WarningCode};
+
+// Finder's trees are a handful of levels deep. Anything deeper is a
+// crafted chain of nodes that would otherwise exhaust the stack.
+const MAX_TREE_DEPTH: usize = 64;
+
+// The signature, three root block words and 16 unknown bytes.
+const HEADER_LENGTH: usize = 0x24;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Normal,
+    Lenient,
+    Strict,
+}
+
+// How much of each record's value the parser decodes. Records whose values
+// are skipped are still read, so names and structure ids are always there.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Decode {
+    // Only names and structure ids, e.g. for listing files.
+    Names,
+    // Also icon positions (Iloc).
+    Positions,
+    // Every value.
+    #[default]
+    All,
+}
+
+impl Decode {
+    fn includes(&self, structure_id: &[u8; 4]) -> bool {
+        match self {
+            Self::Names => false,
+            Self::Positions => structure_id == b"Iloc",
+            Self::All => true,
+        }
+    }
+}
+
+pub struct DsStoreParser {
+    file_signature: Vec<u8>,
+    block_size: usize,
+    root_offset_location: usize,
+    root_offset_location_check: usize,
+    index_padding: usize,
+    strict_names: bool,
+    decode: Decode,
+}
+
+impl Default for DsStoreParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DsStoreParser {
+    pub fn new() -> Self {
+        Self {
+            file_signature: vec![
+                0x00, 0x00, 0x00, 0x01,
+                0x42, 0x75, 0x64, 0x31,
+            ],
+            block_size: 0x04,
+            root_offset_location: 0x08,
+            root_offset_location_check: 0x10,
+            index_padding: 0x100,
+            strict_names: false,
+            decode: Decode::All,
+        }
+    }
+
+    // The bytes every file must start with, `00 00 00 01 Bud1` by default.
+    pub fn with_signature(mut self, signature: &[u8]) -> Self {
+        self.file_signature = signature.to_vec();
+        self
+    }
+
+    // Where the header stores the root block offset and its copy, 0x08 and
+    // 0x10 by default.
+    pub fn with_root_offset_locations(mut self, location: usize, check: usize) -> Self {
+        self.root_offset_location = location;
+        self.root_offset_location_check = check;
+        self
+    }
+
+    // Fail on entry names that aren't valid UTF-16, e.g. with unpaired
+    // surrogates, instead of replacing the bad code units with U+FFFD.
+    pub fn with_strict_names(mut self, strict_names: bool) -> Self {
+        self.strict_names = strict_names;
+        self
+    }
+
+    // Which values to decode, every one by default. Skipped values become
+    // `Value::Skipped` without their payload being copied, which saves most
+    // of the time spent on blob heavy files such as those with plists.
+    pub fn with_decode(mut self, decode: Decode) -> Self {
+        self.decode = decode;
+        self
+    }
+
+    // The allocator reserves block address slots before its table of
+    // contents in multiples of this, 0x100 by default. Zero fails every
+    // parse.
+    pub fn with_index_padding(mut self, index_padding: usize) -> Self {
+        self.index_padding = index_padding;
+        self
+    }
+
+    pub fn parse_bytes(&self, buf: &[u8]) -> Result<DsStore, DsStoreError> {
+        let (ds_store, _) = self.parse_bytes_with_report(buf)?;
+        Ok(ds_store)
+    }
+
+    pub fn parse_bytes_with_report(
+        &self,
+        buf: &[u8]
+    ) -> Result<(DsStore, ParseReport), DsStoreError> {
+        self.parse_with(buf, Mode::Normal)
+    }
+
+    // Like `parse_bytes_with_report`, but a failure while walking the B-tree,
+    // e.g. in a truncated file, keeps the records decoded up to that point and
+    // is reported as a warning. Errors in the file header are still fatal.
+    pub fn parse_lenient(&self, buf: &[u8]) -> Result<(DsStore, ParseReport), DsStoreError> {
+        self.parse_with(buf, Mode::Lenient)
+    }
+
+    // Like `parse_bytes_with_report`, but these fail the parse instead of
+    // being reported as warnings:
+    // - an entry with more than one record of the same structure id
+    //   (`DuplicateRecord`)
+    // - a leading word other than `00 00 00 01` (`BadSignature`)
+    // - bytes after the last allocated block (`TrailingData`)
+    pub fn parse_strict(&self, buf: &[u8]) -> Result<(DsStore, ParseReport), DsStoreError> {
+        self.parse_with(buf, Mode::Strict)
+    }
+
+    // Whether the B-tree has a record for an entry called exactly `name`.
+    // Stops at the first match, and neither decodes values nor builds the
+    // tree, so it's much faster than parsing when that's all a caller needs.
+    pub fn contains(&self, buf: &[u8], name: &str) -> Result<bool, DsStoreError> {
+        let buf = &*decompressed(buf)?;
+
+        self.confirm_signature(buf)?;
+        self.confirm_header_length(buf)?;
+
+        let header = self.read_buddy_header(buf)?;
+        let entry_indices = header.block_addresses
+            .iter()
+            .map(|a| *a as usize)
+            .collect::<Vec<_>>();
+
+        self.validate_block_addresses(buf, &entry_indices)?;
+
+        let entry_offset = self.root_node_offset(buf, &header, &entry_indices)?;
+        let name = name
+            .encode_utf16()
+            .flat_map(u16::to_be_bytes)
+            .collect::<Vec<_>>();
+
+        self.find_name(buf, &entry_indices, entry_offset, &mut Walk::default(), &name)
+    }
+
+    fn parse_with(
+        &self,
+        buf: &[u8],
+        mode: Mode
+    ) -> Result<(DsStore, ParseReport), DsStoreError> {
+        let buf = &*decompressed(buf)?;
+
+        if let Err(e) = self.confirm_signature(buf) {
+            return match AppleDouble::parse(buf) {
+                Some(apple_double) => Err(
+                    DsStoreError::AppleDouble { entries: apple_double.entries.len() }
+                ),
+                None => Err(e),
+            };
+        }
+
+        let leading_word = match self.check_leading_word(buf) {
+            Err(e) if matches!(mode, Mode::Strict) => return Err(e),
+            result => result.err(),
+        };
+
+        self.confirm_header_length(buf)?;
+
+        let header = self.read_buddy_header(buf)?;
+        let entry_indices = header.block_addresses
+            .iter()
+            .map(|a| *a as usize)
+            .collect::<Vec<_>>();
+
+        self.validate_block_addresses(buf, &entry_indices)?;
+
+        let master = self.master_block(buf, &header, &entry_indices)?;
+        let entry_offset = self.block_offset(&entry_indices, master.root_node as usize)?;
+
+        let mut root_node = DsStore {
+            name: "DSDB".into(),
+            children: vec![],
+            records: vec![],
+            indent_length: 4,
+        };
+
+        let mut walk = Walk::default();
+        let mut ds_store_tree = Vec::<DsStore>::new();
+        let mut warnings = Vec::<Warning>::new();
+
+        let walked = self.walk_node(
+            buf,
+            &entry_indices,
+            entry_offset,
+            &mut walk,
+            &mut ds_store_tree
+        );
+
+        if let Err(e) = walked {
+            match mode {
+                Mode::Lenient => warnings.push(
+                    Warning::new(
+                        WarningCode::StoppedEarly,
+                        format!("Stopped reading the B-tree early: {}", e)
+                    )
+                ),
+                _ => return Err(e),
+            }
+        }
+
+        let mut report = ParseReport {
+            record_count: ds_store_tree.len(),
+            node_count: walk.visited.len(),
+            bytes_parsed: walk.bytes_parsed,
+            allocated_length: self.allocated_length(buf, &entry_indices)?,
+            warnings,
+        };
+
+        if let Some(e) = leading_word {
+            report.warnings.push(Warning::new(WarningCode::LeadingWord, e.to_string()));
+        }
+
+        if buf.len() > report.allocated_length {
+            let e = DsStoreError::TrailingData {
+                length: buf.len(),
+                allocated_length: report.allocated_length,
+            };
+
+            match mode {
+                Mode::Strict => return Err(e),
+                _ => report.warnings.push(
+                    Warning::new(WarningCode::TrailingData, e.to_string())
+                ),
+            }
+        }
+
+        if self.root_offset_copy_missing(buf)? {
+            report.warnings.push(
+                Warning::new(
+                    WarningCode::RootOffsetCopyMissing,
+                    "Root block offset is stored only once, its copy is zero"
+                )
+            );
+        }
+
+        if master.records as usize != report.record_count {
+            report.warnings.push(
+                Warning::new(
+                    WarningCode::CountMismatch,
+                    format!(
+                        "Master block lists {} records but {} were found",
+                        master.records, report.record_count
+                    )
+                )
+            );
+        }
+        if master.nodes as usize != report.node_count {
+            report.warnings.push(
+                Warning::new(
+                    WarningCode::CountMismatch,
+                    format!(
+                        "Master block lists {} nodes but {} were found",
+                        master.nodes, report.node_count
+                    )
+                )
+            );
+        }
+
+        // Finder writes at most one record per entry and structure id. More
+        // point at corruption, tampering or a botched merge.
+        let mut seen = HashSet::new();
+        let mut duplicates = HashSet::new();
+
+        for record in ds_store_tree.iter().flat_map(|node| &node.records) {
+            let key = (record.name.as_str(), record.structure_id);
+
+            if seen.insert(key) || !duplicates.insert(key) {
+                continue;
+            }
+
+            let e = DsStoreError::DuplicateRecord {
+                name: record.name.clone(),
+                structure_id: record.structure_id,
+            };
+
+            match mode {
+                Mode::Strict => return Err(e),
+                _ => report.warnings.push(
+                    Warning::new(WarningCode::DuplicateRecord, e.to_string())
+                ),
+            }
+        }
+
+        for node in ds_store_tree {
+            root_node.children.push(node);
+        }
+
+        if let Some(conflict) = root_node.view_style_conflict() {
+            report.warnings.push(Warning::new(WarningCode::ViewStyleConflict, conflict));
+        }
+
+        Ok((root_node, report))
+    }
+
+    pub fn read_header(&self, buf: &[u8]) -> Result<Bud1Header, DsStoreError> {
+        self.confirm_signature(buf)?;
+        self.confirm_header_length(buf)?;
+
+        let mut magic = [0u8; 8];
+        magic.copy_from_slice(self.bytes(buf, 0, 8)?);
+        let mut unknown = [0u8; 16];
+        unknown.copy_from_slice(self.bytes(buf, 0x14, 16)?);
+
+        Ok(
+            Bud1Header {
+                magic,
+                root_block_offset: self.block_to_usize(buf, self.root_offset_location)? as u32,
+                root_block_size: self.block_to_usize(buf, 0x0c)? as u32,
+                root_block_offset_check: self.block_to_usize(
+                    buf,
+                    self.root_offset_location_check
+                )? as u32,
+                unknown,
+            }
+        )
+    }
+
+    fn root_offset_copy_missing(&self, buf: &[u8]) -> Result<bool, DsStoreError> {
+        Ok(self.block_to_usize(buf, self.root_offset_location_check)? == 0)
+    }
+
+    pub fn read_buddy_header(&self, buf: &[u8]) -> Result<BuddyHeader, DsStoreError> {
+        let root_offset = self.block_to_usize(buf, self.root_offset_location)?
+            + self.block_size;
+
+        let root_offset_check = self.block_to_usize(buf, self.root_offset_location_check)?
+            + self.block_size;
+
+        debug!("Root block at 0x{:x}, copy at 0x{:x}", root_offset, root_offset_check);
+
+        // Some writers leave the copy zeroed and only store the offset once.
+        if root_offset != root_offset_check && !self.root_offset_copy_missing(buf)? {
+            return Err(
+                DsStoreError::RootOffsetMismatch {
+                    a: root_offset,
+                    b: root_offset_check,
+                }
+            );
+        }
+
+        // A zero (or any other header) offset would make the allocator
+        // overlap the header.
+        if root_offset < HEADER_LENGTH {
+            return Err(
+                DsStoreError::InvalidRootOffset {
+                    offset: root_offset - self.block_size,
+                }
+            );
+        }
+
+        let entry_count = self.block_to_usize(buf, root_offset)?;
+        debug!("{} block addresses", entry_count);
+
+        // The addresses follow the count and 4 unknown bytes. A count larger
+        // than the rest of the file could hold is corrupt, and looping over
+        // it would only fail at the end of the buffer.
+        let first_address = root_offset + 2 * self.block_size;
+        let max_count = buf.len().saturating_sub(first_address) / self.block_size;
+
+        if entry_count > max_count {
+            return Err(
+                DsStoreError::TooManyBlocks {
+                    count: entry_count,
+                    max: max_count,
+                }
+            );
+        }
+
+        let mut block_addresses = Vec::<u32>::with_capacity(entry_count);
+
+        for i in 0..entry_count {
+            block_addresses.push(
+                self.block_to_usize(buf, first_address + self.block_size * i)? as u32
+            );
+        }
+
+        // The address table is padded to a multiple of `index_padding` slots,
+        // so a file with 257 blocks reserves 512 of them.
+        let padded_length = entry_count
+            .checked_next_multiple_of(self.index_padding)
+            .and_then(|slots| slots.checked_mul(self.block_size));
+        let root_content_offset = match padded_length {
+            Some(length) => first_address + length,
+            None => return Err(DsStoreError::OffsetOutOfRange { offset: first_address }),
+        };
+
+        let toc_count = self.block_to_usize(buf, root_content_offset)?;
+        debug!("{} table of contents entries at 0x{:x}", toc_count, root_content_offset);
+        let mut toc = Vec::<TocEntry>::new();
+        let mut cursor = root_content_offset + self.block_size;
+
+        for _ in 0..toc_count {
+            let name_length = self.bytes(buf, cursor, 1)?[0] as usize;
+            let name = match str::from_utf8(self.bytes(buf, cursor + 1, name_length)?) {
+                Ok(name) => name.into(),
+                Err(_) => return Err(DsStoreError::InvalidUtf8),
+            };
+            let block_id = self.block_to_usize(buf, cursor + 1 + name_length)? as u32;
+
+            debug!("Table of contents entry {} -> block {}", name, block_id);
+            toc.push(TocEntry { name, block_id });
+            cursor += 1 + name_length + self.block_size;
+        }
+
+        if toc.is_empty() {
+            return Err(DsStoreError::OffsetOutOfRange { offset: root_content_offset });
+        }
+
+        let mut free_lists = Vec::<Vec<u32>>::new();
+
+        for _ in 0..32 {
+            let count = self.block_to_usize(buf, cursor)?;
+            let mut free_list = Vec::<u32>::new();
+            cursor += self.block_size;
+
+            for _ in 0..count {
+                free_list.push(self.block_to_usize(buf, cursor)? as u32);
+                cursor += self.block_size;
+            }
+
+            free_lists.push(free_list);
+        }
+
+        Ok(
+            BuddyHeader {
+                block_addresses,
+                toc,
+                free_lists,
+            }
+        )
+    }
+
+    // Zero entries are unused slots. Blocks may legitimately extend past the
+    // end of the file, so only their start has to lie inside the buffer.
+    fn validate_block_addresses(
+        &self,
+        buf: &[u8],
+        entry_indices: &[usize]
+    ) -> Result<(), DsStoreError> {
+        for (block_id, entry_index) in entry_indices.iter().enumerate() {
+            if *entry_index == 0 {
+                continue;
+            }
+
+            let (offset, size) = self.entry_index_to_entry_data(*entry_index);
+
+            if offset + self.block_size > buf.len() {
+                return Err(DsStoreError::BlockOutOfRange { block_id, offset, size });
+            }
+        }
+
+        Ok(())
+    }
+
+    // The end of the last allocated block, the root block included. Finder
+    // never writes past it, so longer files had data appended.
+    fn allocated_length(
+        &self,
+        buf: &[u8],
+        entry_indices: &[usize]
+    ) -> Result<usize, DsStoreError> {
+        let root_offset = self.block_to_usize(buf, self.root_offset_location)? + self.block_size;
+        let root_size = self.block_to_usize(buf, self.root_offset_location + self.block_size)?;
+
+        let block_ends = entry_indices
+            .iter()
+            .filter(|entry_index| **entry_index != 0)
+            .map(|entry_index| self.entry_index_to_entry_data(*entry_index))
+            .map(|(offset, size)| offset + size);
+
+        Ok(block_ends.fold(root_offset + root_size, usize::max))
+    }
+
+    pub fn read_master_block(&self, buf: &[u8]) -> Result<MasterBlock, DsStoreError> {
+        let header = self.read_buddy_header(buf)?;
+        let entry_indices = header.block_addresses
+            .iter()
+            .map(|a| *a as usize)
+            .collect::<Vec<_>>();
+
+        self.validate_block_addresses(buf, &entry_indices)?;
+        self.master_block(buf, &header, &entry_indices)
+    }
+
+    // Follows the first table of contents entry to its master block.
+    pub(crate) fn master_block(
+        &self,
+        buf: &[u8],
+        header: &BuddyHeader,
+        entry_indices: &[usize]
+    ) -> Result<MasterBlock, DsStoreError> {
+        // Finder lists DSDB first, but other writers may not.
+        let dsdb = match header.toc_block("DSDB") {
+            Some(block_id) => block_id as usize,
+            None => return Err(DsStoreError::MissingDsdb),
+        };
+        let index_offset = self.block_offset(entry_indices, dsdb)?;
+        let word = |i: usize| self.block_to_usize(buf, index_offset + i * self.block_size);
+
+        let master = MasterBlock {
+            root_node: word(0)? as u32,
+            levels: word(1)? as u32,
+            records: word(2)? as u32,
+            nodes: word(3)? as u32,
+            page_size: word(4)? as u32,
+        };
+        debug!("Master block at 0x{:x}: {:?}", index_offset, master);
+
+        Ok(master)
+    }
+
+    // The offset of the B-tree's root node.
+    pub(crate) fn root_node_offset(
+        &self,
+        buf: &[u8],
+        header: &BuddyHeader,
+        entry_indices: &[usize]
+    ) -> Result<usize, DsStoreError> {
+        let master = self.master_block(buf, header, entry_indices)?;
+        let entry_offset = self.block_offset(entry_indices, master.root_node as usize)?;
+
+        Ok(entry_offset)
+    }
+
+    pub(crate) fn generate_ds_store_tree(
+        &self,
+        buf: &[u8],
+        entry_indices: &[usize],
+        offset: usize,
+        walk: &mut Walk
+    ) -> Result<Vec<DsStore>, DsStoreError> {
+        let mut result = Vec::<DsStore>::new();
+
+        trace!("Walking B-tree from 0x{:x}", offset);
+        self.walk_node(buf, entry_indices, offset, walk, &mut result)?;
+
+        Ok(result)
+    }
+
+    fn walk_node(
+        &self,
+        buf: &[u8],
+        entry_indices: &[usize],
+        offset: usize,
+        walk: &mut Walk,
+        result: &mut Vec<DsStore>,
+    ) -> Result<(), DsStoreError> {
+        if !walk.visited.insert(offset) {
+            return Err(DsStoreError::TreeCycle { offset });
+        }
+        if walk.depth == MAX_TREE_DEPTH {
+            return Err(DsStoreError::TreeTooDeep { offset });
+        }
+        walk.depth += 1;
+
+        let mode = self.block_to_usize(buf, offset)?;
+        let record_count = self.block_to_usize(buf, offset + self.block_size)?;
+        let mut cursor = offset + (self.block_size * 2);
+
+        match mode {
+            0 => debug!("Leaf node at 0x{:x} with {} records", offset, record_count),
+            _ => debug!(
+                "Internal node at 0x{:x} with {} records, rightmost child {}",
+                offset, record_count, mode
+            ),
+        }
+
+        for _ in 0..record_count {
+            if mode != 0 {
+                let child_id = self.block_to_usize(buf, cursor)?;
+                let child_offset = self.block_offset(entry_indices, child_id)?;
+
+                self.walk_node(buf, entry_indices, child_offset, walk, result)?;
+                cursor += self.block_size;
+            }
+
+            let (record, next) = self.read_record(buf, cursor)?;
+            result.push(DsStore::from_record(record));
+            cursor = next;
+        }
+
+        walk.bytes_parsed += cursor - offset;
+
+        if mode != 0 {
+            let child_offset = self.block_offset(entry_indices, mode)?;
+            self.walk_node(buf, entry_indices, child_offset, walk, result)?;
+        }
+
+        walk.depth -= 1;
+        Ok(())
+    }
+
+    // Walks the node at `offset` like `walk_node`, comparing the raw UTF-16
+    // names of its records to `name` and skipping their values.
+    fn find_name(
+        &self,
+        buf: &[u8],
+        entry_indices: &[usize],
+        offset: usize,
+        walk: &mut Walk,
+        name: &[u8],
+    ) -> Result<bool, DsStoreError> {
+        if !walk.visited.insert(offset) {
+            return Err(DsStoreError::TreeCycle { offset });
+        }
+        if walk.depth == MAX_TREE_DEPTH {
+            return Err(DsStoreError::TreeTooDeep { offset });
+        }
+        walk.depth += 1;
+
+        let mode = self.block_to_usize(buf, offset)?;
+        let record_count = self.block_to_usize(buf, offset + self.block_size)?;
+        let mut cursor = offset + (self.block_size * 2);
+
+        for _ in 0..record_count {
+            if mode != 0 {
+                let child_id = self.block_to_usize(buf, cursor)?;
+                let child_offset = self.block_offset(entry_indices, child_id)?;
+
+                if self.find_name(buf, entry_indices, child_offset, walk, name)? {
+                    return Ok(true);
+                }
+                cursor += self.block_size;
+            }
+
+            let name_length = self.utf16_length(self.block_to_usize(buf, cursor)?, cursor)?;
+            let name_start = self.checked_offset(cursor, self.block_size)?;
+            if self.bytes(buf, name_start, name_length)? == name {
+                return Ok(true);
+            }
+
+            let name_end = self.checked_offset(name_start, name_length)?;
+            let (_, _, next) = self.read_value(buf, self.checked_offset(name_end, 4)?, false)?;
+            cursor = next;
+        }
+
+        if mode != 0 {
+            let child_offset = self.block_offset(entry_indices, mode)?;
+            if self.find_name(buf, entry_indices, child_offset, walk, name)? {
+                return Ok(true);
+            }
+        }
+
+        walk.depth -= 1;
+        Ok(false)
+    }
+
+    // Records are laid out as a UTF-16 name, a 4 byte structure id, a 4 byte
+    // data type and a payload whose length depends on that data type. Returns
+    // the record at `offset` and the offset right after it.
+    pub fn read_record(
+        &self,
+        buf: &[u8],
+        offset: usize
+    ) -> Result<(Record, usize), DsStoreError> {
+        let name_length = self.utf16_length(self.block_to_usize(buf, offset)?, offset)?;
+        let name_start = self.checked_offset(offset, self.block_size)?;
+        let name = self.decode_name(self.bytes(buf, name_start, name_length)?, offset)?;
+        let name_end = self.checked_offset(name_start, name_length)?;
+
+        let mut structure_id = [0u8; 4];
+        structure_id.copy_from_slice(self.bytes(buf, name_end, 4)?);
+
+        let decode = self.decode.includes(&structure_id);
+        let (data_type, value, data_end) = self.read_value(
+            buf,
+            self.checked_offset(name_end, 4)?,
+            decode
+        )?;
+
+        // Finder may start writing structure ids we don't know yet, and their
+        // values could mean anything, so only the raw payload is kept.
+        let value = match (decode, is_known_structure_id(&structure_id)) {
+            (false, _) | (true, true) => value,
+            (true, false) => Value::Unknown {
+                id: structure_id,
+                raw: buf[name_end + 8..data_end].to_vec(),
+            },
+        };
+
+        trace!(
+            "Record {:?} {} {} at 0x{:x}",
+            name,
+            String::from_utf8_lossy(&structure_id),
+            String::from_utf8_lossy(data_type.tag()),
+            offset
+        );
+
+        let record = Record {
+            name,
+            structure_id,
+            data_type,
+            value,
+            source: Source::Live,
+            offset,
+        };
+
+        Ok((record, data_end))
+    }
+
+    // Without `decode` the payload is only bounds checked and the value is
+    // `Value::Skipped`.
+    fn read_value(
+        &self,
+        buf: &[u8],
+        offset: usize,
+        decode: bool
+    ) -> Result<(DataType, Value, usize), DsStoreError> {
+        let tag = self.bytes(buf, offset, 4)?;
+        let data_type = match DataType::from_tag(tag) {
+            Some(data_type) => data_type,
+            None => {
+                return Err(
+                    DsStoreError::UnknownDataType {
+                        offset,
+                        tag: tag.try_into().unwrap(),
+                    }
+                );
+            }
+        };
+        let data_offset = self.checked_offset(offset, 4)?;
+        // Where blob and ustr payloads start, after their length word.
+        let payload_offset = self.checked_offset(data_offset, self.block_size)?;
+
+        let (value, length) = match data_type {
+            DataType::Bool => (Value::Bool(self.bytes(buf, data_offset, 1)?[0] != 0), 1),
+            DataType::Long => (Value::Long(self.block_to_usize(buf, data_offset)? as u32 as i32), 4),
+            DataType::Shor => (Value::Short(self.block_to_usize(buf, data_offset)? as u16 as i16), 4),
+            DataType::Type => {
+                let mut code = [0u8; 4];
+                code.copy_from_slice(self.bytes(buf, data_offset, 4)?);
+                (Value::Type(code), 4)
+            }
+            DataType::Comp | DataType::Dutc => {
+                let raw = i64::from_be_bytes(
+                    self.bytes(buf, data_offset, 8)?.try_into().unwrap()
+                );
+                match data_type {
+                    DataType::Comp => (Value::Comp(raw), 8),
+                    _ => (Value::dutc(raw), 8),
+                }
+            }
+            DataType::Blob => {
+                let length = self.block_to_usize(buf, data_offset)?;
+                let blob = self.bytes(buf, payload_offset, length)?;
+                let length = self.checked_offset(self.block_size, length)?;
+                match decode {
+                    true => (Value::Blob(blob.to_vec()), length),
+                    false => (Value::Skipped, length),
+                }
+            }
+            DataType::Ustr => {
+                let code_units = self.block_to_usize(buf, data_offset)?;
+                let length = self.utf16_length(code_units, data_offset)?;
+                let ustr = self.bytes(buf, payload_offset, length)?;
+                let length = self.checked_offset(self.block_size, length)?;
+                match decode {
+                    true => (Value::Ustr(self.decode_utf16(ustr)), length),
+                    false => (Value::Skipped, length),
+                }
+            }
+        };
+
+        let value = match decode {
+            true => value,
+            false => Value::Skipped,
+        };
+
+        Ok((data_type, value, self.checked_offset(data_offset, length)?))
+    }
+
+    fn decode_name(&self, raw: &[u8], offset: usize) -> Result<String, DsStoreError> {
+        let name = self.decode_utf16(raw);
+
+        match self.strict_names && has_unpaired_surrogates(raw) {
+            true => Err(DsStoreError::InvalidName { offset, name }),
+            false => Ok(name),
+        }
+    }
+
+    // A trailing odd byte can't form a code unit and is dropped. Record
+    // lengths count code units, so only a caller slicing by hand can hit this.
+    fn decode_utf16(&self, raw: &[u8]) -> String {
+        let chunks = raw.chunks_exact(2);
+
+        if !chunks.remainder().is_empty() {
+            debug!("Dropping trailing byte of odd length UTF-16 string");
+        }
+
+        let utf16_packets = chunks
+            .map(|e| u16::from_be_bytes([e[0], e[1]]))
+            .collect::<Vec<_>>();
+
+        String::from_utf16_lossy(&utf16_packets)
+    }
+
+    // `offset + length`, failing like an out of range read where that
+    // overflows, e.g. for a crafted length on a 32 bit target.
+    fn checked_offset(&self, offset: usize, length: usize) -> Result<usize, DsStoreError> {
+        match offset.checked_add(length) {
+            Some(end) => Ok(end),
+            None => Err(DsStoreError::OffsetOutOfRange { offset }),
+        }
+    }
+
+    // The byte length of `code_units` UTF-16 code units, whose count was read
+    // at `offset`.
+    fn utf16_length(&self, code_units: usize, offset: usize) -> Result<usize, DsStoreError> {
+        match code_units.checked_mul(2) {
+            Some(length) => Ok(length),
+            None => Err(DsStoreError::OffsetOutOfRange { offset }),
+        }
+    }
+
+    fn bytes<'a>(
+        &self,
+        buf: &'a [u8],
+        offset: usize,
+        length: usize
+    ) -> Result<&'a [u8], DsStoreError> {
+        let bytes = offset
+            .checked_add(length)
+            .and_then(|end| buf.get(offset..end));
+
+        match bytes {
+            Some(bytes) => Ok(bytes),
+            None => Err(DsStoreError::OffsetOutOfRange { offset }),
+        }
+    }
+
+    // The signature is a leading word, `00 00 00 01` by default, followed by
+    // the `Bud1` magic. Only the magic is required to match; see
+    // `check_leading_word` for the rest.
+    pub fn confirm_signature(&self, buf: &[u8]) -> Result<(), DsStoreError> {
+        if buf.is_empty() {
+            debug!("Input is empty");
+            return Err(DsStoreError::Empty);
+        }
+
+        if buf.len() < self.file_signature.len() {
+            debug!("Input of {} bytes is too short for a signature", buf.len());
+            return Err(DsStoreError::TooShort { length: buf.len() });
+        }
+
+        let magic_start = self.file_signature.len().min(4);
+        let magic = &self.file_signature[magic_start..];
+        let found = &buf[magic_start..self.file_signature.len()];
+
+        if let Some(i) = magic.iter().zip(found).position(|(a, b)| a != b) {
+            debug!("Magic mismatch: {:?}", String::from_utf8_lossy(found));
+            return Err(
+                DsStoreError::BadMagic {
+                    offset: magic_start + i,
+                    expected: magic.to_vec(),
+                    found: found.to_vec(),
+                }
+            );
+        }
+
+        debug!("Signature ok");
+        Ok(())
+    }
+
+    // Writers disagree on the word before the magic, so a different one is
+    // only reported, except in strict mode. Assumes `confirm_signature`
+    // passed.
+    pub fn check_leading_word(&self, buf: &[u8]) -> Result<(), DsStoreError> {
+        let length = self.file_signature.len().min(4);
+
+        let word = self.file_signature[..length].iter().zip(&buf[..length]);
+
+        match word.enumerate().find(|(_, (a, b))| a != b) {
+            Some((offset, (&expected, &found))) => {
+                debug!("Leading word mismatch at offset {}", offset);
+                Err(DsStoreError::BadSignature { offset, expected, found })
+            }
+            None => Ok(()),
+        }
+    }
+
+    // Files cut off right after the signature would otherwise fail with an
+    // obscure out of range offset.
+    fn confirm_header_length(&self, buf: &[u8]) -> Result<(), DsStoreError> {
+        match buf.len() < HEADER_LENGTH {
+            true => Err(DsStoreError::Truncated { length: buf.len() }),
+            false => Ok(()),
+        }
+    }
+
+    // The file offset of an allocated block. Ids past the end of the address
+    // table and unused slots are both invalid references.
+    fn block_offset(
+        &self,
+        entry_indices: &[usize],
+        block_id: usize
+    ) -> Result<usize, DsStoreError> {
+        match entry_indices.get(block_id) {
+            Some(entry_index) if *entry_index != 0 => {
+                Ok(self.entry_index_to_entry_data(*entry_index).0)
+            }
+            _ => Err(DsStoreError::UnknownBlock { block_id }),
+        }
+    }
+
+    pub(crate) fn entry_index_to_entry_data(&self, entry_index: usize) -> (usize, usize) {
+        let offset = ((entry_index >> 0x5) << 0x5) + self.block_size;
+        let size = 1 << (entry_index & 0x1f);
+        (offset, size)
+    }
+
+    fn block_to_usize(&self, buf: &[u8], offset: usize) -> Result<usize, DsStoreError> {
+        let block = offset
+            .checked_add(4)
+            .and_then(|end| buf.get(offset..end));
+
+        match block {
+            Some(block) => {
+                let value = u32::from_be_bytes(block.try_into().unwrap()) as usize;
+                trace!("Block at 0x{:x}: 0x{:x}", offset, value);
+                Ok(value)
+            }
+            None => {
+                trace!("Block at 0x{:x} is out of range", offset);
+                Err(DsStoreError::OffsetOutOfRange { offset })
+            }
+        }
+    }
+}
+
+// Archived captures are often gzip-compressed. These are inflated first so
+// that every offset the parser reports refers to the DS_Store file itself.
+pub(crate) fn decompressed(buf: &[u8]) -> Result<Cow<'_, [u8]>, DsStoreError> {
+    #[cfg(feature = "gzip")]
+    if crate::gzip::is_gzip(buf) {
+        return crate::gzip::decompress(buf).map(Cow::Owned);
+    }
+
+    Ok(Cow::Borrowed(buf))
+}
+
+// The only way big endian UTF-16 can be invalid. A literal U+FFFD in a name
+// is fine.
+fn has_unpaired_surrogates(raw: &[u8]) -> bool {
+    let units = raw.chunks_exact(2).map(|e| u16::from_be_bytes([e[0], e[1]]));
+    char::decode_utf16(units).any(|c| c.is_err())
+}
@@ -0,0 +1,240 @@
+// Minimal reader for the binary property lists (`bplist00`) Finder stores in
+// blob records such as `bwsp`, `icvp` and `lsvp`.
+
+use std::collections::HashSet;
+
+// Finder's plists nest a few containers deep. Anything deeper is a crafted
+// chain of arrays or dictionaries that would otherwise exhaust the stack.
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlistValue {
+    Bool(bool),
+    Integer(i64),
+    Real(f64),
+    Date(f64),
+    Data(Vec<u8>),
+    String(String),
+    Uid(u64),
+    Array(Vec<PlistValue>),
+    Dictionary(Vec<(String, PlistValue)>),
+}
+
+impl PlistValue {
+    pub fn get(&self, key: &str) -> Option<&PlistValue> {
+        match self {
+            Self::Dictionary(entries) => entries
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            Self::Integer(value) => Some(*value != 0),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(value) => Some(*value as f64),
+            Self::Real(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[PlistValue]> {
+        match self {
+            Self::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn as_dictionary(&self) -> Option<&[(String, PlistValue)]> {
+        match self {
+            Self::Dictionary(entries) => Some(entries),
+            _ => None,
+        }
+    }
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    offsets: Vec<usize>,
+    ref_size: usize,
+    in_progress: HashSet<usize>,
+}
+
+pub fn parse(buf: &[u8]) -> Option<PlistValue> {
+    if buf.len() < 8 + 32 || &buf[0..8] != b"bplist00" {
+        return None;
+    }
+
+    let trailer = &buf[buf.len() - 32..];
+    let offset_size = trailer[6] as usize;
+    let ref_size = trailer[7] as usize;
+    let object_count = be_uint(&trailer[8..16])? as usize;
+    let top_object = be_uint(&trailer[16..24])? as usize;
+    let table_offset = be_uint(&trailer[24..32])? as usize;
+
+    if offset_size == 0 || ref_size == 0 {
+        return None;
+    }
+
+    let table_length = object_count.checked_mul(offset_size)?;
+    let table = buf.get(table_offset..table_offset.checked_add(table_length)?)?;
+    let offsets = table
+        .chunks(offset_size)
+        .map(|chunk| be_uint(chunk).map(|o| o as usize))
+        .collect::<Option<Vec<_>>>()?;
+
+    let mut reader = Reader {
+        buf,
+        offsets,
+        ref_size,
+        in_progress: HashSet::new(),
+    };
+
+    reader.object(top_object)
+}
+
+fn be_uint(bytes: &[u8]) -> Option<u64> {
+    if bytes.len() > 8 {
+        return None;
+    }
+
+    Some(bytes.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64))
+}
+
+impl Reader<'_> {
+    fn bytes(&self, offset: usize, length: usize) -> Option<&[u8]> {
+        self.buf.get(offset..offset.checked_add(length)?)
+    }
+
+    // Returns the object length encoded in the marker's low nibble, reading
+    // the trailing integer object when the nibble is 0xf.
+    fn length(&self, marker: u8, offset: usize) -> Option<(usize, usize)> {
+        let low = (marker & 0x0f) as usize;
+
+        if low != 0x0f {
+            return Some((low, offset + 1));
+        }
+
+        let int_marker = *self.buf.get(offset + 1)?;
+        if int_marker >> 4 != 0x1 {
+            return None;
+        }
+
+        let size = 1usize << (int_marker & 0x0f);
+        let length = be_uint(self.bytes(offset + 2, size)?)? as usize;
+        Some((length, offset + 2 + size))
+    }
+
+    fn refs(&self, offset: usize, count: usize) -> Option<Vec<usize>> {
+        self.bytes(offset, count.checked_mul(self.ref_size)?)?
+            .chunks(self.ref_size)
+            .map(|chunk| be_uint(chunk).map(|r| r as usize))
+            .collect()
+    }
+
+    fn object(&mut self, index: usize) -> Option<PlistValue> {
+        // Every object on the path from the top object is in progress, so
+        // the set's size is the current nesting depth.
+        if self.in_progress.len() == MAX_DEPTH || self.in_progress.contains(&index) {
+            return None;
+        }
+
+        let offset = *self.offsets.get(index)?;
+        let marker = *self.buf.get(offset)?;
+
+        self.in_progress.insert(index);
+        let value = self.decode(marker, offset);
+        self.in_progress.remove(&index);
+
+        value
+    }
+
+    fn decode(&mut self, marker: u8, offset: usize) -> Option<PlistValue> {
+        match marker >> 4 {
+            0x0 => match marker {
+                0x08 => Some(PlistValue::Bool(false)),
+                0x09 => Some(PlistValue::Bool(true)),
+                _ => None,
+            },
+            0x1 => {
+                let size = 1usize << (marker & 0x0f);
+                let raw = be_uint(self.bytes(offset + 1, size)?)?;
+                Some(PlistValue::Integer(raw as i64))
+            }
+            0x2 | 0x3 => {
+                let size = 1usize << (marker & 0x0f);
+                let raw = self.bytes(offset + 1, size)?;
+                let value = match size {
+                    4 => f32::from_be_bytes(raw.try_into().ok()?) as f64,
+                    8 => f64::from_be_bytes(raw.try_into().ok()?),
+                    _ => return None,
+                };
+                match marker >> 4 {
+                    0x2 => Some(PlistValue::Real(value)),
+                    _ => Some(PlistValue::Date(value)),
+                }
+            }
+            0x4 => {
+                let (length, start) = self.length(marker, offset)?;
+                Some(PlistValue::Data(self.bytes(start, length)?.to_vec()))
+            }
+            0x5 => {
+                let (length, start) = self.length(marker, offset)?;
+                let raw = self.bytes(start, length)?;
+                Some(PlistValue::String(raw.iter().map(|b| *b as char).collect()))
+            }
+            0x6 => {
+                let (length, start) = self.length(marker, offset)?;
+                let units = self.bytes(start, length.checked_mul(2)?)?
+                    .chunks(2)
+                    .map(|e| u16::from_be_bytes([e[0], e[1]]))
+                    .collect::<Vec<_>>();
+                Some(PlistValue::String(String::from_utf16_lossy(&units)))
+            }
+            0x8 => {
+                let size = (marker & 0x0f) as usize + 1;
+                Some(PlistValue::Uid(be_uint(self.bytes(offset + 1, size)?)?))
+            }
+            0xa => {
+                let (count, start) = self.length(marker, offset)?;
+                let refs = self.refs(start, count)?;
+                let mut items = Vec::with_capacity(refs.len());
+                for r in refs {
+                    items.push(self.object(r)?);
+                }
+                Some(PlistValue::Array(items))
+            }
+            0xd => {
+                let (count, start) = self.length(marker, offset)?;
+                let keys = self.refs(start, count)?;
+                let values = self.refs(start + count * self.ref_size, count)?;
+                let mut entries = Vec::with_capacity(count);
+                for (k, v) in keys.into_iter().zip(values) {
+                    let key = match self.object(k)? {
+                        PlistValue::String(key) => key,
+                        _ => return None,
+                    };
+                    entries.push((key, self.object(v)?));
+                }
+                Some(PlistValue::Dictionary(entries))
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,525 @@
+#[cfg(feature = "plist")]
+use crate::plist::{self, PlistValue};
+use std::fmt;
+
+use crate::ds_store::DsStore;
+use crate::record::{dutc_to_unix_secs, Record, Value};
+
+// A coordinate is `None` when Finder left it unset, i.e. the icon has no
+// fixed position along that axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IconLocation {
+    pub x: Option<i32>,
+    pub y: Option<i32>,
+}
+
+// Values Finder writes for a coordinate that isn't set: -1 as a 32 bit or as a
+// 16 bit word. The latter means a real coordinate of 65535 can't be told
+// apart and reads as unset too, which no window is wide enough to need.
+const UNSET_COORDINATES: [u32; 2] = [0xffff_ffff, 0xffff];
+
+fn coordinate(bytes: &[u8]) -> Option<i32> {
+    let raw = u32::from_be_bytes(bytes.try_into().unwrap());
+
+    match UNSET_COORDINATES.contains(&raw) {
+        true => None,
+        false => Some(raw as i32),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Background {
+    Default,
+    Color { r: u16, g: u16, b: u16 },
+    // The image itself lives in the folder's `pict` record.
+    Picture { blob_len: u32 },
+}
+
+// The view code is one of `icnv` (icons), `clmv` (columns), `Nlsv` (list)
+// or `Flwv` (cover flow).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowInfo {
+    pub top: i16,
+    pub left: i16,
+    pub bottom: i16,
+    pub right: i16,
+    pub view: [u8; 4],
+}
+
+// Guesses the file extension of an image from its magic bytes.
+pub fn image_extension(data: &[u8]) -> Option<&'static str> {
+    const SIGNATURES: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "png"),
+        (b"\xff\xd8\xff", "jpg"),
+        (b"GIF87a", "gif"),
+        (b"GIF89a", "gif"),
+        (b"II*\x00", "tiff"),
+        (b"MM\x00*", "tiff"),
+        (b"BM", "bmp"),
+        (b"icns", "icns"),
+        (b"%PDF", "pdf"),
+    ];
+
+    SIGNATURES
+        .iter()
+        .find(|(magic, _)| data.starts_with(magic))
+        .map(|(_, extension)| *extension)
+}
+
+// How Finder shows a folder's contents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewStyle {
+    Icon,
+    List,
+    Column,
+    CoverFlow,
+    Gallery,
+}
+
+impl ViewStyle {
+    // Maps the view codes used by `fwi0` and `vstl`.
+    pub fn from_code(code: &[u8; 4]) -> Option<Self> {
+        match code {
+            b"icnv" => Some(Self::Icon),
+            b"Nlsv" => Some(Self::List),
+            b"clmv" => Some(Self::Column),
+            b"Flwv" => Some(Self::CoverFlow),
+            b"glyv" => Some(Self::Gallery),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> &'static [u8; 4] {
+        match self {
+            Self::Icon => b"icnv",
+            Self::List => b"Nlsv",
+            Self::Column => b"clmv",
+            Self::CoverFlow => b"Flwv",
+            Self::Gallery => b"glyv",
+        }
+    }
+}
+
+impl fmt::Display for ViewStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Icon => "icon",
+            Self::List => "list",
+            Self::Column => "column",
+            Self::CoverFlow => "cover flow",
+            Self::Gallery => "gallery",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(feature = "plist")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowSettings {
+    pub window_bounds: Option<String>,
+    pub sidebar_width: Option<f64>,
+    pub show_toolbar: Option<bool>,
+    pub show_sidebar: Option<bool>,
+    pub show_status_bar: Option<bool>,
+    pub show_pathbar: Option<bool>,
+}
+
+#[cfg(feature = "plist")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IconViewSettings {
+    pub icon_size: Option<f64>,
+    pub grid_spacing: Option<f64>,
+    pub text_size: Option<f64>,
+    // Labels are drawn to the right of the icons otherwise.
+    pub label_on_bottom: Option<bool>,
+    pub show_item_info: Option<bool>,
+}
+
+#[cfg(feature = "plist")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListViewColumn {
+    pub name: String,
+    pub width: Option<f64>,
+    pub visible: Option<bool>,
+    pub ascending: Option<bool>,
+}
+
+#[cfg(feature = "plist")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListViewSettings {
+    pub sort_column: Option<String>,
+    pub sort_ascending: Option<bool>,
+    pub columns: Vec<ListViewColumn>,
+}
+
+#[cfg(feature = "plist")]
+fn list_view_column(name: &str, column: &PlistValue) -> ListViewColumn {
+    ListViewColumn {
+        name: name.into(),
+        width: column.get("width").and_then(PlistValue::as_f64),
+        visible: column.get("visible").and_then(PlistValue::as_bool),
+        ascending: column.get("ascending").and_then(PlistValue::as_bool),
+    }
+}
+
+impl Record {
+    // The value of this record if it has the given structure id.
+    fn value_of(&self, structure_id: &[u8; 4]) -> Option<&Value> {
+        match &self.structure_id == structure_id {
+            true => Some(&self.value),
+            false => None,
+        }
+    }
+
+    fn blob_for(&self, structure_id: &[u8; 4]) -> Option<&[u8]> {
+        match self.value_of(structure_id)? {
+            Value::Blob(blob) => Some(blob),
+            _ => None,
+        }
+    }
+
+    // Spotlight comments are stored as plain ustr values.
+    pub fn comment(&self) -> Option<&str> {
+        match self.value_of(b"cmmt")? {
+            Value::Ustr(comment) => Some(comment),
+            _ => None,
+        }
+    }
+
+    // extn holds an entry's file name extension as a ustr, without the dot.
+    // The layouts of the fdsc and clip records that often come with it are
+    // unknown, so those only have their raw values.
+    pub fn extension(&self) -> Option<&str> {
+        match self.value_of(b"extn")? {
+            Value::Ustr(extension) => Some(extension),
+            _ => None,
+        }
+    }
+
+    // dscl is true for folders whose disclosure triangle was left open in
+    // list view.
+    pub fn expanded(&self) -> Option<bool> {
+        match self.value_of(b"dscl")? {
+            Value::Bool(expanded) => Some(*expanded),
+            _ => None,
+        }
+    }
+
+    // vSrn is a long giving the version of the folder's view settings.
+    // Finder currently writes 1.
+    pub fn view_settings_version(&self) -> Option<i32> {
+        match self.value_of(b"vSrn")? {
+            Value::Long(version) => Some(*version),
+            _ => None,
+        }
+    }
+
+    // GRP0 names the key list view items are grouped by, e.g. `Kind`.
+    pub fn grouping(&self) -> Option<&str> {
+        match self.value_of(b"GRP0")? {
+            Value::Ustr(grouping) => Some(grouping),
+            Value::Type(grouping) => std::str::from_utf8(grouping).ok(),
+            _ => None,
+        }
+    }
+
+    // modD and moDD hold an entry's modification time, either as a dutc
+    // value or as a blob with the same ticks in little endian order. Returns
+    // seconds since the Unix epoch.
+    pub fn modified(&self) -> Option<i64> {
+        if &self.structure_id != b"modD" && &self.structure_id != b"moDD" {
+            return None;
+        }
+
+        match &self.value {
+            Value::Dutc { unix_secs, .. } => Some(*unix_secs),
+            Value::Blob(blob) if blob.len() == 8 => Some(
+                dutc_to_unix_secs(i64::from_le_bytes(blob[..].try_into().unwrap()))
+            ),
+            _ => None,
+        }
+    }
+
+    // logS and lg1S hold an entry's logical size in bytes, phyS and ph1S
+    // the space it takes on disk. Finder writes comp values; older versions
+    // wrote the same 64 bits as a big endian blob.
+    pub fn logical_size(&self) -> Option<u64> {
+        match &self.structure_id {
+            b"logS" | b"lg1S" => self.size(),
+            _ => None,
+        }
+    }
+
+    pub fn physical_size(&self) -> Option<u64> {
+        match &self.structure_id {
+            b"phyS" | b"ph1S" => self.size(),
+            _ => None,
+        }
+    }
+
+    fn size(&self) -> Option<u64> {
+        match &self.value {
+            Value::Comp(size) => u64::try_from(*size).ok(),
+            Value::Blob(blob) if blob.len() == 8 => Some(
+                u64::from_be_bytes(blob[..].try_into().unwrap())
+            ),
+            _ => None,
+        }
+    }
+
+    // Iloc blobs hold two big endian i32 coordinates followed by padding.
+    // Either coordinate may be one of the unset sentinels.
+    pub fn icon_location(&self) -> Option<IconLocation> {
+        let blob = self.blob_for(b"Iloc")?;
+
+        if blob.len() < 8 {
+            return None;
+        }
+
+        Some(
+            IconLocation {
+                x: coordinate(&blob[0..4]),
+                y: coordinate(&blob[4..8]),
+            }
+        )
+    }
+
+    // BKGD blobs are 12 bytes: a 4 byte kind followed by its parameters.
+    pub fn background(&self) -> Option<Background> {
+        let blob = self.blob_for(b"BKGD")?;
+
+        if blob.len() < 12 {
+            return None;
+        }
+
+        let short = |i: usize| u16::from_be_bytes([blob[i], blob[i + 1]]);
+
+        match &blob[0..4] {
+            b"DefB" => Some(Background::Default),
+            b"ClrB" => Some(Background::Color { r: short(4), g: short(6), b: short(8) }),
+            b"PctB" => Some(
+                Background::Picture {
+                    blob_len: u32::from_be_bytes(blob[4..8].try_into().unwrap()),
+                }
+            ),
+            _ => None,
+        }
+    }
+
+    // The payload of a folder's `pict` record, which holds its background
+    // picture when `BKGD` is `PctB`.
+    pub fn picture(&self) -> Option<&[u8]> {
+        self.blob_for(b"pict")
+    }
+
+    // fwi0 blobs are 16 bytes: the window rectangle as four i16s, the view
+    // code and 4 bytes of unknown flags.
+    pub fn window_info(&self) -> Option<WindowInfo> {
+        let blob = self.blob_for(b"fwi0")?;
+
+        if blob.len() < 12 {
+            return None;
+        }
+
+        let short = |i: usize| i16::from_be_bytes([blob[i], blob[i + 1]]);
+
+        Some(
+            WindowInfo {
+                top: short(0),
+                left: short(2),
+                bottom: short(4),
+                right: short(6),
+                view: blob[8..12].try_into().unwrap(),
+            }
+        )
+    }
+
+    #[cfg(feature = "plist")]
+    pub fn plist(&self) -> Option<PlistValue> {
+        match &self.value {
+            Value::Blob(blob) => plist::parse(blob),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "plist")]
+    pub fn window_settings(&self) -> Option<WindowSettings> {
+        let settings = plist::parse(self.blob_for(b"bwsp")?)?;
+        let flag = |key| settings.get(key).and_then(PlistValue::as_bool);
+
+        Some(
+            WindowSettings {
+                window_bounds: settings
+                    .get("WindowBounds")
+                    .and_then(PlistValue::as_str)
+                    .map(String::from),
+                sidebar_width: settings.get("SidebarWidth").and_then(PlistValue::as_f64),
+                show_toolbar: flag("ShowToolbar"),
+                show_sidebar: flag("ShowSidebar"),
+                show_status_bar: flag("ShowStatusBar"),
+                show_pathbar: flag("ShowPathbar"),
+            }
+        )
+    }
+
+    #[cfg(feature = "plist")]
+    pub fn icon_view_settings(&self) -> Option<IconViewSettings> {
+        let settings = plist::parse(self.blob_for(b"icvp")?)?;
+        let number = |key| settings.get(key).and_then(PlistValue::as_f64);
+        let flag = |key| settings.get(key).and_then(PlistValue::as_bool);
+
+        Some(
+            IconViewSettings {
+                icon_size: number("iconSize"),
+                grid_spacing: number("gridSpacing"),
+                text_size: number("textSize"),
+                label_on_bottom: flag("labelOnBottom"),
+                show_item_info: flag("showItemInfo"),
+            }
+        )
+    }
+
+    // `lsvp` keys its columns by name, while the older `lsvP` lists them in
+    // an array with an `identifier` each. Both are accepted under either id.
+    #[cfg(feature = "plist")]
+    pub fn list_view_settings(&self) -> Option<ListViewSettings> {
+        let blob = match self.blob_for(b"lsvp") {
+            Some(blob) => blob,
+            None => self.blob_for(b"lsvP")?,
+        };
+        let settings = plist::parse(blob)?;
+
+        let columns = match settings.get("columns") {
+            Some(PlistValue::Dictionary(columns)) => columns
+                .iter()
+                .map(|(name, column)| list_view_column(name, column))
+                .collect(),
+            Some(PlistValue::Array(columns)) => columns
+                .iter()
+                .filter_map(|column| {
+                    let name = column.get("identifier")?.as_str()?;
+                    Some(list_view_column(name, column))
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        let sort_column = settings
+            .get("sortColumn")
+            .and_then(PlistValue::as_str)
+            .map(String::from);
+        let sort_ascending = columns
+            .iter()
+            .find(|c| Some(&c.name) == sort_column.as_ref())
+            .and_then(|c| c.ascending);
+
+        Some(
+            ListViewSettings {
+                sort_column,
+                sort_ascending,
+                columns,
+            }
+        )
+    }
+}
+
+impl DsStore {
+    pub fn comment(&self) -> Option<&str> {
+        self.records.iter().find_map(Record::comment)
+    }
+
+    pub fn modified(&self) -> Option<i64> {
+        self.records.iter().find_map(Record::modified)
+    }
+
+    pub fn expanded(&self) -> Option<bool> {
+        self.records.iter().find_map(Record::expanded)
+    }
+
+    pub fn extension(&self) -> Option<&str> {
+        self.records.iter().find_map(Record::extension)
+    }
+
+    pub fn grouping(&self) -> Option<&str> {
+        self.records.iter().find_map(Record::grouping)
+    }
+
+    pub fn view_settings_version(&self) -> Option<i32> {
+        self.records.iter().find_map(Record::view_settings_version)
+    }
+
+    pub fn logical_size(&self) -> Option<u64> {
+        self.records.iter().find_map(Record::logical_size)
+    }
+
+    pub fn physical_size(&self) -> Option<u64> {
+        self.records.iter().find_map(Record::physical_size)
+    }
+
+    // The folder's view style. `fwi0` wins over `vstl`; if neither is there
+    // the style is inferred from which of `icvp` and `lsvp` exists, as
+    // Finder only writes the settings of views that were used.
+    pub fn view_style(&self) -> Option<ViewStyle> {
+        let (explicit, inferred) = self.view_style_sources();
+
+        match explicit.first() {
+            Some((_, style)) => Some(*style),
+            None => inferred,
+        }
+    }
+
+    // Describes a disagreement between `fwi0` and `vstl`, if any.
+    pub(crate) fn view_style_conflict(&self) -> Option<String> {
+        let (explicit, _) = self.view_style_sources();
+        let (source, style) = explicit.first()?;
+
+        explicit
+            .iter()
+            .find(|(_, other)| other != style)
+            .map(|(other_source, other)| {
+                format!(
+                    "Folder view style is {} according to {} but {} according to {}",
+                    style, source, other, other_source
+                )
+            })
+    }
+
+    // Styles named by the folder's `fwi0` and `vstl` records, in that order,
+    // and the one implied by its view settings.
+    fn view_style_sources(&self) -> (Vec<(&'static str, ViewStyle)>, Option<ViewStyle>) {
+        let mut fwi0 = vec![];
+        let mut vstl = vec![];
+        let mut has_icon_settings = false;
+        let mut has_list_settings = false;
+
+        for (_, record) in self.iter().filter(|(_, r)| r.name == "." && !r.is_recovered()) {
+            match &record.structure_id {
+                b"fwi0" => fwi0.extend(
+                    record
+                        .window_info()
+                        .and_then(|info| ViewStyle::from_code(&info.view))
+                        .map(|style| ("fwi0", style))
+                ),
+                b"vstl" => {
+                    if let Value::Type(code) = &record.value
+                        && let Some(style) = ViewStyle::from_code(code)
+                    {
+                        vstl.push(("vstl", style));
+                    }
+                }
+                b"icvp" | b"icvo" => has_icon_settings = true,
+                b"lsvp" | b"lsvP" | b"lsvo" => has_list_settings = true,
+                _ => {}
+            }
+        }
+
+        let inferred = match (has_icon_settings, has_list_settings) {
+            (true, false) => Some(ViewStyle::Icon),
+            (false, true) => Some(ViewStyle::List),
+            _ => None,
+        };
+
+        fwi0.append(&mut vstl);
+        (fwi0, inferred)
+    }
+}
@@ -0,0 +1,220 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataType {
+    Long,
+    Shor,
+    Bool,
+    Blob,
+    Type,
+    Ustr,
+    Comp,
+    Dutc,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Long(i32),
+    Short(i16),
+    Bool(bool),
+    Blob(Vec<u8>),
+    Ustr(String),
+    Type([u8; 4]),
+    Comp(i64),
+    Dutc { raw: i64, unix_secs: i64 },
+    // The payload of a record whose structure id isn't known, as the bytes
+    // after the data type, including the length prefix of blob and ustr
+    // values.
+    Unknown { id: [u8; 4], raw: Vec<u8> },
+    // A value left undecoded by `DsStoreParser::with_decode`.
+    Skipped,
+}
+
+// Where a record was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Source {
+    // The live B-tree.
+    Live,
+    // Free blocks or blocks the B-tree no longer references.
+    FreeSpace,
+    // A node found by scanning input without a file header.
+    Carved,
+}
+
+impl Source {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Live => "live",
+            Self::FreeSpace => "free-space",
+            Self::Carved => "carved",
+        }
+    }
+}
+
+// Structure ids Finder is known to write.
+pub const KNOWN_STRUCTURE_IDS: &[&str] = &[
+    "BKGD", "GRP0", "ICVO", "Iloc", "LSVO", "bwsp", "clip", "cmmt", "dilc",
+    "dscl", "extn", "fdsc", "fwi0", "fwsw", "fwvh", "icgo", "icsp", "icvo",
+    "icvp", "icvt", "info", "lg1S", "logS", "lssp", "lsvC", "lsvP", "lsvo",
+    "lsvp", "lsvt", "moDD", "modD", "ph1S", "phyS", "pict", "ptbL", "ptbN",
+    "vSrn", "vstl",
+];
+
+pub(crate) fn is_known_structure_id(structure_id: &[u8; 4]) -> bool {
+    KNOWN_STRUCTURE_IDS.iter().any(|id| id.as_bytes() == structure_id)
+}
+
+// Bytes of an unknown record's payload shown by `value_as_string`.
+const UNKNOWN_SNIPPET_LENGTH: usize = 16;
+
+// Seconds between the classic Mac epoch (1904-01-01) and the Unix epoch.
+const MAC_EPOCH_OFFSET: i64 = 2_082_844_800;
+
+impl Value {
+    // dutc values count 1/65536 second ticks since the Mac epoch.
+    pub fn dutc(raw: i64) -> Self {
+        Self::Dutc {
+            raw,
+            unix_secs: dutc_to_unix_secs(raw),
+        }
+    }
+}
+
+pub(crate) fn dutc_to_unix_secs(raw: i64) -> i64 {
+    raw.div_euclid(65_536) - MAC_EPOCH_OFFSET
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub(crate) name: String,
+    pub(crate) structure_id: [u8; 4],
+    pub(crate) data_type: DataType,
+    pub(crate) value: Value,
+    pub(crate) source: Source,
+    pub(crate) offset: usize,
+}
+
+impl DataType {
+    pub fn from_tag(tag: &[u8]) -> Option<Self> {
+        match tag {
+            b"long" => Some(Self::Long),
+            b"shor" => Some(Self::Shor),
+            b"bool" => Some(Self::Bool),
+            b"blob" => Some(Self::Blob),
+            b"type" => Some(Self::Type),
+            b"ustr" => Some(Self::Ustr),
+            b"comp" => Some(Self::Comp),
+            b"dutc" => Some(Self::Dutc),
+            _ => None,
+        }
+    }
+
+    pub fn tag(&self) -> &'static [u8; 4] {
+        match self {
+            Self::Long => b"long",
+            Self::Shor => b"shor",
+            Self::Bool => b"bool",
+            Self::Blob => b"blob",
+            Self::Type => b"type",
+            Self::Ustr => b"ustr",
+            Self::Comp => b"comp",
+            Self::Dutc => b"dutc",
+        }
+    }
+}
+
+impl Record {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn structure_id(&self) -> [u8; 4] {
+        self.structure_id
+    }
+
+    pub fn structure_id_str(&self) -> String {
+        String::from_utf8_lossy(&self.structure_id).into()
+    }
+
+    pub fn data_type(&self) -> DataType {
+        self.data_type
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    // True for records carved out of free space rather than read from the
+    // live B-tree.
+    pub fn is_recovered(&self) -> bool {
+        self.source == Source::FreeSpace
+    }
+
+    pub fn source(&self) -> Source {
+        self.source
+    }
+
+    // Byte offset in the file where the record starts.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    // Human readable form of the value for output formats: blobs in hex,
+    // ustr values quoted, type codes as their four characters and dutc
+    // values as a UTC timestamp. Unknown records show their structure id and
+    // the start of their payload. Unlike the CSV form it tells the types
+    // apart, e.g. the string "1" from the number 1.
+    pub fn value_as_string(&self) -> String {
+        match &self.value {
+            Value::Ustr(value) => format!("{:?}", value),
+            Value::Dutc { unix_secs, .. } => format_timestamp(*unix_secs),
+            Value::Unknown { id, raw } if raw.len() > UNKNOWN_SNIPPET_LENGTH => format!(
+                "{} {}…",
+                String::from_utf8_lossy(id),
+                to_hex(&raw[..UNKNOWN_SNIPPET_LENGTH])
+            ),
+            value => value_to_string(value),
+        }
+    }
+}
+
+// Formats seconds since the Unix epoch as `YYYY-MM-DD HH:MM:SS UTC`.
+fn format_timestamp(unix_secs: i64) -> String {
+    let days = unix_secs.div_euclid(86_400);
+    let secs = unix_secs.rem_euclid(86_400);
+
+    // Howard Hinnant's days_from_civil, inverted.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year, month, day, secs / 3600, secs % 3600 / 60, secs % 60
+    )
+}
+
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// Plain text form of a value as used by the CSV and diff output.
+pub(crate) fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::Long(value) => value.to_string(),
+        Value::Short(value) => value.to_string(),
+        Value::Bool(value) => value.to_string(),
+        Value::Blob(value) => to_hex(value),
+        Value::Ustr(value) => value.clone(),
+        Value::Type(value) => String::from_utf8_lossy(value).into(),
+        Value::Comp(value) => value.to_string(),
+        Value::Dutc { unix_secs, .. } => unix_secs.to_string(),
+        Value::Unknown { id, raw } => format!("{} {}", String::from_utf8_lossy(id), to_hex(raw)),
+        Value::Skipped => String::new(),
+    }
+}
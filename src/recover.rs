@@ -0,0 +1,136 @@
+use crate::error::DsStoreError;
+use crate::parser::{decompressed, DsStoreParser};
+use crate::record::{Record, Source, Value};
+use crate::report::Walk;
+
+// HFS+ limits file names to 255 UTF-16 code units.
+const MAX_NAME_LENGTH: usize = 255;
+
+impl DsStoreParser {
+    // Scans free blocks, and allocated blocks the B-tree no longer references,
+    // for leftover records. Returns nothing if the allocator header itself
+    // can't be read.
+    pub fn recover(&self, buf: &[u8]) -> Vec<Record> {
+        let buf = match decompressed(buf) {
+            Ok(buf) => buf,
+            Err(_) => return vec![],
+        };
+        let buf = &*buf;
+
+        let regions = match self.unused_regions(buf) {
+            Ok(regions) => regions,
+            Err(_) => return vec![],
+        };
+
+        let mut recovered = Vec::new();
+        for (start, end) in regions {
+            self.scan_region(buf, start, end, &mut recovered);
+        }
+
+        recovered
+    }
+
+    fn unused_regions(&self, buf: &[u8]) -> Result<Vec<(usize, usize)>, DsStoreError> {
+        let header = self.read_buddy_header(buf)?;
+        let entry_indices = header.block_addresses
+            .iter()
+            .map(|a| *a as usize)
+            .collect::<Vec<_>>();
+
+        let mut regions = Vec::new();
+
+        for (log_size, free_list) in header.free_lists.iter().enumerate() {
+            for address in free_list {
+                let start = *address as usize + 4;
+                regions.push((start, start.saturating_add(1 << log_size)));
+            }
+        }
+
+        // Everything but the allocator block, the TOC blocks and the nodes
+        // reachable from the root is unreferenced.
+        let mut walk = Walk::default();
+        if let Ok(root) = self.root_node_offset(buf, &header, &entry_indices) {
+            let _ = self.generate_ds_store_tree(buf, &entry_indices, root, &mut walk);
+        }
+
+        for (id, index) in entry_indices.iter().enumerate() {
+            let (offset, size) = self.entry_index_to_entry_data(*index);
+            let referenced = id == 0
+                || header.toc.iter().any(|e| e.block_id as usize == id)
+                || walk.visited.contains(&offset);
+
+            if !referenced && *index != 0 {
+                regions.push((offset, offset.saturating_add(size)));
+            }
+        }
+
+        let mut regions = regions
+            .into_iter()
+            .map(|(start, end)| (start, end.min(buf.len())))
+            .filter(|(start, end)| start < end)
+            .collect::<Vec<_>>();
+
+        // Corrupted free lists can name the same space many times over, so
+        // merge overlapping regions to scan every byte at most once.
+        regions.sort();
+        let mut merged = Vec::<(usize, usize)>::new();
+
+        for (start, end) in regions {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+
+        Ok(merged)
+    }
+
+    fn scan_region(&self, buf: &[u8], start: usize, end: usize, out: &mut Vec<Record>) {
+        let region = &buf[..end];
+        let mut offset = start;
+
+        while offset + 12 <= end {
+            match self.read_record(region, offset) {
+                Ok((mut record, next)) if Self::plausible(region, offset, &record) => {
+                    record.source = Source::FreeSpace;
+                    out.push(record);
+                    offset = next;
+                }
+                _ => offset += 1,
+            }
+        }
+    }
+
+    // Free space is mostly zeros and fragments of old data, so only accept
+    // records whose name and structure id look like something Finder wrote.
+    pub(crate) fn plausible(buf: &[u8], offset: usize, record: &Record) -> bool {
+        let name_length = u32::from_be_bytes(buf[offset..offset + 4].try_into().unwrap()) as usize;
+
+        if name_length == 0 || name_length > MAX_NAME_LENGTH {
+            return false;
+        }
+
+        let units = buf[offset + 4..offset + 4 + name_length * 2]
+            .chunks(2)
+            .map(|e| u16::from_be_bytes([e[0], e[1]]))
+            .collect::<Vec<_>>();
+
+        let name = match String::from_utf16(&units) {
+            Ok(name) => name,
+            Err(_) => return false,
+        };
+
+        if name.chars().any(char::is_control) {
+            return false;
+        }
+
+        if !record.structure_id.iter().all(|b| b.is_ascii_alphanumeric()) {
+            return false;
+        }
+
+        match &record.value {
+            Value::Ustr(value) => !value.contains('\u{fffd}'),
+            _ => true,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+use std::fmt;
+
+// Details about a parse that aren't part of the tree itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseReport {
+    pub record_count: usize,
+    pub node_count: usize,
+    // Bytes of B-tree nodes decoded, including node headers.
+    pub bytes_parsed: usize,
+    // Bytes up to the end of the last allocated block. Anything after that
+    // isn't part of the file as the allocator sees it.
+    pub allocated_length: usize,
+    pub warnings: Vec<Warning>,
+}
+
+// What a warning is about, so callers can act on some kinds and ignore
+// others without matching on messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WarningCode {
+    // A lenient parse gave up on part of the B-tree.
+    StoppedEarly,
+    // The word before the `Bud1` magic isn't `00 00 00 01`.
+    LeadingWord,
+    // There are bytes after the last allocated block.
+    TrailingData,
+    // The header's second copy of the root block offset is zero.
+    RootOffsetCopyMissing,
+    // The master block's record or node count is off.
+    CountMismatch,
+    // An entry has several records with the same structure id.
+    DuplicateRecord,
+    // `fwi0` and `vstl` disagree on the view style.
+    ViewStyleConflict,
+    // The tree was carved from input without a header.
+    Reconstructed,
+}
+
+impl WarningCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::StoppedEarly => "stopped-early",
+            Self::LeadingWord => "leading-word",
+            Self::TrailingData => "trailing-data",
+            Self::RootOffsetCopyMissing => "root-offset-copy-missing",
+            Self::CountMismatch => "count-mismatch",
+            Self::DuplicateRecord => "duplicate-record",
+            Self::ViewStyleConflict => "view-style-conflict",
+            Self::Reconstructed => "reconstructed",
+        }
+    }
+}
+
+impl fmt::Display for WarningCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+// A problem that didn't stop the parse. Displays as its message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    code: WarningCode,
+    message: String,
+}
+
+impl Warning {
+    pub fn new(code: WarningCode, message: impl Into<String>) -> Self {
+        Self { code, message: message.into() }
+    }
+
+    pub fn code(&self) -> WarningCode {
+        self.code
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+// State threaded through a walk of the B-tree.
+#[derive(Default)]
+pub(crate) struct Walk {
+    // The offset of every node walked.
+    pub(crate) visited: HashSet<usize>,
+    pub(crate) depth: usize,
+    pub(crate) bytes_parsed: usize,
+}
@@ -0,0 +1,92 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+use crate::ds_store::DsStore;
+use crate::properties::ViewStyle;
+
+// Aggregate statistics over one parsed file, for triaging many files at a
+// glance rather than reading every tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub entries: usize,
+    pub records: usize,
+    pub structure_ids: BTreeMap<String, usize>,
+    pub comments: usize,
+    pub icon_locations: usize,
+    pub recovered_names: Vec<String>,
+    pub view_style: Option<ViewStyle>,
+}
+
+impl DsStore {
+    pub fn summary(&self) -> Summary {
+        let mut entries = HashSet::new();
+        let mut live = HashSet::new();
+        let mut recovered = HashSet::new();
+        let mut summary = Summary {
+            entries: 0,
+            records: 0,
+            structure_ids: BTreeMap::new(),
+            comments: 0,
+            icon_locations: 0,
+            recovered_names: vec![],
+            view_style: self.view_style(),
+        };
+
+        for (path, record) in self.iter() {
+            summary.records += 1;
+            *summary.structure_ids.entry(record.structure_id_str()).or_default() += 1;
+
+            if record.comment().is_some() {
+                summary.comments += 1;
+            }
+
+            if record.icon_location().is_some() {
+                summary.icon_locations += 1;
+            }
+
+            match record.is_recovered() {
+                true if recovered.insert(path.clone()) => {
+                    summary.recovered_names.push(path.clone());
+                }
+                true => {}
+                false => {
+                    live.insert(path.clone());
+                }
+            }
+
+            entries.insert(path);
+        }
+
+        // Slack space often still holds copies of records that are live, and
+        // those entries weren't deleted.
+        summary.recovered_names.retain(|name| !live.contains(name));
+        summary.entries = entries.len();
+        summary
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Entries: {}", self.entries)?;
+        writeln!(f, "Records: {}", self.records)?;
+
+        for (structure_id, count) in self.structure_ids.iter() {
+            writeln!(f, "    {}: {}", structure_id, count)?;
+        }
+
+        writeln!(f, "Comments: {}", self.comments)?;
+        writeln!(f, "Icon positions: {}", self.icon_locations)?;
+
+        match &self.view_style {
+            Some(view_style) => writeln!(f, "View style: {}", view_style)?,
+            None => writeln!(f, "View style: unknown")?,
+        }
+
+        writeln!(f, "Recovered names: {}", self.recovered_names.len())?;
+        for name in self.recovered_names.iter() {
+            writeln!(f, "    {}", name)?;
+        }
+
+        Ok(())
+    }
+}
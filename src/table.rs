@@ -0,0 +1,165 @@
+use std::io::{self, Write};
+
+use crate::ds_store::DsStore;
+
+const HEADERS: [&str; 3] = ["PATH", "TYPE", "VALUE"];
+const SEPARATOR: &str = " | ";
+
+// Narrowest a text column gets before the table simply overflows.
+const MIN_COLUMN_WIDTH: usize = 8;
+
+// Combining marks, zero width spaces and joiners, and variation selectors
+// take up no column of their own.
+fn is_zero_width(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x0300..=0x036f
+            | 0x0483..=0x0489
+            | 0x0591..=0x05bd
+            | 0x0610..=0x061a
+            | 0x064b..=0x065f
+            | 0x0e31 | 0x0e34..=0x0e3a | 0x0e47..=0x0e4e
+            | 0x1ab0..=0x1aff
+            | 0x1dc0..=0x1dff
+            | 0x200b..=0x200f
+            | 0x20d0..=0x20ff
+            | 0x302a..=0x302f
+            | 0x3099..=0x309a
+            | 0xfe00..=0xfe0f
+            | 0xfe20..=0xfe2f
+            | 0xfeff
+            | 0xe0100..=0xe01ef
+    )
+}
+
+// East Asian wide and fullwidth characters, and emoji, take up two columns.
+fn is_wide(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1100..=0x115f
+            | 0x2e80..=0x303e
+            | 0x3041..=0x33ff
+            | 0x3400..=0x4dbf
+            | 0x4e00..=0x9fff
+            | 0xa000..=0xa4cf
+            | 0xac00..=0xd7a3
+            | 0xf900..=0xfaff
+            | 0xfe30..=0xfe4f
+            | 0xff00..=0xff60
+            | 0xffe0..=0xffe6
+            | 0x1f300..=0x1f64f
+            | 0x1f900..=0x1f9ff
+            | 0x20000..=0x2fffd
+            | 0x30000..=0x3fffd
+    )
+}
+
+fn char_width(c: char) -> usize {
+    match (is_zero_width(c), is_wide(c)) {
+        (true, _) => 0,
+        (_, true) => 2,
+        _ => 1,
+    }
+}
+
+// The number of terminal columns `text` takes up.
+pub(crate) fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+// Cuts `text` down to at most `width` columns, ending in `…` if anything was
+// removed.
+fn truncate(text: &str, width: usize) -> String {
+    if display_width(text) <= width {
+        return text.to_string();
+    }
+
+    let mut out = String::new();
+    let mut used = 0;
+
+    for c in text.chars() {
+        let w = char_width(c);
+        if used + w + 1 > width {
+            break;
+        }
+        out.push(c);
+        used += w;
+    }
+
+    out.push('…');
+    out
+}
+
+fn pad(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(display_width(text));
+    format!("{}{:2$}", text, "", padding)
+}
+
+// Line breaks and other control characters would break the row apart.
+fn single_line(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect()
+}
+
+impl DsStore {
+    // Writes one `PATH | TYPE | VALUE` row per record, in the same order as
+    // `iter`. Columns are as wide as their contents, and when the table would
+    // be wider than `width` the path and value columns are shortened. Values
+    // are rendered by `value_as_string` and only cut afterwards.
+    pub fn write_table<W: Write>(&self, w: &mut W, width: usize) -> io::Result<()> {
+        let rows = self
+            .iter()
+            .map(|(path, record)| [
+                single_line(&path),
+                record.structure_id_str(),
+                single_line(&record.value_as_string()),
+            ])
+            .collect::<Vec<_>>();
+
+        let mut widths = HEADERS.map(display_width);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(display_width(cell));
+            }
+        }
+
+        let available = width.saturating_sub(widths[1] + 2 * SEPARATOR.len());
+
+        if widths[0] + widths[2] > available {
+            // Give the value whatever the path doesn't need, but never less
+            // than half the space.
+            let path = widths[0].min(available.saturating_sub(widths[2]).max(available / 2));
+            widths[0] = path.max(MIN_COLUMN_WIDTH.min(widths[0]));
+            widths[2] = available.saturating_sub(widths[0]).max(MIN_COLUMN_WIDTH);
+        }
+
+        let header = HEADERS.map(String::from);
+        let rule = widths.map(|width| "-".repeat(width));
+
+        writeln!(
+            w,
+            "{}",
+            [
+                pad(&header[0], widths[0]),
+                pad(&header[1], widths[1]),
+                header[2].clone(),
+            ].join(SEPARATOR)
+        )?;
+        writeln!(w, "{}", rule.join("-+-"))?;
+
+        for row in &rows {
+            writeln!(
+                w,
+                "{}",
+                [
+                    pad(&truncate(&row[0], widths[0]), widths[0]),
+                    pad(&row[1], widths[1]),
+                    truncate(&row[2], widths[2]),
+                ].join(SEPARATOR)
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,3076 @@
+// Generated by scripts/unicode_tables.py from Unicode 14.0.0. Do not edit.
+
+// Canonical combining classes of the Basic Multilingual Plane as
+// (first, last, class) ranges. Everything else has class 0.
+pub(crate) const COMBINING_CLASSES: &[(char, char, u8)] = &[
+    ('\u{0300}', '\u{0314}', 230),
+    ('\u{0315}', '\u{0315}', 232),
+    ('\u{0316}', '\u{0319}', 220),
+    ('\u{031a}', '\u{031a}', 232),
+    ('\u{031b}', '\u{031b}', 216),
+    ('\u{031c}', '\u{0320}', 220),
+    ('\u{0321}', '\u{0322}', 202),
+    ('\u{0323}', '\u{0326}', 220),
+    ('\u{0327}', '\u{0328}', 202),
+    ('\u{0329}', '\u{0333}', 220),
+    ('\u{0334}', '\u{0338}', 1),
+    ('\u{0339}', '\u{033c}', 220),
+    ('\u{033d}', '\u{0344}', 230),
+    ('\u{0345}', '\u{0345}', 240),
+    ('\u{0346}', '\u{0346}', 230),
+    ('\u{0347}', '\u{0349}', 220),
+    ('\u{034a}', '\u{034c}', 230),
+    ('\u{034d}', '\u{034e}', 220),
+    ('\u{0350}', '\u{0352}', 230),
+    ('\u{0353}', '\u{0356}', 220),
+    ('\u{0357}', '\u{0357}', 230),
+    ('\u{0358}', '\u{0358}', 232),
+    ('\u{0359}', '\u{035a}', 220),
+    ('\u{035b}', '\u{035b}', 230),
+    ('\u{035c}', '\u{035c}', 233),
+    ('\u{035d}', '\u{035e}', 234),
+    ('\u{035f}', '\u{035f}', 233),
+    ('\u{0360}', '\u{0361}', 234),
+    ('\u{0362}', '\u{0362}', 233),
+    ('\u{0363}', '\u{036f}', 230),
+    ('\u{0483}', '\u{0487}', 230),
+    ('\u{0591}', '\u{0591}', 220),
+    ('\u{0592}', '\u{0595}', 230),
+    ('\u{0596}', '\u{0596}', 220),
+    ('\u{0597}', '\u{0599}', 230),
+    ('\u{059a}', '\u{059a}', 222),
+    ('\u{059b}', '\u{059b}', 220),
+    ('\u{059c}', '\u{05a1}', 230),
+    ('\u{05a2}', '\u{05a7}', 220),
+    ('\u{05a8}', '\u{05a9}', 230),
+    ('\u{05aa}', '\u{05aa}', 220),
+    ('\u{05ab}', '\u{05ac}', 230),
+    ('\u{05ad}', '\u{05ad}', 222),
+    ('\u{05ae}', '\u{05ae}', 228),
+    ('\u{05af}', '\u{05af}', 230),
+    ('\u{05b0}', '\u{05b0}', 10),
+    ('\u{05b1}', '\u{05b1}', 11),
+    ('\u{05b2}', '\u{05b2}', 12),
+    ('\u{05b3}', '\u{05b3}', 13),
+    ('\u{05b4}', '\u{05b4}', 14),
+    ('\u{05b5}', '\u{05b5}', 15),
+    ('\u{05b6}', '\u{05b6}', 16),
+    ('\u{05b7}', '\u{05b7}', 17),
+    ('\u{05b8}', '\u{05b8}', 18),
+    ('\u{05b9}', '\u{05ba}', 19),
+    ('\u{05bb}', '\u{05bb}', 20),
+    ('\u{05bc}', '\u{05bc}', 21),
+    ('\u{05bd}', '\u{05bd}', 22),
+    ('\u{05bf}', '\u{05bf}', 23),
+    ('\u{05c1}', '\u{05c1}', 24),
+    ('\u{05c2}', '\u{05c2}', 25),
+    ('\u{05c4}', '\u{05c4}', 230),
+    ('\u{05c5}', '\u{05c5}', 220),
+    ('\u{05c7}', '\u{05c7}', 18),
+    ('\u{0610}', '\u{0617}', 230),
+    ('\u{0618}', '\u{0618}', 30),
+    ('\u{0619}', '\u{0619}', 31),
+    ('\u{061a}', '\u{061a}', 32),
+    ('\u{064b}', '\u{064b}', 27),
+    ('\u{064c}', '\u{064c}', 28),
+    ('\u{064d}', '\u{064d}', 29),
+    ('\u{064e}', '\u{064e}', 30),
+    ('\u{064f}', '\u{064f}', 31),
+    ('\u{0650}', '\u{0650}', 32),
+    ('\u{0651}', '\u{0651}', 33),
+    ('\u{0652}', '\u{0652}', 34),
+    ('\u{0653}', '\u{0654}', 230),
+    ('\u{0655}', '\u{0656}', 220),
+    ('\u{0657}', '\u{065b}', 230),
+    ('\u{065c}', '\u{065c}', 220),
+    ('\u{065d}', '\u{065e}', 230),
+    ('\u{065f}', '\u{065f}', 220),
+    ('\u{0670}', '\u{0670}', 35),
+    ('\u{06d6}', '\u{06dc}', 230),
+    ('\u{06df}', '\u{06e2}', 230),
+    ('\u{06e3}', '\u{06e3}', 220),
+    ('\u{06e4}', '\u{06e4}', 230),
+    ('\u{06e7}', '\u{06e8}', 230),
+    ('\u{06ea}', '\u{06ea}', 220),
+    ('\u{06eb}', '\u{06ec}', 230),
+    ('\u{06ed}', '\u{06ed}', 220),
+    ('\u{0711}', '\u{0711}', 36),
+    ('\u{0730}', '\u{0730}', 230),
+    ('\u{0731}', '\u{0731}', 220),
+    ('\u{0732}', '\u{0733}', 230),
+    ('\u{0734}', '\u{0734}', 220),
+    ('\u{0735}', '\u{0736}', 230),
+    ('\u{0737}', '\u{0739}', 220),
+    ('\u{073a}', '\u{073a}', 230),
+    ('\u{073b}', '\u{073c}', 220),
+    ('\u{073d}', '\u{073d}', 230),
+    ('\u{073e}', '\u{073e}', 220),
+    ('\u{073f}', '\u{0741}', 230),
+    ('\u{0742}', '\u{0742}', 220),
+    ('\u{0743}', '\u{0743}', 230),
+    ('\u{0744}', '\u{0744}', 220),
+    ('\u{0745}', '\u{0745}', 230),
+    ('\u{0746}', '\u{0746}', 220),
+    ('\u{0747}', '\u{0747}', 230),
+    ('\u{0748}', '\u{0748}', 220),
+    ('\u{0749}', '\u{074a}', 230),
+    ('\u{07eb}', '\u{07f1}', 230),
+    ('\u{07f2}', '\u{07f2}', 220),
+    ('\u{07f3}', '\u{07f3}', 230),
+    ('\u{07fd}', '\u{07fd}', 220),
+    ('\u{0816}', '\u{0819}', 230),
+    ('\u{081b}', '\u{0823}', 230),
+    ('\u{0825}', '\u{0827}', 230),
+    ('\u{0829}', '\u{082d}', 230),
+    ('\u{0859}', '\u{085b}', 220),
+    ('\u{0898}', '\u{0898}', 230),
+    ('\u{0899}', '\u{089b}', 220),
+    ('\u{089c}', '\u{089f}', 230),
+    ('\u{08ca}', '\u{08ce}', 230),
+    ('\u{08cf}', '\u{08d3}', 220),
+    ('\u{08d4}', '\u{08e1}', 230),
+    ('\u{08e3}', '\u{08e3}', 220),
+    ('\u{08e4}', '\u{08e5}', 230),
+    ('\u{08e6}', '\u{08e6}', 220),
+    ('\u{08e7}', '\u{08e8}', 230),
+    ('\u{08e9}', '\u{08e9}', 220),
+    ('\u{08ea}', '\u{08ec}', 230),
+    ('\u{08ed}', '\u{08ef}', 220),
+    ('\u{08f0}', '\u{08f0}', 27),
+    ('\u{08f1}', '\u{08f1}', 28),
+    ('\u{08f2}', '\u{08f2}', 29),
+    ('\u{08f3}', '\u{08f5}', 230),
+    ('\u{08f6}', '\u{08f6}', 220),
+    ('\u{08f7}', '\u{08f8}', 230),
+    ('\u{08f9}', '\u{08fa}', 220),
+    ('\u{08fb}', '\u{08ff}', 230),
+    ('\u{093c}', '\u{093c}', 7),
+    ('\u{094d}', '\u{094d}', 9),
+    ('\u{0951}', '\u{0951}', 230),
+    ('\u{0952}', '\u{0952}', 220),
+    ('\u{0953}', '\u{0954}', 230),
+    ('\u{09bc}', '\u{09bc}', 7),
+    ('\u{09cd}', '\u{09cd}', 9),
+    ('\u{09fe}', '\u{09fe}', 230),
+    ('\u{0a3c}', '\u{0a3c}', 7),
+    ('\u{0a4d}', '\u{0a4d}', 9),
+    ('\u{0abc}', '\u{0abc}', 7),
+    ('\u{0acd}', '\u{0acd}', 9),
+    ('\u{0b3c}', '\u{0b3c}', 7),
+    ('\u{0b4d}', '\u{0b4d}', 9),
+    ('\u{0bcd}', '\u{0bcd}', 9),
+    ('\u{0c3c}', '\u{0c3c}', 7),
+    ('\u{0c4d}', '\u{0c4d}', 9),
+    ('\u{0c55}', '\u{0c55}', 84),
+    ('\u{0c56}', '\u{0c56}', 91),
+    ('\u{0cbc}', '\u{0cbc}', 7),
+    ('\u{0ccd}', '\u{0ccd}', 9),
+    ('\u{0d3b}', '\u{0d3c}', 9),
+    ('\u{0d4d}', '\u{0d4d}', 9),
+    ('\u{0dca}', '\u{0dca}', 9),
+    ('\u{0e38}', '\u{0e39}', 103),
+    ('\u{0e3a}', '\u{0e3a}', 9),
+    ('\u{0e48}', '\u{0e4b}', 107),
+    ('\u{0eb8}', '\u{0eb9}', 118),
+    ('\u{0eba}', '\u{0eba}', 9),
+    ('\u{0ec8}', '\u{0ecb}', 122),
+    ('\u{0f18}', '\u{0f19}', 220),
+    ('\u{0f35}', '\u{0f35}', 220),
+    ('\u{0f37}', '\u{0f37}', 220),
+    ('\u{0f39}', '\u{0f39}', 216),
+    ('\u{0f71}', '\u{0f71}', 129),
+    ('\u{0f72}', '\u{0f72}', 130),
+    ('\u{0f74}', '\u{0f74}', 132),
+    ('\u{0f7a}', '\u{0f7d}', 130),
+    ('\u{0f80}', '\u{0f80}', 130),
+    ('\u{0f82}', '\u{0f83}', 230),
+    ('\u{0f84}', '\u{0f84}', 9),
+    ('\u{0f86}', '\u{0f87}', 230),
+    ('\u{0fc6}', '\u{0fc6}', 220),
+    ('\u{1037}', '\u{1037}', 7),
+    ('\u{1039}', '\u{103a}', 9),
+    ('\u{108d}', '\u{108d}', 220),
+    ('\u{135d}', '\u{135f}', 230),
+    ('\u{1714}', '\u{1715}', 9),
+    ('\u{1734}', '\u{1734}', 9),
+    ('\u{17d2}', '\u{17d2}', 9),
+    ('\u{17dd}', '\u{17dd}', 230),
+    ('\u{18a9}', '\u{18a9}', 228),
+    ('\u{1939}', '\u{1939}', 222),
+    ('\u{193a}', '\u{193a}', 230),
+    ('\u{193b}', '\u{193b}', 220),
+    ('\u{1a17}', '\u{1a17}', 230),
+    ('\u{1a18}', '\u{1a18}', 220),
+    ('\u{1a60}', '\u{1a60}', 9),
+    ('\u{1a75}', '\u{1a7c}', 230),
+    ('\u{1a7f}', '\u{1a7f}', 220),
+    ('\u{1ab0}', '\u{1ab4}', 230),
+    ('\u{1ab5}', '\u{1aba}', 220),
+    ('\u{1abb}', '\u{1abc}', 230),
+    ('\u{1abd}', '\u{1abd}', 220),
+    ('\u{1abf}', '\u{1ac0}', 220),
+    ('\u{1ac1}', '\u{1ac2}', 230),
+    ('\u{1ac3}', '\u{1ac4}', 220),
+    ('\u{1ac5}', '\u{1ac9}', 230),
+    ('\u{1aca}', '\u{1aca}', 220),
+    ('\u{1acb}', '\u{1ace}', 230),
+    ('\u{1b34}', '\u{1b34}', 7),
+    ('\u{1b44}', '\u{1b44}', 9),
+    ('\u{1b6b}', '\u{1b6b}', 230),
+    ('\u{1b6c}', '\u{1b6c}', 220),
+    ('\u{1b6d}', '\u{1b73}', 230),
+    ('\u{1baa}', '\u{1bab}', 9),
+    ('\u{1be6}', '\u{1be6}', 7),
+    ('\u{1bf2}', '\u{1bf3}', 9),
+    ('\u{1c37}', '\u{1c37}', 7),
+    ('\u{1cd0}', '\u{1cd2}', 230),
+    ('\u{1cd4}', '\u{1cd4}', 1),
+    ('\u{1cd5}', '\u{1cd9}', 220),
+    ('\u{1cda}', '\u{1cdb}', 230),
+    ('\u{1cdc}', '\u{1cdf}', 220),
+    ('\u{1ce0}', '\u{1ce0}', 230),
+    ('\u{1ce2}', '\u{1ce8}', 1),
+    ('\u{1ced}', '\u{1ced}', 220),
+    ('\u{1cf4}', '\u{1cf4}', 230),
+    ('\u{1cf8}', '\u{1cf9}', 230),
+    ('\u{1dc0}', '\u{1dc1}', 230),
+    ('\u{1dc2}', '\u{1dc2}', 220),
+    ('\u{1dc3}', '\u{1dc9}', 230),
+    ('\u{1dca}', '\u{1dca}', 220),
+    ('\u{1dcb}', '\u{1dcc}', 230),
+    ('\u{1dcd}', '\u{1dcd}', 234),
+    ('\u{1dce}', '\u{1dce}', 214),
+    ('\u{1dcf}', '\u{1dcf}', 220),
+    ('\u{1dd0}', '\u{1dd0}', 202),
+    ('\u{1dd1}', '\u{1df5}', 230),
+    ('\u{1df6}', '\u{1df6}', 232),
+    ('\u{1df7}', '\u{1df8}', 228),
+    ('\u{1df9}', '\u{1df9}', 220),
+    ('\u{1dfa}', '\u{1dfa}', 218),
+    ('\u{1dfb}', '\u{1dfb}', 230),
+    ('\u{1dfc}', '\u{1dfc}', 233),
+    ('\u{1dfd}', '\u{1dfd}', 220),
+    ('\u{1dfe}', '\u{1dfe}', 230),
+    ('\u{1dff}', '\u{1dff}', 220),
+    ('\u{20d0}', '\u{20d1}', 230),
+    ('\u{20d2}', '\u{20d3}', 1),
+    ('\u{20d4}', '\u{20d7}', 230),
+    ('\u{20d8}', '\u{20da}', 1),
+    ('\u{20db}', '\u{20dc}', 230),
+    ('\u{20e1}', '\u{20e1}', 230),
+    ('\u{20e5}', '\u{20e6}', 1),
+    ('\u{20e7}', '\u{20e7}', 230),
+    ('\u{20e8}', '\u{20e8}', 220),
+    ('\u{20e9}', '\u{20e9}', 230),
+    ('\u{20ea}', '\u{20eb}', 1),
+    ('\u{20ec}', '\u{20ef}', 220),
+    ('\u{20f0}', '\u{20f0}', 230),
+    ('\u{2cef}', '\u{2cf1}', 230),
+    ('\u{2d7f}', '\u{2d7f}', 9),
+    ('\u{2de0}', '\u{2dff}', 230),
+    ('\u{302a}', '\u{302a}', 218),
+    ('\u{302b}', '\u{302b}', 228),
+    ('\u{302c}', '\u{302c}', 232),
+    ('\u{302d}', '\u{302d}', 222),
+    ('\u{302e}', '\u{302f}', 224),
+    ('\u{3099}', '\u{309a}', 8),
+    ('\u{a66f}', '\u{a66f}', 230),
+    ('\u{a674}', '\u{a67d}', 230),
+    ('\u{a69e}', '\u{a69f}', 230),
+    ('\u{a6f0}', '\u{a6f1}', 230),
+    ('\u{a806}', '\u{a806}', 9),
+    ('\u{a82c}', '\u{a82c}', 9),
+    ('\u{a8c4}', '\u{a8c4}', 9),
+    ('\u{a8e0}', '\u{a8f1}', 230),
+    ('\u{a92b}', '\u{a92d}', 220),
+    ('\u{a953}', '\u{a953}', 9),
+    ('\u{a9b3}', '\u{a9b3}', 7),
+    ('\u{a9c0}', '\u{a9c0}', 9),
+    ('\u{aab0}', '\u{aab0}', 230),
+    ('\u{aab2}', '\u{aab3}', 230),
+    ('\u{aab4}', '\u{aab4}', 220),
+    ('\u{aab7}', '\u{aab8}', 230),
+    ('\u{aabe}', '\u{aabf}', 230),
+    ('\u{aac1}', '\u{aac1}', 230),
+    ('\u{aaf6}', '\u{aaf6}', 9),
+    ('\u{abed}', '\u{abed}', 9),
+    ('\u{fb1e}', '\u{fb1e}', 26),
+    ('\u{fe20}', '\u{fe26}', 230),
+    ('\u{fe27}', '\u{fe2d}', 220),
+    ('\u{fe2e}', '\u{fe2f}', 230),
+];
+
+// Canonical decompositions as (character, first, second, composes),
+// sorted by character. `second` is NUL for singletons, and `composes`
+// is false for characters NFC never produces.
+pub(crate) const DECOMPOSITIONS: &[(char, char, char, bool)] = &[
+    ('\u{00c0}', '\u{0041}', '\u{0300}', true),
+    ('\u{00c1}', '\u{0041}', '\u{0301}', true),
+    ('\u{00c2}', '\u{0041}', '\u{0302}', true),
+    ('\u{00c3}', '\u{0041}', '\u{0303}', true),
+    ('\u{00c4}', '\u{0041}', '\u{0308}', true),
+    ('\u{00c5}', '\u{0041}', '\u{030a}', true),
+    ('\u{00c7}', '\u{0043}', '\u{0327}', true),
+    ('\u{00c8}', '\u{0045}', '\u{0300}', true),
+    ('\u{00c9}', '\u{0045}', '\u{0301}', true),
+    ('\u{00ca}', '\u{0045}', '\u{0302}', true),
+    ('\u{00cb}', '\u{0045}', '\u{0308}', true),
+    ('\u{00cc}', '\u{0049}', '\u{0300}', true),
+    ('\u{00cd}', '\u{0049}', '\u{0301}', true),
+    ('\u{00ce}', '\u{0049}', '\u{0302}', true),
+    ('\u{00cf}', '\u{0049}', '\u{0308}', true),
+    ('\u{00d1}', '\u{004e}', '\u{0303}', true),
+    ('\u{00d2}', '\u{004f}', '\u{0300}', true),
+    ('\u{00d3}', '\u{004f}', '\u{0301}', true),
+    ('\u{00d4}', '\u{004f}', '\u{0302}', true),
+    ('\u{00d5}', '\u{004f}', '\u{0303}', true),
+    ('\u{00d6}', '\u{004f}', '\u{0308}', true),
+    ('\u{00d9}', '\u{0055}', '\u{0300}', true),
+    ('\u{00da}', '\u{0055}', '\u{0301}', true),
+    ('\u{00db}', '\u{0055}', '\u{0302}', true),
+    ('\u{00dc}', '\u{0055}', '\u{0308}', true),
+    ('\u{00dd}', '\u{0059}', '\u{0301}', true),
+    ('\u{00e0}', '\u{0061}', '\u{0300}', true),
+    ('\u{00e1}', '\u{0061}', '\u{0301}', true),
+    ('\u{00e2}', '\u{0061}', '\u{0302}', true),
+    ('\u{00e3}', '\u{0061}', '\u{0303}', true),
+    ('\u{00e4}', '\u{0061}', '\u{0308}', true),
+    ('\u{00e5}', '\u{0061}', '\u{030a}', true),
+    ('\u{00e7}', '\u{0063}', '\u{0327}', true),
+    ('\u{00e8}', '\u{0065}', '\u{0300}', true),
+    ('\u{00e9}', '\u{0065}', '\u{0301}', true),
+    ('\u{00ea}', '\u{0065}', '\u{0302}', true),
+    ('\u{00eb}', '\u{0065}', '\u{0308}', true),
+    ('\u{00ec}', '\u{0069}', '\u{0300}', true),
+    ('\u{00ed}', '\u{0069}', '\u{0301}', true),
+    ('\u{00ee}', '\u{0069}', '\u{0302}', true),
+    ('\u{00ef}', '\u{0069}', '\u{0308}', true),
+    ('\u{00f1}', '\u{006e}', '\u{0303}', true),
+    ('\u{00f2}', '\u{006f}', '\u{0300}', true),
+    ('\u{00f3}', '\u{006f}', '\u{0301}', true),
+    ('\u{00f4}', '\u{006f}', '\u{0302}', true),
+    ('\u{00f5}', '\u{006f}', '\u{0303}', true),
+    ('\u{00f6}', '\u{006f}', '\u{0308}', true),
+    ('\u{00f9}', '\u{0075}', '\u{0300}', true),
+    ('\u{00fa}', '\u{0075}', '\u{0301}', true),
+    ('\u{00fb}', '\u{0075}', '\u{0302}', true),
+    ('\u{00fc}', '\u{0075}', '\u{0308}', true),
+    ('\u{00fd}', '\u{0079}', '\u{0301}', true),
+    ('\u{00ff}', '\u{0079}', '\u{0308}', true),
+    ('\u{0100}', '\u{0041}', '\u{0304}', true),
+    ('\u{0101}', '\u{0061}', '\u{0304}', true),
+    ('\u{0102}', '\u{0041}', '\u{0306}', true),
+    ('\u{0103}', '\u{0061}', '\u{0306}', true),
+    ('\u{0104}', '\u{0041}', '\u{0328}', true),
+    ('\u{0105}', '\u{0061}', '\u{0328}', true),
+    ('\u{0106}', '\u{0043}', '\u{0301}', true),
+    ('\u{0107}', '\u{0063}', '\u{0301}', true),
+    ('\u{0108}', '\u{0043}', '\u{0302}', true),
+    ('\u{0109}', '\u{0063}', '\u{0302}', true),
+    ('\u{010a}', '\u{0043}', '\u{0307}', true),
+    ('\u{010b}', '\u{0063}', '\u{0307}', true),
+    ('\u{010c}', '\u{0043}', '\u{030c}', true),
+    ('\u{010d}', '\u{0063}', '\u{030c}', true),
+    ('\u{010e}', '\u{0044}', '\u{030c}', true),
+    ('\u{010f}', '\u{0064}', '\u{030c}', true),
+    ('\u{0112}', '\u{0045}', '\u{0304}', true),
+    ('\u{0113}', '\u{0065}', '\u{0304}', true),
+    ('\u{0114}', '\u{0045}', '\u{0306}', true),
+    ('\u{0115}', '\u{0065}', '\u{0306}', true),
+    ('\u{0116}', '\u{0045}', '\u{0307}', true),
+    ('\u{0117}', '\u{0065}', '\u{0307}', true),
+    ('\u{0118}', '\u{0045}', '\u{0328}', true),
+    ('\u{0119}', '\u{0065}', '\u{0328}', true),
+    ('\u{011a}', '\u{0045}', '\u{030c}', true),
+    ('\u{011b}', '\u{0065}', '\u{030c}', true),
+    ('\u{011c}', '\u{0047}', '\u{0302}', true),
+    ('\u{011d}', '\u{0067}', '\u{0302}', true),
+    ('\u{011e}', '\u{0047}', '\u{0306}', true),
+    ('\u{011f}', '\u{0067}', '\u{0306}', true),
+    ('\u{0120}', '\u{0047}', '\u{0307}', true),
+    ('\u{0121}', '\u{0067}', '\u{0307}', true),
+    ('\u{0122}', '\u{0047}', '\u{0327}', true),
+    ('\u{0123}', '\u{0067}', '\u{0327}', true),
+    ('\u{0124}', '\u{0048}', '\u{0302}', true),
+    ('\u{0125}', '\u{0068}', '\u{0302}', true),
+    ('\u{0128}', '\u{0049}', '\u{0303}', true),
+    ('\u{0129}', '\u{0069}', '\u{0303}', true),
+    ('\u{012a}', '\u{0049}', '\u{0304}', true),
+    ('\u{012b}', '\u{0069}', '\u{0304}', true),
+    ('\u{012c}', '\u{0049}', '\u{0306}', true),
+    ('\u{012d}', '\u{0069}', '\u{0306}', true),
+    ('\u{012e}', '\u{0049}', '\u{0328}', true),
+    ('\u{012f}', '\u{0069}', '\u{0328}', true),
+    ('\u{0130}', '\u{0049}', '\u{0307}', true),
+    ('\u{0134}', '\u{004a}', '\u{0302}', true),
+    ('\u{0135}', '\u{006a}', '\u{0302}', true),
+    ('\u{0136}', '\u{004b}', '\u{0327}', true),
+    ('\u{0137}', '\u{006b}', '\u{0327}', true),
+    ('\u{0139}', '\u{004c}', '\u{0301}', true),
+    ('\u{013a}', '\u{006c}', '\u{0301}', true),
+    ('\u{013b}', '\u{004c}', '\u{0327}', true),
+    ('\u{013c}', '\u{006c}', '\u{0327}', true),
+    ('\u{013d}', '\u{004c}', '\u{030c}', true),
+    ('\u{013e}', '\u{006c}', '\u{030c}', true),
+    ('\u{0143}', '\u{004e}', '\u{0301}', true),
+    ('\u{0144}', '\u{006e}', '\u{0301}', true),
+    ('\u{0145}', '\u{004e}', '\u{0327}', true),
+    ('\u{0146}', '\u{006e}', '\u{0327}', true),
+    ('\u{0147}', '\u{004e}', '\u{030c}', true),
+    ('\u{0148}', '\u{006e}', '\u{030c}', true),
+    ('\u{014c}', '\u{004f}', '\u{0304}', true),
+    ('\u{014d}', '\u{006f}', '\u{0304}', true),
+    ('\u{014e}', '\u{004f}', '\u{0306}', true),
+    ('\u{014f}', '\u{006f}', '\u{0306}', true),
+    ('\u{0150}', '\u{004f}', '\u{030b}', true),
+    ('\u{0151}', '\u{006f}', '\u{030b}', true),
+    ('\u{0154}', '\u{0052}', '\u{0301}', true),
+    ('\u{0155}', '\u{0072}', '\u{0301}', true),
+    ('\u{0156}', '\u{0052}', '\u{0327}', true),
+    ('\u{0157}', '\u{0072}', '\u{0327}', true),
+    ('\u{0158}', '\u{0052}', '\u{030c}', true),
+    ('\u{0159}', '\u{0072}', '\u{030c}', true),
+    ('\u{015a}', '\u{0053}', '\u{0301}', true),
+    ('\u{015b}', '\u{0073}', '\u{0301}', true),
+    ('\u{015c}', '\u{0053}', '\u{0302}', true),
+    ('\u{015d}', '\u{0073}', '\u{0302}', true),
+    ('\u{015e}', '\u{0053}', '\u{0327}', true),
+    ('\u{015f}', '\u{0073}', '\u{0327}', true),
+    ('\u{0160}', '\u{0053}', '\u{030c}', true),
+    ('\u{0161}', '\u{0073}', '\u{030c}', true),
+    ('\u{0162}', '\u{0054}', '\u{0327}', true),
+    ('\u{0163}', '\u{0074}', '\u{0327}', true),
+    ('\u{0164}', '\u{0054}', '\u{030c}', true),
+    ('\u{0165}', '\u{0074}', '\u{030c}', true),
+    ('\u{0168}', '\u{0055}', '\u{0303}', true),
+    ('\u{0169}', '\u{0075}', '\u{0303}', true),
+    ('\u{016a}', '\u{0055}', '\u{0304}', true),
+    ('\u{016b}', '\u{0075}', '\u{0304}', true),
+    ('\u{016c}', '\u{0055}', '\u{0306}', true),
+    ('\u{016d}', '\u{0075}', '\u{0306}', true),
+    ('\u{016e}', '\u{0055}', '\u{030a}', true),
+    ('\u{016f}', '\u{0075}', '\u{030a}', true),
+    ('\u{0170}', '\u{0055}', '\u{030b}', true),
+    ('\u{0171}', '\u{0075}', '\u{030b}', true),
+    ('\u{0172}', '\u{0055}', '\u{0328}', true),
+    ('\u{0173}', '\u{0075}', '\u{0328}', true),
+    ('\u{0174}', '\u{0057}', '\u{0302}', true),
+    ('\u{0175}', '\u{0077}', '\u{0302}', true),
+    ('\u{0176}', '\u{0059}', '\u{0302}', true),
+    ('\u{0177}', '\u{0079}', '\u{0302}', true),
+    ('\u{0178}', '\u{0059}', '\u{0308}', true),
+    ('\u{0179}', '\u{005a}', '\u{0301}', true),
+    ('\u{017a}', '\u{007a}', '\u{0301}', true),
+    ('\u{017b}', '\u{005a}', '\u{0307}', true),
+    ('\u{017c}', '\u{007a}', '\u{0307}', true),
+    ('\u{017d}', '\u{005a}', '\u{030c}', true),
+    ('\u{017e}', '\u{007a}', '\u{030c}', true),
+    ('\u{01a0}', '\u{004f}', '\u{031b}', true),
+    ('\u{01a1}', '\u{006f}', '\u{031b}', true),
+    ('\u{01af}', '\u{0055}', '\u{031b}', true),
+    ('\u{01b0}', '\u{0075}', '\u{031b}', true),
+    ('\u{01cd}', '\u{0041}', '\u{030c}', true),
+    ('\u{01ce}', '\u{0061}', '\u{030c}', true),
+    ('\u{01cf}', '\u{0049}', '\u{030c}', true),
+    ('\u{01d0}', '\u{0069}', '\u{030c}', true),
+    ('\u{01d1}', '\u{004f}', '\u{030c}', true),
+    ('\u{01d2}', '\u{006f}', '\u{030c}', true),
+    ('\u{01d3}', '\u{0055}', '\u{030c}', true),
+    ('\u{01d4}', '\u{0075}', '\u{030c}', true),
+    ('\u{01d5}', '\u{00dc}', '\u{0304}', true),
+    ('\u{01d6}', '\u{00fc}', '\u{0304}', true),
+    ('\u{01d7}', '\u{00dc}', '\u{0301}', true),
+    ('\u{01d8}', '\u{00fc}', '\u{0301}', true),
+    ('\u{01d9}', '\u{00dc}', '\u{030c}', true),
+    ('\u{01da}', '\u{00fc}', '\u{030c}', true),
+    ('\u{01db}', '\u{00dc}', '\u{0300}', true),
+    ('\u{01dc}', '\u{00fc}', '\u{0300}', true),
+    ('\u{01de}', '\u{00c4}', '\u{0304}', true),
+    ('\u{01df}', '\u{00e4}', '\u{0304}', true),
+    ('\u{01e0}', '\u{0226}', '\u{0304}', true),
+    ('\u{01e1}', '\u{0227}', '\u{0304}', true),
+    ('\u{01e2}', '\u{00c6}', '\u{0304}', true),
+    ('\u{01e3}', '\u{00e6}', '\u{0304}', true),
+    ('\u{01e6}', '\u{0047}', '\u{030c}', true),
+    ('\u{01e7}', '\u{0067}', '\u{030c}', true),
+    ('\u{01e8}', '\u{004b}', '\u{030c}', true),
+    ('\u{01e9}', '\u{006b}', '\u{030c}', true),
+    ('\u{01ea}', '\u{004f}', '\u{0328}', true),
+    ('\u{01eb}', '\u{006f}', '\u{0328}', true),
+    ('\u{01ec}', '\u{01ea}', '\u{0304}', true),
+    ('\u{01ed}', '\u{01eb}', '\u{0304}', true),
+    ('\u{01ee}', '\u{01b7}', '\u{030c}', true),
+    ('\u{01ef}', '\u{0292}', '\u{030c}', true),
+    ('\u{01f0}', '\u{006a}', '\u{030c}', true),
+    ('\u{01f4}', '\u{0047}', '\u{0301}', true),
+    ('\u{01f5}', '\u{0067}', '\u{0301}', true),
+    ('\u{01f8}', '\u{004e}', '\u{0300}', true),
+    ('\u{01f9}', '\u{006e}', '\u{0300}', true),
+    ('\u{01fa}', '\u{00c5}', '\u{0301}', true),
+    ('\u{01fb}', '\u{00e5}', '\u{0301}', true),
+    ('\u{01fc}', '\u{00c6}', '\u{0301}', true),
+    ('\u{01fd}', '\u{00e6}', '\u{0301}', true),
+    ('\u{01fe}', '\u{00d8}', '\u{0301}', true),
+    ('\u{01ff}', '\u{00f8}', '\u{0301}', true),
+    ('\u{0200}', '\u{0041}', '\u{030f}', true),
+    ('\u{0201}', '\u{0061}', '\u{030f}', true),
+    ('\u{0202}', '\u{0041}', '\u{0311}', true),
+    ('\u{0203}', '\u{0061}', '\u{0311}', true),
+    ('\u{0204}', '\u{0045}', '\u{030f}', true),
+    ('\u{0205}', '\u{0065}', '\u{030f}', true),
+    ('\u{0206}', '\u{0045}', '\u{0311}', true),
+    ('\u{0207}', '\u{0065}', '\u{0311}', true),
+    ('\u{0208}', '\u{0049}', '\u{030f}', true),
+    ('\u{0209}', '\u{0069}', '\u{030f}', true),
+    ('\u{020a}', '\u{0049}', '\u{0311}', true),
+    ('\u{020b}', '\u{0069}', '\u{0311}', true),
+    ('\u{020c}', '\u{004f}', '\u{030f}', true),
+    ('\u{020d}', '\u{006f}', '\u{030f}', true),
+    ('\u{020e}', '\u{004f}', '\u{0311}', true),
+    ('\u{020f}', '\u{006f}', '\u{0311}', true),
+    ('\u{0210}', '\u{0052}', '\u{030f}', true),
+    ('\u{0211}', '\u{0072}', '\u{030f}', true),
+    ('\u{0212}', '\u{0052}', '\u{0311}', true),
+    ('\u{0213}', '\u{0072}', '\u{0311}', true),
+    ('\u{0214}', '\u{0055}', '\u{030f}', true),
+    ('\u{0215}', '\u{0075}', '\u{030f}', true),
+    ('\u{0216}', '\u{0055}', '\u{0311}', true),
+    ('\u{0217}', '\u{0075}', '\u{0311}', true),
+    ('\u{0218}', '\u{0053}', '\u{0326}', true),
+    ('\u{0219}', '\u{0073}', '\u{0326}', true),
+    ('\u{021a}', '\u{0054}', '\u{0326}', true),
+    ('\u{021b}', '\u{0074}', '\u{0326}', true),
+    ('\u{021e}', '\u{0048}', '\u{030c}', true),
+    ('\u{021f}', '\u{0068}', '\u{030c}', true),
+    ('\u{0226}', '\u{0041}', '\u{0307}', true),
+    ('\u{0227}', '\u{0061}', '\u{0307}', true),
+    ('\u{0228}', '\u{0045}', '\u{0327}', true),
+    ('\u{0229}', '\u{0065}', '\u{0327}', true),
+    ('\u{022a}', '\u{00d6}', '\u{0304}', true),
+    ('\u{022b}', '\u{00f6}', '\u{0304}', true),
+    ('\u{022c}', '\u{00d5}', '\u{0304}', true),
+    ('\u{022d}', '\u{00f5}', '\u{0304}', true),
+    ('\u{022e}', '\u{004f}', '\u{0307}', true),
+    ('\u{022f}', '\u{006f}', '\u{0307}', true),
+    ('\u{0230}', '\u{022e}', '\u{0304}', true),
+    ('\u{0231}', '\u{022f}', '\u{0304}', true),
+    ('\u{0232}', '\u{0059}', '\u{0304}', true),
+    ('\u{0233}', '\u{0079}', '\u{0304}', true),
+    ('\u{0340}', '\u{0300}', '\u{0000}', false),
+    ('\u{0341}', '\u{0301}', '\u{0000}', false),
+    ('\u{0343}', '\u{0313}', '\u{0000}', false),
+    ('\u{0344}', '\u{0308}', '\u{0301}', false),
+    ('\u{0374}', '\u{02b9}', '\u{0000}', false),
+    ('\u{037e}', '\u{003b}', '\u{0000}', false),
+    ('\u{0385}', '\u{00a8}', '\u{0301}', true),
+    ('\u{0386}', '\u{0391}', '\u{0301}', true),
+    ('\u{0387}', '\u{00b7}', '\u{0000}', false),
+    ('\u{0388}', '\u{0395}', '\u{0301}', true),
+    ('\u{0389}', '\u{0397}', '\u{0301}', true),
+    ('\u{038a}', '\u{0399}', '\u{0301}', true),
+    ('\u{038c}', '\u{039f}', '\u{0301}', true),
+    ('\u{038e}', '\u{03a5}', '\u{0301}', true),
+    ('\u{038f}', '\u{03a9}', '\u{0301}', true),
+    ('\u{0390}', '\u{03ca}', '\u{0301}', true),
+    ('\u{03aa}', '\u{0399}', '\u{0308}', true),
+    ('\u{03ab}', '\u{03a5}', '\u{0308}', true),
+    ('\u{03ac}', '\u{03b1}', '\u{0301}', true),
+    ('\u{03ad}', '\u{03b5}', '\u{0301}', true),
+    ('\u{03ae}', '\u{03b7}', '\u{0301}', true),
+    ('\u{03af}', '\u{03b9}', '\u{0301}', true),
+    ('\u{03b0}', '\u{03cb}', '\u{0301}', true),
+    ('\u{03ca}', '\u{03b9}', '\u{0308}', true),
+    ('\u{03cb}', '\u{03c5}', '\u{0308}', true),
+    ('\u{03cc}', '\u{03bf}', '\u{0301}', true),
+    ('\u{03cd}', '\u{03c5}', '\u{0301}', true),
+    ('\u{03ce}', '\u{03c9}', '\u{0301}', true),
+    ('\u{03d3}', '\u{03d2}', '\u{0301}', true),
+    ('\u{03d4}', '\u{03d2}', '\u{0308}', true),
+    ('\u{0400}', '\u{0415}', '\u{0300}', true),
+    ('\u{0401}', '\u{0415}', '\u{0308}', true),
+    ('\u{0403}', '\u{0413}', '\u{0301}', true),
+    ('\u{0407}', '\u{0406}', '\u{0308}', true),
+    ('\u{040c}', '\u{041a}', '\u{0301}', true),
+    ('\u{040d}', '\u{0418}', '\u{0300}', true),
+    ('\u{040e}', '\u{0423}', '\u{0306}', true),
+    ('\u{0419}', '\u{0418}', '\u{0306}', true),
+    ('\u{0439}', '\u{0438}', '\u{0306}', true),
+    ('\u{0450}', '\u{0435}', '\u{0300}', true),
+    ('\u{0451}', '\u{0435}', '\u{0308}', true),
+    ('\u{0453}', '\u{0433}', '\u{0301}', true),
+    ('\u{0457}', '\u{0456}', '\u{0308}', true),
+    ('\u{045c}', '\u{043a}', '\u{0301}', true),
+    ('\u{045d}', '\u{0438}', '\u{0300}', true),
+    ('\u{045e}', '\u{0443}', '\u{0306}', true),
+    ('\u{0476}', '\u{0474}', '\u{030f}', true),
+    ('\u{0477}', '\u{0475}', '\u{030f}', true),
+    ('\u{04c1}', '\u{0416}', '\u{0306}', true),
+    ('\u{04c2}', '\u{0436}', '\u{0306}', true),
+    ('\u{04d0}', '\u{0410}', '\u{0306}', true),
+    ('\u{04d1}', '\u{0430}', '\u{0306}', true),
+    ('\u{04d2}', '\u{0410}', '\u{0308}', true),
+    ('\u{04d3}', '\u{0430}', '\u{0308}', true),
+    ('\u{04d6}', '\u{0415}', '\u{0306}', true),
+    ('\u{04d7}', '\u{0435}', '\u{0306}', true),
+    ('\u{04da}', '\u{04d8}', '\u{0308}', true),
+    ('\u{04db}', '\u{04d9}', '\u{0308}', true),
+    ('\u{04dc}', '\u{0416}', '\u{0308}', true),
+    ('\u{04dd}', '\u{0436}', '\u{0308}', true),
+    ('\u{04de}', '\u{0417}', '\u{0308}', true),
+    ('\u{04df}', '\u{0437}', '\u{0308}', true),
+    ('\u{04e2}', '\u{0418}', '\u{0304}', true),
+    ('\u{04e3}', '\u{0438}', '\u{0304}', true),
+    ('\u{04e4}', '\u{0418}', '\u{0308}', true),
+    ('\u{04e5}', '\u{0438}', '\u{0308}', true),
+    ('\u{04e6}', '\u{041e}', '\u{0308}', true),
+    ('\u{04e7}', '\u{043e}', '\u{0308}', true),
+    ('\u{04ea}', '\u{04e8}', '\u{0308}', true),
+    ('\u{04eb}', '\u{04e9}', '\u{0308}', true),
+    ('\u{04ec}', '\u{042d}', '\u{0308}', true),
+    ('\u{04ed}', '\u{044d}', '\u{0308}', true),
+    ('\u{04ee}', '\u{0423}', '\u{0304}', true),
+    ('\u{04ef}', '\u{0443}', '\u{0304}', true),
+    ('\u{04f0}', '\u{0423}', '\u{0308}', true),
+    ('\u{04f1}', '\u{0443}', '\u{0308}', true),
+    ('\u{04f2}', '\u{0423}', '\u{030b}', true),
+    ('\u{04f3}', '\u{0443}', '\u{030b}', true),
+    ('\u{04f4}', '\u{0427}', '\u{0308}', true),
+    ('\u{04f5}', '\u{0447}', '\u{0308}', true),
+    ('\u{04f8}', '\u{042b}', '\u{0308}', true),
+    ('\u{04f9}', '\u{044b}', '\u{0308}', true),
+    ('\u{0622}', '\u{0627}', '\u{0653}', true),
+    ('\u{0623}', '\u{0627}', '\u{0654}', true),
+    ('\u{0624}', '\u{0648}', '\u{0654}', true),
+    ('\u{0625}', '\u{0627}', '\u{0655}', true),
+    ('\u{0626}', '\u{064a}', '\u{0654}', true),
+    ('\u{06c0}', '\u{06d5}', '\u{0654}', true),
+    ('\u{06c2}', '\u{06c1}', '\u{0654}', true),
+    ('\u{06d3}', '\u{06d2}', '\u{0654}', true),
+    ('\u{0929}', '\u{0928}', '\u{093c}', true),
+    ('\u{0931}', '\u{0930}', '\u{093c}', true),
+    ('\u{0934}', '\u{0933}', '\u{093c}', true),
+    ('\u{0958}', '\u{0915}', '\u{093c}', false),
+    ('\u{0959}', '\u{0916}', '\u{093c}', false),
+    ('\u{095a}', '\u{0917}', '\u{093c}', false),
+    ('\u{095b}', '\u{091c}', '\u{093c}', false),
+    ('\u{095c}', '\u{0921}', '\u{093c}', false),
+    ('\u{095d}', '\u{0922}', '\u{093c}', false),
+    ('\u{095e}', '\u{092b}', '\u{093c}', false),
+    ('\u{095f}', '\u{092f}', '\u{093c}', false),
+    ('\u{09cb}', '\u{09c7}', '\u{09be}', true),
+    ('\u{09cc}', '\u{09c7}', '\u{09d7}', true),
+    ('\u{09dc}', '\u{09a1}', '\u{09bc}', false),
+    ('\u{09dd}', '\u{09a2}', '\u{09bc}', false),
+    ('\u{09df}', '\u{09af}', '\u{09bc}', false),
+    ('\u{0a33}', '\u{0a32}', '\u{0a3c}', false),
+    ('\u{0a36}', '\u{0a38}', '\u{0a3c}', false),
+    ('\u{0a59}', '\u{0a16}', '\u{0a3c}', false),
+    ('\u{0a5a}', '\u{0a17}', '\u{0a3c}', false),
+    ('\u{0a5b}', '\u{0a1c}', '\u{0a3c}', false),
+    ('\u{0a5e}', '\u{0a2b}', '\u{0a3c}', false),
+    ('\u{0b48}', '\u{0b47}', '\u{0b56}', true),
+    ('\u{0b4b}', '\u{0b47}', '\u{0b3e}', true),
+    ('\u{0b4c}', '\u{0b47}', '\u{0b57}', true),
+    ('\u{0b5c}', '\u{0b21}', '\u{0b3c}', false),
+    ('\u{0b5d}', '\u{0b22}', '\u{0b3c}', false),
+    ('\u{0b94}', '\u{0b92}', '\u{0bd7}', true),
+    ('\u{0bca}', '\u{0bc6}', '\u{0bbe}', true),
+    ('\u{0bcb}', '\u{0bc7}', '\u{0bbe}', true),
+    ('\u{0bcc}', '\u{0bc6}', '\u{0bd7}', true),
+    ('\u{0c48}', '\u{0c46}', '\u{0c56}', true),
+    ('\u{0cc0}', '\u{0cbf}', '\u{0cd5}', true),
+    ('\u{0cc7}', '\u{0cc6}', '\u{0cd5}', true),
+    ('\u{0cc8}', '\u{0cc6}', '\u{0cd6}', true),
+    ('\u{0cca}', '\u{0cc6}', '\u{0cc2}', true),
+    ('\u{0ccb}', '\u{0cca}', '\u{0cd5}', true),
+    ('\u{0d4a}', '\u{0d46}', '\u{0d3e}', true),
+    ('\u{0d4b}', '\u{0d47}', '\u{0d3e}', true),
+    ('\u{0d4c}', '\u{0d46}', '\u{0d57}', true),
+    ('\u{0dda}', '\u{0dd9}', '\u{0dca}', true),
+    ('\u{0ddc}', '\u{0dd9}', '\u{0dcf}', true),
+    ('\u{0ddd}', '\u{0ddc}', '\u{0dca}', true),
+    ('\u{0dde}', '\u{0dd9}', '\u{0ddf}', true),
+    ('\u{0f43}', '\u{0f42}', '\u{0fb7}', false),
+    ('\u{0f4d}', '\u{0f4c}', '\u{0fb7}', false),
+    ('\u{0f52}', '\u{0f51}', '\u{0fb7}', false),
+    ('\u{0f57}', '\u{0f56}', '\u{0fb7}', false),
+    ('\u{0f5c}', '\u{0f5b}', '\u{0fb7}', false),
+    ('\u{0f69}', '\u{0f40}', '\u{0fb5}', false),
+    ('\u{0f73}', '\u{0f71}', '\u{0f72}', false),
+    ('\u{0f75}', '\u{0f71}', '\u{0f74}', false),
+    ('\u{0f76}', '\u{0fb2}', '\u{0f80}', false),
+    ('\u{0f78}', '\u{0fb3}', '\u{0f80}', false),
+    ('\u{0f81}', '\u{0f71}', '\u{0f80}', false),
+    ('\u{0f93}', '\u{0f92}', '\u{0fb7}', false),
+    ('\u{0f9d}', '\u{0f9c}', '\u{0fb7}', false),
+    ('\u{0fa2}', '\u{0fa1}', '\u{0fb7}', false),
+    ('\u{0fa7}', '\u{0fa6}', '\u{0fb7}', false),
+    ('\u{0fac}', '\u{0fab}', '\u{0fb7}', false),
+    ('\u{0fb9}', '\u{0f90}', '\u{0fb5}', false),
+    ('\u{1026}', '\u{1025}', '\u{102e}', true),
+    ('\u{1b06}', '\u{1b05}', '\u{1b35}', true),
+    ('\u{1b08}', '\u{1b07}', '\u{1b35}', true),
+    ('\u{1b0a}', '\u{1b09}', '\u{1b35}', true),
+    ('\u{1b0c}', '\u{1b0b}', '\u{1b35}', true),
+    ('\u{1b0e}', '\u{1b0d}', '\u{1b35}', true),
+    ('\u{1b12}', '\u{1b11}', '\u{1b35}', true),
+    ('\u{1b3b}', '\u{1b3a}', '\u{1b35}', true),
+    ('\u{1b3d}', '\u{1b3c}', '\u{1b35}', true),
+    ('\u{1b40}', '\u{1b3e}', '\u{1b35}', true),
+    ('\u{1b41}', '\u{1b3f}', '\u{1b35}', true),
+    ('\u{1b43}', '\u{1b42}', '\u{1b35}', true),
+    ('\u{1e00}', '\u{0041}', '\u{0325}', true),
+    ('\u{1e01}', '\u{0061}', '\u{0325}', true),
+    ('\u{1e02}', '\u{0042}', '\u{0307}', true),
+    ('\u{1e03}', '\u{0062}', '\u{0307}', true),
+    ('\u{1e04}', '\u{0042}', '\u{0323}', true),
+    ('\u{1e05}', '\u{0062}', '\u{0323}', true),
+    ('\u{1e06}', '\u{0042}', '\u{0331}', true),
+    ('\u{1e07}', '\u{0062}', '\u{0331}', true),
+    ('\u{1e08}', '\u{00c7}', '\u{0301}', true),
+    ('\u{1e09}', '\u{00e7}', '\u{0301}', true),
+    ('\u{1e0a}', '\u{0044}', '\u{0307}', true),
+    ('\u{1e0b}', '\u{0064}', '\u{0307}', true),
+    ('\u{1e0c}', '\u{0044}', '\u{0323}', true),
+    ('\u{1e0d}', '\u{0064}', '\u{0323}', true),
+    ('\u{1e0e}', '\u{0044}', '\u{0331}', true),
+    ('\u{1e0f}', '\u{0064}', '\u{0331}', true),
+    ('\u{1e10}', '\u{0044}', '\u{0327}', true),
+    ('\u{1e11}', '\u{0064}', '\u{0327}', true),
+    ('\u{1e12}', '\u{0044}', '\u{032d}', true),
+    ('\u{1e13}', '\u{0064}', '\u{032d}', true),
+    ('\u{1e14}', '\u{0112}', '\u{0300}', true),
+    ('\u{1e15}', '\u{0113}', '\u{0300}', true),
+    ('\u{1e16}', '\u{0112}', '\u{0301}', true),
+    ('\u{1e17}', '\u{0113}', '\u{0301}', true),
+    ('\u{1e18}', '\u{0045}', '\u{032d}', true),
+    ('\u{1e19}', '\u{0065}', '\u{032d}', true),
+    ('\u{1e1a}', '\u{0045}', '\u{0330}', true),
+    ('\u{1e1b}', '\u{0065}', '\u{0330}', true),
+    ('\u{1e1c}', '\u{0228}', '\u{0306}', true),
+    ('\u{1e1d}', '\u{0229}', '\u{0306}', true),
+    ('\u{1e1e}', '\u{0046}', '\u{0307}', true),
+    ('\u{1e1f}', '\u{0066}', '\u{0307}', true),
+    ('\u{1e20}', '\u{0047}', '\u{0304}', true),
+    ('\u{1e21}', '\u{0067}', '\u{0304}', true),
+    ('\u{1e22}', '\u{0048}', '\u{0307}', true),
+    ('\u{1e23}', '\u{0068}', '\u{0307}', true),
+    ('\u{1e24}', '\u{0048}', '\u{0323}', true),
+    ('\u{1e25}', '\u{0068}', '\u{0323}', true),
+    ('\u{1e26}', '\u{0048}', '\u{0308}', true),
+    ('\u{1e27}', '\u{0068}', '\u{0308}', true),
+    ('\u{1e28}', '\u{0048}', '\u{0327}', true),
+    ('\u{1e29}', '\u{0068}', '\u{0327}', true),
+    ('\u{1e2a}', '\u{0048}', '\u{032e}', true),
+    ('\u{1e2b}', '\u{0068}', '\u{032e}', true),
+    ('\u{1e2c}', '\u{0049}', '\u{0330}', true),
+    ('\u{1e2d}', '\u{0069}', '\u{0330}', true),
+    ('\u{1e2e}', '\u{00cf}', '\u{0301}', true),
+    ('\u{1e2f}', '\u{00ef}', '\u{0301}', true),
+    ('\u{1e30}', '\u{004b}', '\u{0301}', true),
+    ('\u{1e31}', '\u{006b}', '\u{0301}', true),
+    ('\u{1e32}', '\u{004b}', '\u{0323}', true),
+    ('\u{1e33}', '\u{006b}', '\u{0323}', true),
+    ('\u{1e34}', '\u{004b}', '\u{0331}', true),
+    ('\u{1e35}', '\u{006b}', '\u{0331}', true),
+    ('\u{1e36}', '\u{004c}', '\u{0323}', true),
+    ('\u{1e37}', '\u{006c}', '\u{0323}', true),
+    ('\u{1e38}', '\u{1e36}', '\u{0304}', true),
+    ('\u{1e39}', '\u{1e37}', '\u{0304}', true),
+    ('\u{1e3a}', '\u{004c}', '\u{0331}', true),
+    ('\u{1e3b}', '\u{006c}', '\u{0331}', true),
+    ('\u{1e3c}', '\u{004c}', '\u{032d}', true),
+    ('\u{1e3d}', '\u{006c}', '\u{032d}', true),
+    ('\u{1e3e}', '\u{004d}', '\u{0301}', true),
+    ('\u{1e3f}', '\u{006d}', '\u{0301}', true),
+    ('\u{1e40}', '\u{004d}', '\u{0307}', true),
+    ('\u{1e41}', '\u{006d}', '\u{0307}', true),
+    ('\u{1e42}', '\u{004d}', '\u{0323}', true),
+    ('\u{1e43}', '\u{006d}', '\u{0323}', true),
+    ('\u{1e44}', '\u{004e}', '\u{0307}', true),
+    ('\u{1e45}', '\u{006e}', '\u{0307}', true),
+    ('\u{1e46}', '\u{004e}', '\u{0323}', true),
+    ('\u{1e47}', '\u{006e}', '\u{0323}', true),
+    ('\u{1e48}', '\u{004e}', '\u{0331}', true),
+    ('\u{1e49}', '\u{006e}', '\u{0331}', true),
+    ('\u{1e4a}', '\u{004e}', '\u{032d}', true),
+    ('\u{1e4b}', '\u{006e}', '\u{032d}', true),
+    ('\u{1e4c}', '\u{00d5}', '\u{0301}', true),
+    ('\u{1e4d}', '\u{00f5}', '\u{0301}', true),
+    ('\u{1e4e}', '\u{00d5}', '\u{0308}', true),
+    ('\u{1e4f}', '\u{00f5}', '\u{0308}', true),
+    ('\u{1e50}', '\u{014c}', '\u{0300}', true),
+    ('\u{1e51}', '\u{014d}', '\u{0300}', true),
+    ('\u{1e52}', '\u{014c}', '\u{0301}', true),
+    ('\u{1e53}', '\u{014d}', '\u{0301}', true),
+    ('\u{1e54}', '\u{0050}', '\u{0301}', true),
+    ('\u{1e55}', '\u{0070}', '\u{0301}', true),
+    ('\u{1e56}', '\u{0050}', '\u{0307}', true),
+    ('\u{1e57}', '\u{0070}', '\u{0307}', true),
+    ('\u{1e58}', '\u{0052}', '\u{0307}', true),
+    ('\u{1e59}', '\u{0072}', '\u{0307}', true),
+    ('\u{1e5a}', '\u{0052}', '\u{0323}', true),
+    ('\u{1e5b}', '\u{0072}', '\u{0323}', true),
+    ('\u{1e5c}', '\u{1e5a}', '\u{0304}', true),
+    ('\u{1e5d}', '\u{1e5b}', '\u{0304}', true),
+    ('\u{1e5e}', '\u{0052}', '\u{0331}', true),
+    ('\u{1e5f}', '\u{0072}', '\u{0331}', true),
+    ('\u{1e60}', '\u{0053}', '\u{0307}', true),
+    ('\u{1e61}', '\u{0073}', '\u{0307}', true),
+    ('\u{1e62}', '\u{0053}', '\u{0323}', true),
+    ('\u{1e63}', '\u{0073}', '\u{0323}', true),
+    ('\u{1e64}', '\u{015a}', '\u{0307}', true),
+    ('\u{1e65}', '\u{015b}', '\u{0307}', true),
+    ('\u{1e66}', '\u{0160}', '\u{0307}', true),
+    ('\u{1e67}', '\u{0161}', '\u{0307}', true),
+    ('\u{1e68}', '\u{1e62}', '\u{0307}', true),
+    ('\u{1e69}', '\u{1e63}', '\u{0307}', true),
+    ('\u{1e6a}', '\u{0054}', '\u{0307}', true),
+    ('\u{1e6b}', '\u{0074}', '\u{0307}', true),
+    ('\u{1e6c}', '\u{0054}', '\u{0323}', true),
+    ('\u{1e6d}', '\u{0074}', '\u{0323}', true),
+    ('\u{1e6e}', '\u{0054}', '\u{0331}', true),
+    ('\u{1e6f}', '\u{0074}', '\u{0331}', true),
+    ('\u{1e70}', '\u{0054}', '\u{032d}', true),
+    ('\u{1e71}', '\u{0074}', '\u{032d}', true),
+    ('\u{1e72}', '\u{0055}', '\u{0324}', true),
+    ('\u{1e73}', '\u{0075}', '\u{0324}', true),
+    ('\u{1e74}', '\u{0055}', '\u{0330}', true),
+    ('\u{1e75}', '\u{0075}', '\u{0330}', true),
+    ('\u{1e76}', '\u{0055}', '\u{032d}', true),
+    ('\u{1e77}', '\u{0075}', '\u{032d}', true),
+    ('\u{1e78}', '\u{0168}', '\u{0301}', true),
+    ('\u{1e79}', '\u{0169}', '\u{0301}', true),
+    ('\u{1e7a}', '\u{016a}', '\u{0308}', true),
+    ('\u{1e7b}', '\u{016b}', '\u{0308}', true),
+    ('\u{1e7c}', '\u{0056}', '\u{0303}', true),
+    ('\u{1e7d}', '\u{0076}', '\u{0303}', true),
+    ('\u{1e7e}', '\u{0056}', '\u{0323}', true),
+    ('\u{1e7f}', '\u{0076}', '\u{0323}', true),
+    ('\u{1e80}', '\u{0057}', '\u{0300}', true),
+    ('\u{1e81}', '\u{0077}', '\u{0300}', true),
+    ('\u{1e82}', '\u{0057}', '\u{0301}', true),
+    ('\u{1e83}', '\u{0077}', '\u{0301}', true),
+    ('\u{1e84}', '\u{0057}', '\u{0308}', true),
+    ('\u{1e85}', '\u{0077}', '\u{0308}', true),
+    ('\u{1e86}', '\u{0057}', '\u{0307}', true),
+    ('\u{1e87}', '\u{0077}', '\u{0307}', true),
+    ('\u{1e88}', '\u{0057}', '\u{0323}', true),
+    ('\u{1e89}', '\u{0077}', '\u{0323}', true),
+    ('\u{1e8a}', '\u{0058}', '\u{0307}', true),
+    ('\u{1e8b}', '\u{0078}', '\u{0307}', true),
+    ('\u{1e8c}', '\u{0058}', '\u{0308}', true),
+    ('\u{1e8d}', '\u{0078}', '\u{0308}', true),
+    ('\u{1e8e}', '\u{0059}', '\u{0307}', true),
+    ('\u{1e8f}', '\u{0079}', '\u{0307}', true),
+    ('\u{1e90}', '\u{005a}', '\u{0302}', true),
+    ('\u{1e91}', '\u{007a}', '\u{0302}', true),
+    ('\u{1e92}', '\u{005a}', '\u{0323}', true),
+    ('\u{1e93}', '\u{007a}', '\u{0323}', true),
+    ('\u{1e94}', '\u{005a}', '\u{0331}', true),
+    ('\u{1e95}', '\u{007a}', '\u{0331}', true),
+    ('\u{1e96}', '\u{0068}', '\u{0331}', true),
+    ('\u{1e97}', '\u{0074}', '\u{0308}', true),
+    ('\u{1e98}', '\u{0077}', '\u{030a}', true),
+    ('\u{1e99}', '\u{0079}', '\u{030a}', true),
+    ('\u{1e9b}', '\u{017f}', '\u{0307}', true),
+    ('\u{1ea0}', '\u{0041}', '\u{0323}', true),
+    ('\u{1ea1}', '\u{0061}', '\u{0323}', true),
+    ('\u{1ea2}', '\u{0041}', '\u{0309}', true),
+    ('\u{1ea3}', '\u{0061}', '\u{0309}', true),
+    ('\u{1ea4}', '\u{00c2}', '\u{0301}', true),
+    ('\u{1ea5}', '\u{00e2}', '\u{0301}', true),
+    ('\u{1ea6}', '\u{00c2}', '\u{0300}', true),
+    ('\u{1ea7}', '\u{00e2}', '\u{0300}', true),
+    ('\u{1ea8}', '\u{00c2}', '\u{0309}', true),
+    ('\u{1ea9}', '\u{00e2}', '\u{0309}', true),
+    ('\u{1eaa}', '\u{00c2}', '\u{0303}', true),
+    ('\u{1eab}', '\u{00e2}', '\u{0303}', true),
+    ('\u{1eac}', '\u{1ea0}', '\u{0302}', true),
+    ('\u{1ead}', '\u{1ea1}', '\u{0302}', true),
+    ('\u{1eae}', '\u{0102}', '\u{0301}', true),
+    ('\u{1eaf}', '\u{0103}', '\u{0301}', true),
+    ('\u{1eb0}', '\u{0102}', '\u{0300}', true),
+    ('\u{1eb1}', '\u{0103}', '\u{0300}', true),
+    ('\u{1eb2}', '\u{0102}', '\u{0309}', true),
+    ('\u{1eb3}', '\u{0103}', '\u{0309}', true),
+    ('\u{1eb4}', '\u{0102}', '\u{0303}', true),
+    ('\u{1eb5}', '\u{0103}', '\u{0303}', true),
+    ('\u{1eb6}', '\u{1ea0}', '\u{0306}', true),
+    ('\u{1eb7}', '\u{1ea1}', '\u{0306}', true),
+    ('\u{1eb8}', '\u{0045}', '\u{0323}', true),
+    ('\u{1eb9}', '\u{0065}', '\u{0323}', true),
+    ('\u{1eba}', '\u{0045}', '\u{0309}', true),
+    ('\u{1ebb}', '\u{0065}', '\u{0309}', true),
+    ('\u{1ebc}', '\u{0045}', '\u{0303}', true),
+    ('\u{1ebd}', '\u{0065}', '\u{0303}', true),
+    ('\u{1ebe}', '\u{00ca}', '\u{0301}', true),
+    ('\u{1ebf}', '\u{00ea}', '\u{0301}', true),
+    ('\u{1ec0}', '\u{00ca}', '\u{0300}', true),
+    ('\u{1ec1}', '\u{00ea}', '\u{0300}', true),
+    ('\u{1ec2}', '\u{00ca}', '\u{0309}', true),
+    ('\u{1ec3}', '\u{00ea}', '\u{0309}', true),
+    ('\u{1ec4}', '\u{00ca}', '\u{0303}', true),
+    ('\u{1ec5}', '\u{00ea}', '\u{0303}', true),
+    ('\u{1ec6}', '\u{1eb8}', '\u{0302}', true),
+    ('\u{1ec7}', '\u{1eb9}', '\u{0302}', true),
+    ('\u{1ec8}', '\u{0049}', '\u{0309}', true),
+    ('\u{1ec9}', '\u{0069}', '\u{0309}', true),
+    ('\u{1eca}', '\u{0049}', '\u{0323}', true),
+    ('\u{1ecb}', '\u{0069}', '\u{0323}', true),
+    ('\u{1ecc}', '\u{004f}', '\u{0323}', true),
+    ('\u{1ecd}', '\u{006f}', '\u{0323}', true),
+    ('\u{1ece}', '\u{004f}', '\u{0309}', true),
+    ('\u{1ecf}', '\u{006f}', '\u{0309}', true),
+    ('\u{1ed0}', '\u{00d4}', '\u{0301}', true),
+    ('\u{1ed1}', '\u{00f4}', '\u{0301}', true),
+    ('\u{1ed2}', '\u{00d4}', '\u{0300}', true),
+    ('\u{1ed3}', '\u{00f4}', '\u{0300}', true),
+    ('\u{1ed4}', '\u{00d4}', '\u{0309}', true),
+    ('\u{1ed5}', '\u{00f4}', '\u{0309}', true),
+    ('\u{1ed6}', '\u{00d4}', '\u{0303}', true),
+    ('\u{1ed7}', '\u{00f4}', '\u{0303}', true),
+    ('\u{1ed8}', '\u{1ecc}', '\u{0302}', true),
+    ('\u{1ed9}', '\u{1ecd}', '\u{0302}', true),
+    ('\u{1eda}', '\u{01a0}', '\u{0301}', true),
+    ('\u{1edb}', '\u{01a1}', '\u{0301}', true),
+    ('\u{1edc}', '\u{01a0}', '\u{0300}', true),
+    ('\u{1edd}', '\u{01a1}', '\u{0300}', true),
+    ('\u{1ede}', '\u{01a0}', '\u{0309}', true),
+    ('\u{1edf}', '\u{01a1}', '\u{0309}', true),
+    ('\u{1ee0}', '\u{01a0}', '\u{0303}', true),
+    ('\u{1ee1}', '\u{01a1}', '\u{0303}', true),
+    ('\u{1ee2}', '\u{01a0}', '\u{0323}', true),
+    ('\u{1ee3}', '\u{01a1}', '\u{0323}', true),
+    ('\u{1ee4}', '\u{0055}', '\u{0323}', true),
+    ('\u{1ee5}', '\u{0075}', '\u{0323}', true),
+    ('\u{1ee6}', '\u{0055}', '\u{0309}', true),
+    ('\u{1ee7}', '\u{0075}', '\u{0309}', true),
+    ('\u{1ee8}', '\u{01af}', '\u{0301}', true),
+    ('\u{1ee9}', '\u{01b0}', '\u{0301}', true),
+    ('\u{1eea}', '\u{01af}', '\u{0300}', true),
+    ('\u{1eeb}', '\u{01b0}', '\u{0300}', true),
+    ('\u{1eec}', '\u{01af}', '\u{0309}', true),
+    ('\u{1eed}', '\u{01b0}', '\u{0309}', true),
+    ('\u{1eee}', '\u{01af}', '\u{0303}', true),
+    ('\u{1eef}', '\u{01b0}', '\u{0303}', true),
+    ('\u{1ef0}', '\u{01af}', '\u{0323}', true),
+    ('\u{1ef1}', '\u{01b0}', '\u{0323}', true),
+    ('\u{1ef2}', '\u{0059}', '\u{0300}', true),
+    ('\u{1ef3}', '\u{0079}', '\u{0300}', true),
+    ('\u{1ef4}', '\u{0059}', '\u{0323}', true),
+    ('\u{1ef5}', '\u{0079}', '\u{0323}', true),
+    ('\u{1ef6}', '\u{0059}', '\u{0309}', true),
+    ('\u{1ef7}', '\u{0079}', '\u{0309}', true),
+    ('\u{1ef8}', '\u{0059}', '\u{0303}', true),
+    ('\u{1ef9}', '\u{0079}', '\u{0303}', true),
+    ('\u{1f00}', '\u{03b1}', '\u{0313}', true),
+    ('\u{1f01}', '\u{03b1}', '\u{0314}', true),
+    ('\u{1f02}', '\u{1f00}', '\u{0300}', true),
+    ('\u{1f03}', '\u{1f01}', '\u{0300}', true),
+    ('\u{1f04}', '\u{1f00}', '\u{0301}', true),
+    ('\u{1f05}', '\u{1f01}', '\u{0301}', true),
+    ('\u{1f06}', '\u{1f00}', '\u{0342}', true),
+    ('\u{1f07}', '\u{1f01}', '\u{0342}', true),
+    ('\u{1f08}', '\u{0391}', '\u{0313}', true),
+    ('\u{1f09}', '\u{0391}', '\u{0314}', true),
+    ('\u{1f0a}', '\u{1f08}', '\u{0300}', true),
+    ('\u{1f0b}', '\u{1f09}', '\u{0300}', true),
+    ('\u{1f0c}', '\u{1f08}', '\u{0301}', true),
+    ('\u{1f0d}', '\u{1f09}', '\u{0301}', true),
+    ('\u{1f0e}', '\u{1f08}', '\u{0342}', true),
+    ('\u{1f0f}', '\u{1f09}', '\u{0342}', true),
+    ('\u{1f10}', '\u{03b5}', '\u{0313}', true),
+    ('\u{1f11}', '\u{03b5}', '\u{0314}', true),
+    ('\u{1f12}', '\u{1f10}', '\u{0300}', true),
+    ('\u{1f13}', '\u{1f11}', '\u{0300}', true),
+    ('\u{1f14}', '\u{1f10}', '\u{0301}', true),
+    ('\u{1f15}', '\u{1f11}', '\u{0301}', true),
+    ('\u{1f18}', '\u{0395}', '\u{0313}', true),
+    ('\u{1f19}', '\u{0395}', '\u{0314}', true),
+    ('\u{1f1a}', '\u{1f18}', '\u{0300}', true),
+    ('\u{1f1b}', '\u{1f19}', '\u{0300}', true),
+    ('\u{1f1c}', '\u{1f18}', '\u{0301}', true),
+    ('\u{1f1d}', '\u{1f19}', '\u{0301}', true),
+    ('\u{1f20}', '\u{03b7}', '\u{0313}', true),
+    ('\u{1f21}', '\u{03b7}', '\u{0314}', true),
+    ('\u{1f22}', '\u{1f20}', '\u{0300}', true),
+    ('\u{1f23}', '\u{1f21}', '\u{0300}', true),
+    ('\u{1f24}', '\u{1f20}', '\u{0301}', true),
+    ('\u{1f25}', '\u{1f21}', '\u{0301}', true),
+    ('\u{1f26}', '\u{1f20}', '\u{0342}', true),
+    ('\u{1f27}', '\u{1f21}', '\u{0342}', true),
+    ('\u{1f28}', '\u{0397}', '\u{0313}', true),
+    ('\u{1f29}', '\u{0397}', '\u{0314}', true),
+    ('\u{1f2a}', '\u{1f28}', '\u{0300}', true),
+    ('\u{1f2b}', '\u{1f29}', '\u{0300}', true),
+    ('\u{1f2c}', '\u{1f28}', '\u{0301}', true),
+    ('\u{1f2d}', '\u{1f29}', '\u{0301}', true),
+    ('\u{1f2e}', '\u{1f28}', '\u{0342}', true),
+    ('\u{1f2f}', '\u{1f29}', '\u{0342}', true),
+    ('\u{1f30}', '\u{03b9}', '\u{0313}', true),
+    ('\u{1f31}', '\u{03b9}', '\u{0314}', true),
+    ('\u{1f32}', '\u{1f30}', '\u{0300}', true),
+    ('\u{1f33}', '\u{1f31}', '\u{0300}', true),
+    ('\u{1f34}', '\u{1f30}', '\u{0301}', true),
+    ('\u{1f35}', '\u{1f31}', '\u{0301}', true),
+    ('\u{1f36}', '\u{1f30}', '\u{0342}', true),
+    ('\u{1f37}', '\u{1f31}', '\u{0342}', true),
+    ('\u{1f38}', '\u{0399}', '\u{0313}', true),
+    ('\u{1f39}', '\u{0399}', '\u{0314}', true),
+    ('\u{1f3a}', '\u{1f38}', '\u{0300}', true),
+    ('\u{1f3b}', '\u{1f39}', '\u{0300}', true),
+    ('\u{1f3c}', '\u{1f38}', '\u{0301}', true),
+    ('\u{1f3d}', '\u{1f39}', '\u{0301}', true),
+    ('\u{1f3e}', '\u{1f38}', '\u{0342}', true),
+    ('\u{1f3f}', '\u{1f39}', '\u{0342}', true),
+    ('\u{1f40}', '\u{03bf}', '\u{0313}', true),
+    ('\u{1f41}', '\u{03bf}', '\u{0314}', true),
+    ('\u{1f42}', '\u{1f40}', '\u{0300}', true),
+    ('\u{1f43}', '\u{1f41}', '\u{0300}', true),
+    ('\u{1f44}', '\u{1f40}', '\u{0301}', true),
+    ('\u{1f45}', '\u{1f41}', '\u{0301}', true),
+    ('\u{1f48}', '\u{039f}', '\u{0313}', true),
+    ('\u{1f49}', '\u{039f}', '\u{0314}', true),
+    ('\u{1f4a}', '\u{1f48}', '\u{0300}', true),
+    ('\u{1f4b}', '\u{1f49}', '\u{0300}', true),
+    ('\u{1f4c}', '\u{1f48}', '\u{0301}', true),
+    ('\u{1f4d}', '\u{1f49}', '\u{0301}', true),
+    ('\u{1f50}', '\u{03c5}', '\u{0313}', true),
+    ('\u{1f51}', '\u{03c5}', '\u{0314}', true),
+    ('\u{1f52}', '\u{1f50}', '\u{0300}', true),
+    ('\u{1f53}', '\u{1f51}', '\u{0300}', true),
+    ('\u{1f54}', '\u{1f50}', '\u{0301}', true),
+    ('\u{1f55}', '\u{1f51}', '\u{0301}', true),
+    ('\u{1f56}', '\u{1f50}', '\u{0342}', true),
+    ('\u{1f57}', '\u{1f51}', '\u{0342}', true),
+    ('\u{1f59}', '\u{03a5}', '\u{0314}', true),
+    ('\u{1f5b}', '\u{1f59}', '\u{0300}', true),
+    ('\u{1f5d}', '\u{1f59}', '\u{0301}', true),
+    ('\u{1f5f}', '\u{1f59}', '\u{0342}', true),
+    ('\u{1f60}', '\u{03c9}', '\u{0313}', true),
+    ('\u{1f61}', '\u{03c9}', '\u{0314}', true),
+    ('\u{1f62}', '\u{1f60}', '\u{0300}', true),
+    ('\u{1f63}', '\u{1f61}', '\u{0300}', true),
+    ('\u{1f64}', '\u{1f60}', '\u{0301}', true),
+    ('\u{1f65}', '\u{1f61}', '\u{0301}', true),
+    ('\u{1f66}', '\u{1f60}', '\u{0342}', true),
+    ('\u{1f67}', '\u{1f61}', '\u{0342}', true),
+    ('\u{1f68}', '\u{03a9}', '\u{0313}', true),
+    ('\u{1f69}', '\u{03a9}', '\u{0314}', true),
+    ('\u{1f6a}', '\u{1f68}', '\u{0300}', true),
+    ('\u{1f6b}', '\u{1f69}', '\u{0300}', true),
+    ('\u{1f6c}', '\u{1f68}', '\u{0301}', true),
+    ('\u{1f6d}', '\u{1f69}', '\u{0301}', true),
+    ('\u{1f6e}', '\u{1f68}', '\u{0342}', true),
+    ('\u{1f6f}', '\u{1f69}', '\u{0342}', true),
+    ('\u{1f70}', '\u{03b1}', '\u{0300}', true),
+    ('\u{1f71}', '\u{03ac}', '\u{0000}', false),
+    ('\u{1f72}', '\u{03b5}', '\u{0300}', true),
+    ('\u{1f73}', '\u{03ad}', '\u{0000}', false),
+    ('\u{1f74}', '\u{03b7}', '\u{0300}', true),
+    ('\u{1f75}', '\u{03ae}', '\u{0000}', false),
+    ('\u{1f76}', '\u{03b9}', '\u{0300}', true),
+    ('\u{1f77}', '\u{03af}', '\u{0000}', false),
+    ('\u{1f78}', '\u{03bf}', '\u{0300}', true),
+    ('\u{1f79}', '\u{03cc}', '\u{0000}', false),
+    ('\u{1f7a}', '\u{03c5}', '\u{0300}', true),
+    ('\u{1f7b}', '\u{03cd}', '\u{0000}', false),
+    ('\u{1f7c}', '\u{03c9}', '\u{0300}', true),
+    ('\u{1f7d}', '\u{03ce}', '\u{0000}', false),
+    ('\u{1f80}', '\u{1f00}', '\u{0345}', true),
+    ('\u{1f81}', '\u{1f01}', '\u{0345}', true),
+    ('\u{1f82}', '\u{1f02}', '\u{0345}', true),
+    ('\u{1f83}', '\u{1f03}', '\u{0345}', true),
+    ('\u{1f84}', '\u{1f04}', '\u{0345}', true),
+    ('\u{1f85}', '\u{1f05}', '\u{0345}', true),
+    ('\u{1f86}', '\u{1f06}', '\u{0345}', true),
+    ('\u{1f87}', '\u{1f07}', '\u{0345}', true),
+    ('\u{1f88}', '\u{1f08}', '\u{0345}', true),
+    ('\u{1f89}', '\u{1f09}', '\u{0345}', true),
+    ('\u{1f8a}', '\u{1f0a}', '\u{0345}', true),
+    ('\u{1f8b}', '\u{1f0b}', '\u{0345}', true),
+    ('\u{1f8c}', '\u{1f0c}', '\u{0345}', true),
+    ('\u{1f8d}', '\u{1f0d}', '\u{0345}', true),
+    ('\u{1f8e}', '\u{1f0e}', '\u{0345}', true),
+    ('\u{1f8f}', '\u{1f0f}', '\u{0345}', true),
+    ('\u{1f90}', '\u{1f20}', '\u{0345}', true),
+    ('\u{1f91}', '\u{1f21}', '\u{0345}', true),
+    ('\u{1f92}', '\u{1f22}', '\u{0345}', true),
+    ('\u{1f93}', '\u{1f23}', '\u{0345}', true),
+    ('\u{1f94}', '\u{1f24}', '\u{0345}', true),
+    ('\u{1f95}', '\u{1f25}', '\u{0345}', true),
+    ('\u{1f96}', '\u{1f26}', '\u{0345}', true),
+    ('\u{1f97}', '\u{1f27}', '\u{0345}', true),
+    ('\u{1f98}', '\u{1f28}', '\u{0345}', true),
+    ('\u{1f99}', '\u{1f29}', '\u{0345}', true),
+    ('\u{1f9a}', '\u{1f2a}', '\u{0345}', true),
+    ('\u{1f9b}', '\u{1f2b}', '\u{0345}', true),
+    ('\u{1f9c}', '\u{1f2c}', '\u{0345}', true),
+    ('\u{1f9d}', '\u{1f2d}', '\u{0345}', true),
+    ('\u{1f9e}', '\u{1f2e}', '\u{0345}', true),
+    ('\u{1f9f}', '\u{1f2f}', '\u{0345}', true),
+    ('\u{1fa0}', '\u{1f60}', '\u{0345}', true),
+    ('\u{1fa1}', '\u{1f61}', '\u{0345}', true),
+    ('\u{1fa2}', '\u{1f62}', '\u{0345}', true),
+    ('\u{1fa3}', '\u{1f63}', '\u{0345}', true),
+    ('\u{1fa4}', '\u{1f64}', '\u{0345}', true),
+    ('\u{1fa5}', '\u{1f65}', '\u{0345}', true),
+    ('\u{1fa6}', '\u{1f66}', '\u{0345}', true),
+    ('\u{1fa7}', '\u{1f67}', '\u{0345}', true),
+    ('\u{1fa8}', '\u{1f68}', '\u{0345}', true),
+    ('\u{1fa9}', '\u{1f69}', '\u{0345}', true),
+    ('\u{1faa}', '\u{1f6a}', '\u{0345}', true),
+    ('\u{1fab}', '\u{1f6b}', '\u{0345}', true),
+    ('\u{1fac}', '\u{1f6c}', '\u{0345}', true),
+    ('\u{1fad}', '\u{1f6d}', '\u{0345}', true),
+    ('\u{1fae}', '\u{1f6e}', '\u{0345}', true),
+    ('\u{1faf}', '\u{1f6f}', '\u{0345}', true),
+    ('\u{1fb0}', '\u{03b1}', '\u{0306}', true),
+    ('\u{1fb1}', '\u{03b1}', '\u{0304}', true),
+    ('\u{1fb2}', '\u{1f70}', '\u{0345}', true),
+    ('\u{1fb3}', '\u{03b1}', '\u{0345}', true),
+    ('\u{1fb4}', '\u{03ac}', '\u{0345}', true),
+    ('\u{1fb6}', '\u{03b1}', '\u{0342}', true),
+    ('\u{1fb7}', '\u{1fb6}', '\u{0345}', true),
+    ('\u{1fb8}', '\u{0391}', '\u{0306}', true),
+    ('\u{1fb9}', '\u{0391}', '\u{0304}', true),
+    ('\u{1fba}', '\u{0391}', '\u{0300}', true),
+    ('\u{1fbb}', '\u{0386}', '\u{0000}', false),
+    ('\u{1fbc}', '\u{0391}', '\u{0345}', true),
+    ('\u{1fbe}', '\u{03b9}', '\u{0000}', false),
+    ('\u{1fc1}', '\u{00a8}', '\u{0342}', true),
+    ('\u{1fc2}', '\u{1f74}', '\u{0345}', true),
+    ('\u{1fc3}', '\u{03b7}', '\u{0345}', true),
+    ('\u{1fc4}', '\u{03ae}', '\u{0345}', true),
+    ('\u{1fc6}', '\u{03b7}', '\u{0342}', true),
+    ('\u{1fc7}', '\u{1fc6}', '\u{0345}', true),
+    ('\u{1fc8}', '\u{0395}', '\u{0300}', true),
+    ('\u{1fc9}', '\u{0388}', '\u{0000}', false),
+    ('\u{1fca}', '\u{0397}', '\u{0300}', true),
+    ('\u{1fcb}', '\u{0389}', '\u{0000}', false),
+    ('\u{1fcc}', '\u{0397}', '\u{0345}', true),
+    ('\u{1fcd}', '\u{1fbf}', '\u{0300}', true),
+    ('\u{1fce}', '\u{1fbf}', '\u{0301}', true),
+    ('\u{1fcf}', '\u{1fbf}', '\u{0342}', true),
+    ('\u{1fd0}', '\u{03b9}', '\u{0306}', true),
+    ('\u{1fd1}', '\u{03b9}', '\u{0304}', true),
+    ('\u{1fd2}', '\u{03ca}', '\u{0300}', true),
+    ('\u{1fd3}', '\u{0390}', '\u{0000}', false),
+    ('\u{1fd6}', '\u{03b9}', '\u{0342}', true),
+    ('\u{1fd7}', '\u{03ca}', '\u{0342}', true),
+    ('\u{1fd8}', '\u{0399}', '\u{0306}', true),
+    ('\u{1fd9}', '\u{0399}', '\u{0304}', true),
+    ('\u{1fda}', '\u{0399}', '\u{0300}', true),
+    ('\u{1fdb}', '\u{038a}', '\u{0000}', false),
+    ('\u{1fdd}', '\u{1ffe}', '\u{0300}', true),
+    ('\u{1fde}', '\u{1ffe}', '\u{0301}', true),
+    ('\u{1fdf}', '\u{1ffe}', '\u{0342}', true),
+    ('\u{1fe0}', '\u{03c5}', '\u{0306}', true),
+    ('\u{1fe1}', '\u{03c5}', '\u{0304}', true),
+    ('\u{1fe2}', '\u{03cb}', '\u{0300}', true),
+    ('\u{1fe3}', '\u{03b0}', '\u{0000}', false),
+    ('\u{1fe4}', '\u{03c1}', '\u{0313}', true),
+    ('\u{1fe5}', '\u{03c1}', '\u{0314}', true),
+    ('\u{1fe6}', '\u{03c5}', '\u{0342}', true),
+    ('\u{1fe7}', '\u{03cb}', '\u{0342}', true),
+    ('\u{1fe8}', '\u{03a5}', '\u{0306}', true),
+    ('\u{1fe9}', '\u{03a5}', '\u{0304}', true),
+    ('\u{1fea}', '\u{03a5}', '\u{0300}', true),
+    ('\u{1feb}', '\u{038e}', '\u{0000}', false),
+    ('\u{1fec}', '\u{03a1}', '\u{0314}', true),
+    ('\u{1fed}', '\u{00a8}', '\u{0300}', true),
+    ('\u{1fee}', '\u{0385}', '\u{0000}', false),
+    ('\u{1fef}', '\u{0060}', '\u{0000}', false),
+    ('\u{1ff2}', '\u{1f7c}', '\u{0345}', true),
+    ('\u{1ff3}', '\u{03c9}', '\u{0345}', true),
+    ('\u{1ff4}', '\u{03ce}', '\u{0345}', true),
+    ('\u{1ff6}', '\u{03c9}', '\u{0342}', true),
+    ('\u{1ff7}', '\u{1ff6}', '\u{0345}', true),
+    ('\u{1ff8}', '\u{039f}', '\u{0300}', true),
+    ('\u{1ff9}', '\u{038c}', '\u{0000}', false),
+    ('\u{1ffa}', '\u{03a9}', '\u{0300}', true),
+    ('\u{1ffb}', '\u{038f}', '\u{0000}', false),
+    ('\u{1ffc}', '\u{03a9}', '\u{0345}', true),
+    ('\u{1ffd}', '\u{00b4}', '\u{0000}', false),
+    ('\u{2000}', '\u{2002}', '\u{0000}', false),
+    ('\u{2001}', '\u{2003}', '\u{0000}', false),
+    ('\u{2126}', '\u{03a9}', '\u{0000}', false),
+    ('\u{212a}', '\u{004b}', '\u{0000}', false),
+    ('\u{212b}', '\u{00c5}', '\u{0000}', false),
+    ('\u{219a}', '\u{2190}', '\u{0338}', true),
+    ('\u{219b}', '\u{2192}', '\u{0338}', true),
+    ('\u{21ae}', '\u{2194}', '\u{0338}', true),
+    ('\u{21cd}', '\u{21d0}', '\u{0338}', true),
+    ('\u{21ce}', '\u{21d4}', '\u{0338}', true),
+    ('\u{21cf}', '\u{21d2}', '\u{0338}', true),
+    ('\u{2204}', '\u{2203}', '\u{0338}', true),
+    ('\u{2209}', '\u{2208}', '\u{0338}', true),
+    ('\u{220c}', '\u{220b}', '\u{0338}', true),
+    ('\u{2224}', '\u{2223}', '\u{0338}', true),
+    ('\u{2226}', '\u{2225}', '\u{0338}', true),
+    ('\u{2241}', '\u{223c}', '\u{0338}', true),
+    ('\u{2244}', '\u{2243}', '\u{0338}', true),
+    ('\u{2247}', '\u{2245}', '\u{0338}', true),
+    ('\u{2249}', '\u{2248}', '\u{0338}', true),
+    ('\u{2260}', '\u{003d}', '\u{0338}', true),
+    ('\u{2262}', '\u{2261}', '\u{0338}', true),
+    ('\u{226d}', '\u{224d}', '\u{0338}', true),
+    ('\u{226e}', '\u{003c}', '\u{0338}', true),
+    ('\u{226f}', '\u{003e}', '\u{0338}', true),
+    ('\u{2270}', '\u{2264}', '\u{0338}', true),
+    ('\u{2271}', '\u{2265}', '\u{0338}', true),
+    ('\u{2274}', '\u{2272}', '\u{0338}', true),
+    ('\u{2275}', '\u{2273}', '\u{0338}', true),
+    ('\u{2278}', '\u{2276}', '\u{0338}', true),
+    ('\u{2279}', '\u{2277}', '\u{0338}', true),
+    ('\u{2280}', '\u{227a}', '\u{0338}', true),
+    ('\u{2281}', '\u{227b}', '\u{0338}', true),
+    ('\u{2284}', '\u{2282}', '\u{0338}', true),
+    ('\u{2285}', '\u{2283}', '\u{0338}', true),
+    ('\u{2288}', '\u{2286}', '\u{0338}', true),
+    ('\u{2289}', '\u{2287}', '\u{0338}', true),
+    ('\u{22ac}', '\u{22a2}', '\u{0338}', true),
+    ('\u{22ad}', '\u{22a8}', '\u{0338}', true),
+    ('\u{22ae}', '\u{22a9}', '\u{0338}', true),
+    ('\u{22af}', '\u{22ab}', '\u{0338}', true),
+    ('\u{22e0}', '\u{227c}', '\u{0338}', true),
+    ('\u{22e1}', '\u{227d}', '\u{0338}', true),
+    ('\u{22e2}', '\u{2291}', '\u{0338}', true),
+    ('\u{22e3}', '\u{2292}', '\u{0338}', true),
+    ('\u{22ea}', '\u{22b2}', '\u{0338}', true),
+    ('\u{22eb}', '\u{22b3}', '\u{0338}', true),
+    ('\u{22ec}', '\u{22b4}', '\u{0338}', true),
+    ('\u{22ed}', '\u{22b5}', '\u{0338}', true),
+    ('\u{2329}', '\u{3008}', '\u{0000}', false),
+    ('\u{232a}', '\u{3009}', '\u{0000}', false),
+    ('\u{2adc}', '\u{2add}', '\u{0338}', false),
+    ('\u{304c}', '\u{304b}', '\u{3099}', true),
+    ('\u{304e}', '\u{304d}', '\u{3099}', true),
+    ('\u{3050}', '\u{304f}', '\u{3099}', true),
+    ('\u{3052}', '\u{3051}', '\u{3099}', true),
+    ('\u{3054}', '\u{3053}', '\u{3099}', true),
+    ('\u{3056}', '\u{3055}', '\u{3099}', true),
+    ('\u{3058}', '\u{3057}', '\u{3099}', true),
+    ('\u{305a}', '\u{3059}', '\u{3099}', true),
+    ('\u{305c}', '\u{305b}', '\u{3099}', true),
+    ('\u{305e}', '\u{305d}', '\u{3099}', true),
+    ('\u{3060}', '\u{305f}', '\u{3099}', true),
+    ('\u{3062}', '\u{3061}', '\u{3099}', true),
+    ('\u{3065}', '\u{3064}', '\u{3099}', true),
+    ('\u{3067}', '\u{3066}', '\u{3099}', true),
+    ('\u{3069}', '\u{3068}', '\u{3099}', true),
+    ('\u{3070}', '\u{306f}', '\u{3099}', true),
+    ('\u{3071}', '\u{306f}', '\u{309a}', true),
+    ('\u{3073}', '\u{3072}', '\u{3099}', true),
+    ('\u{3074}', '\u{3072}', '\u{309a}', true),
+    ('\u{3076}', '\u{3075}', '\u{3099}', true),
+    ('\u{3077}', '\u{3075}', '\u{309a}', true),
+    ('\u{3079}', '\u{3078}', '\u{3099}', true),
+    ('\u{307a}', '\u{3078}', '\u{309a}', true),
+    ('\u{307c}', '\u{307b}', '\u{3099}', true),
+    ('\u{307d}', '\u{307b}', '\u{309a}', true),
+    ('\u{3094}', '\u{3046}', '\u{3099}', true),
+    ('\u{309e}', '\u{309d}', '\u{3099}', true),
+    ('\u{30ac}', '\u{30ab}', '\u{3099}', true),
+    ('\u{30ae}', '\u{30ad}', '\u{3099}', true),
+    ('\u{30b0}', '\u{30af}', '\u{3099}', true),
+    ('\u{30b2}', '\u{30b1}', '\u{3099}', true),
+    ('\u{30b4}', '\u{30b3}', '\u{3099}', true),
+    ('\u{30b6}', '\u{30b5}', '\u{3099}', true),
+    ('\u{30b8}', '\u{30b7}', '\u{3099}', true),
+    ('\u{30ba}', '\u{30b9}', '\u{3099}', true),
+    ('\u{30bc}', '\u{30bb}', '\u{3099}', true),
+    ('\u{30be}', '\u{30bd}', '\u{3099}', true),
+    ('\u{30c0}', '\u{30bf}', '\u{3099}', true),
+    ('\u{30c2}', '\u{30c1}', '\u{3099}', true),
+    ('\u{30c5}', '\u{30c4}', '\u{3099}', true),
+    ('\u{30c7}', '\u{30c6}', '\u{3099}', true),
+    ('\u{30c9}', '\u{30c8}', '\u{3099}', true),
+    ('\u{30d0}', '\u{30cf}', '\u{3099}', true),
+    ('\u{30d1}', '\u{30cf}', '\u{309a}', true),
+    ('\u{30d3}', '\u{30d2}', '\u{3099}', true),
+    ('\u{30d4}', '\u{30d2}', '\u{309a}', true),
+    ('\u{30d6}', '\u{30d5}', '\u{3099}', true),
+    ('\u{30d7}', '\u{30d5}', '\u{309a}', true),
+    ('\u{30d9}', '\u{30d8}', '\u{3099}', true),
+    ('\u{30da}', '\u{30d8}', '\u{309a}', true),
+    ('\u{30dc}', '\u{30db}', '\u{3099}', true),
+    ('\u{30dd}', '\u{30db}', '\u{309a}', true),
+    ('\u{30f4}', '\u{30a6}', '\u{3099}', true),
+    ('\u{30f7}', '\u{30ef}', '\u{3099}', true),
+    ('\u{30f8}', '\u{30f0}', '\u{3099}', true),
+    ('\u{30f9}', '\u{30f1}', '\u{3099}', true),
+    ('\u{30fa}', '\u{30f2}', '\u{3099}', true),
+    ('\u{30fe}', '\u{30fd}', '\u{3099}', true),
+    ('\u{f900}', '\u{8c48}', '\u{0000}', false),
+    ('\u{f901}', '\u{66f4}', '\u{0000}', false),
+    ('\u{f902}', '\u{8eca}', '\u{0000}', false),
+    ('\u{f903}', '\u{8cc8}', '\u{0000}', false),
+    ('\u{f904}', '\u{6ed1}', '\u{0000}', false),
+    ('\u{f905}', '\u{4e32}', '\u{0000}', false),
+    ('\u{f906}', '\u{53e5}', '\u{0000}', false),
+    ('\u{f907}', '\u{9f9c}', '\u{0000}', false),
+    ('\u{f908}', '\u{9f9c}', '\u{0000}', false),
+    ('\u{f909}', '\u{5951}', '\u{0000}', false),
+    ('\u{f90a}', '\u{91d1}', '\u{0000}', false),
+    ('\u{f90b}', '\u{5587}', '\u{0000}', false),
+    ('\u{f90c}', '\u{5948}', '\u{0000}', false),
+    ('\u{f90d}', '\u{61f6}', '\u{0000}', false),
+    ('\u{f90e}', '\u{7669}', '\u{0000}', false),
+    ('\u{f90f}', '\u{7f85}', '\u{0000}', false),
+    ('\u{f910}', '\u{863f}', '\u{0000}', false),
+    ('\u{f911}', '\u{87ba}', '\u{0000}', false),
+    ('\u{f912}', '\u{88f8}', '\u{0000}', false),
+    ('\u{f913}', '\u{908f}', '\u{0000}', false),
+    ('\u{f914}', '\u{6a02}', '\u{0000}', false),
+    ('\u{f915}', '\u{6d1b}', '\u{0000}', false),
+    ('\u{f916}', '\u{70d9}', '\u{0000}', false),
+    ('\u{f917}', '\u{73de}', '\u{0000}', false),
+    ('\u{f918}', '\u{843d}', '\u{0000}', false),
+    ('\u{f919}', '\u{916a}', '\u{0000}', false),
+    ('\u{f91a}', '\u{99f1}', '\u{0000}', false),
+    ('\u{f91b}', '\u{4e82}', '\u{0000}', false),
+    ('\u{f91c}', '\u{5375}', '\u{0000}', false),
+    ('\u{f91d}', '\u{6b04}', '\u{0000}', false),
+    ('\u{f91e}', '\u{721b}', '\u{0000}', false),
+    ('\u{f91f}', '\u{862d}', '\u{0000}', false),
+    ('\u{f920}', '\u{9e1e}', '\u{0000}', false),
+    ('\u{f921}', '\u{5d50}', '\u{0000}', false),
+    ('\u{f922}', '\u{6feb}', '\u{0000}', false),
+    ('\u{f923}', '\u{85cd}', '\u{0000}', false),
+    ('\u{f924}', '\u{8964}', '\u{0000}', false),
+    ('\u{f925}', '\u{62c9}', '\u{0000}', false),
+    ('\u{f926}', '\u{81d8}', '\u{0000}', false),
+    ('\u{f927}', '\u{881f}', '\u{0000}', false),
+    ('\u{f928}', '\u{5eca}', '\u{0000}', false),
+    ('\u{f929}', '\u{6717}', '\u{0000}', false),
+    ('\u{f92a}', '\u{6d6a}', '\u{0000}', false),
+    ('\u{f92b}', '\u{72fc}', '\u{0000}', false),
+    ('\u{f92c}', '\u{90ce}', '\u{0000}', false),
+    ('\u{f92d}', '\u{4f86}', '\u{0000}', false),
+    ('\u{f92e}', '\u{51b7}', '\u{0000}', false),
+    ('\u{f92f}', '\u{52de}', '\u{0000}', false),
+    ('\u{f930}', '\u{64c4}', '\u{0000}', false),
+    ('\u{f931}', '\u{6ad3}', '\u{0000}', false),
+    ('\u{f932}', '\u{7210}', '\u{0000}', false),
+    ('\u{f933}', '\u{76e7}', '\u{0000}', false),
+    ('\u{f934}', '\u{8001}', '\u{0000}', false),
+    ('\u{f935}', '\u{8606}', '\u{0000}', false),
+    ('\u{f936}', '\u{865c}', '\u{0000}', false),
+    ('\u{f937}', '\u{8def}', '\u{0000}', false),
+    ('\u{f938}', '\u{9732}', '\u{0000}', false),
+    ('\u{f939}', '\u{9b6f}', '\u{0000}', false),
+    ('\u{f93a}', '\u{9dfa}', '\u{0000}', false),
+    ('\u{f93b}', '\u{788c}', '\u{0000}', false),
+    ('\u{f93c}', '\u{797f}', '\u{0000}', false),
+    ('\u{f93d}', '\u{7da0}', '\u{0000}', false),
+    ('\u{f93e}', '\u{83c9}', '\u{0000}', false),
+    ('\u{f93f}', '\u{9304}', '\u{0000}', false),
+    ('\u{f940}', '\u{9e7f}', '\u{0000}', false),
+    ('\u{f941}', '\u{8ad6}', '\u{0000}', false),
+    ('\u{f942}', '\u{58df}', '\u{0000}', false),
+    ('\u{f943}', '\u{5f04}', '\u{0000}', false),
+    ('\u{f944}', '\u{7c60}', '\u{0000}', false),
+    ('\u{f945}', '\u{807e}', '\u{0000}', false),
+    ('\u{f946}', '\u{7262}', '\u{0000}', false),
+    ('\u{f947}', '\u{78ca}', '\u{0000}', false),
+    ('\u{f948}', '\u{8cc2}', '\u{0000}', false),
+    ('\u{f949}', '\u{96f7}', '\u{0000}', false),
+    ('\u{f94a}', '\u{58d8}', '\u{0000}', false),
+    ('\u{f94b}', '\u{5c62}', '\u{0000}', false),
+    ('\u{f94c}', '\u{6a13}', '\u{0000}', false),
+    ('\u{f94d}', '\u{6dda}', '\u{0000}', false),
+    ('\u{f94e}', '\u{6f0f}', '\u{0000}', false),
+    ('\u{f94f}', '\u{7d2f}', '\u{0000}', false),
+    ('\u{f950}', '\u{7e37}', '\u{0000}', false),
+    ('\u{f951}', '\u{964b}', '\u{0000}', false),
+    ('\u{f952}', '\u{52d2}', '\u{0000}', false),
+    ('\u{f953}', '\u{808b}', '\u{0000}', false),
+    ('\u{f954}', '\u{51dc}', '\u{0000}', false),
+    ('\u{f955}', '\u{51cc}', '\u{0000}', false),
+    ('\u{f956}', '\u{7a1c}', '\u{0000}', false),
+    ('\u{f957}', '\u{7dbe}', '\u{0000}', false),
+    ('\u{f958}', '\u{83f1}', '\u{0000}', false),
+    ('\u{f959}', '\u{9675}', '\u{0000}', false),
+    ('\u{f95a}', '\u{8b80}', '\u{0000}', false),
+    ('\u{f95b}', '\u{62cf}', '\u{0000}', false),
+    ('\u{f95c}', '\u{6a02}', '\u{0000}', false),
+    ('\u{f95d}', '\u{8afe}', '\u{0000}', false),
+    ('\u{f95e}', '\u{4e39}', '\u{0000}', false),
+    ('\u{f95f}', '\u{5be7}', '\u{0000}', false),
+    ('\u{f960}', '\u{6012}', '\u{0000}', false),
+    ('\u{f961}', '\u{7387}', '\u{0000}', false),
+    ('\u{f962}', '\u{7570}', '\u{0000}', false),
+    ('\u{f963}', '\u{5317}', '\u{0000}', false),
+    ('\u{f964}', '\u{78fb}', '\u{0000}', false),
+    ('\u{f965}', '\u{4fbf}', '\u{0000}', false),
+    ('\u{f966}', '\u{5fa9}', '\u{0000}', false),
+    ('\u{f967}', '\u{4e0d}', '\u{0000}', false),
+    ('\u{f968}', '\u{6ccc}', '\u{0000}', false),
+    ('\u{f969}', '\u{6578}', '\u{0000}', false),
+    ('\u{f96a}', '\u{7d22}', '\u{0000}', false),
+    ('\u{f96b}', '\u{53c3}', '\u{0000}', false),
+    ('\u{f96c}', '\u{585e}', '\u{0000}', false),
+    ('\u{f96d}', '\u{7701}', '\u{0000}', false),
+    ('\u{f96e}', '\u{8449}', '\u{0000}', false),
+    ('\u{f96f}', '\u{8aaa}', '\u{0000}', false),
+    ('\u{f970}', '\u{6bba}', '\u{0000}', false),
+    ('\u{f971}', '\u{8fb0}', '\u{0000}', false),
+    ('\u{f972}', '\u{6c88}', '\u{0000}', false),
+    ('\u{f973}', '\u{62fe}', '\u{0000}', false),
+    ('\u{f974}', '\u{82e5}', '\u{0000}', false),
+    ('\u{f975}', '\u{63a0}', '\u{0000}', false),
+    ('\u{f976}', '\u{7565}', '\u{0000}', false),
+    ('\u{f977}', '\u{4eae}', '\u{0000}', false),
+    ('\u{f978}', '\u{5169}', '\u{0000}', false),
+    ('\u{f979}', '\u{51c9}', '\u{0000}', false),
+    ('\u{f97a}', '\u{6881}', '\u{0000}', false),
+    ('\u{f97b}', '\u{7ce7}', '\u{0000}', false),
+    ('\u{f97c}', '\u{826f}', '\u{0000}', false),
+    ('\u{f97d}', '\u{8ad2}', '\u{0000}', false),
+    ('\u{f97e}', '\u{91cf}', '\u{0000}', false),
+    ('\u{f97f}', '\u{52f5}', '\u{0000}', false),
+    ('\u{f980}', '\u{5442}', '\u{0000}', false),
+    ('\u{f981}', '\u{5973}', '\u{0000}', false),
+    ('\u{f982}', '\u{5eec}', '\u{0000}', false),
+    ('\u{f983}', '\u{65c5}', '\u{0000}', false),
+    ('\u{f984}', '\u{6ffe}', '\u{0000}', false),
+    ('\u{f985}', '\u{792a}', '\u{0000}', false),
+    ('\u{f986}', '\u{95ad}', '\u{0000}', false),
+    ('\u{f987}', '\u{9a6a}', '\u{0000}', false),
+    ('\u{f988}', '\u{9e97}', '\u{0000}', false),
+    ('\u{f989}', '\u{9ece}', '\u{0000}', false),
+    ('\u{f98a}', '\u{529b}', '\u{0000}', false),
+    ('\u{f98b}', '\u{66c6}', '\u{0000}', false),
+    ('\u{f98c}', '\u{6b77}', '\u{0000}', false),
+    ('\u{f98d}', '\u{8f62}', '\u{0000}', false),
+    ('\u{f98e}', '\u{5e74}', '\u{0000}', false),
+    ('\u{f98f}', '\u{6190}', '\u{0000}', false),
+    ('\u{f990}', '\u{6200}', '\u{0000}', false),
+    ('\u{f991}', '\u{649a}', '\u{0000}', false),
+    ('\u{f992}', '\u{6f23}', '\u{0000}', false),
+    ('\u{f993}', '\u{7149}', '\u{0000}', false),
+    ('\u{f994}', '\u{7489}', '\u{0000}', false),
+    ('\u{f995}', '\u{79ca}', '\u{0000}', false),
+    ('\u{f996}', '\u{7df4}', '\u{0000}', false),
+    ('\u{f997}', '\u{806f}', '\u{0000}', false),
+    ('\u{f998}', '\u{8f26}', '\u{0000}', false),
+    ('\u{f999}', '\u{84ee}', '\u{0000}', false),
+    ('\u{f99a}', '\u{9023}', '\u{0000}', false),
+    ('\u{f99b}', '\u{934a}', '\u{0000}', false),
+    ('\u{f99c}', '\u{5217}', '\u{0000}', false),
+    ('\u{f99d}', '\u{52a3}', '\u{0000}', false),
+    ('\u{f99e}', '\u{54bd}', '\u{0000}', false),
+    ('\u{f99f}', '\u{70c8}', '\u{0000}', false),
+    ('\u{f9a0}', '\u{88c2}', '\u{0000}', false),
+    ('\u{f9a1}', '\u{8aaa}', '\u{0000}', false),
+    ('\u{f9a2}', '\u{5ec9}', '\u{0000}', false),
+    ('\u{f9a3}', '\u{5ff5}', '\u{0000}', false),
+    ('\u{f9a4}', '\u{637b}', '\u{0000}', false),
+    ('\u{f9a5}', '\u{6bae}', '\u{0000}', false),
+    ('\u{f9a6}', '\u{7c3e}', '\u{0000}', false),
+    ('\u{f9a7}', '\u{7375}', '\u{0000}', false),
+    ('\u{f9a8}', '\u{4ee4}', '\u{0000}', false),
+    ('\u{f9a9}', '\u{56f9}', '\u{0000}', false),
+    ('\u{f9aa}', '\u{5be7}', '\u{0000}', false),
+    ('\u{f9ab}', '\u{5dba}', '\u{0000}', false),
+    ('\u{f9ac}', '\u{601c}', '\u{0000}', false),
+    ('\u{f9ad}', '\u{73b2}', '\u{0000}', false),
+    ('\u{f9ae}', '\u{7469}', '\u{0000}', false),
+    ('\u{f9af}', '\u{7f9a}', '\u{0000}', false),
+    ('\u{f9b0}', '\u{8046}', '\u{0000}', false),
+    ('\u{f9b1}', '\u{9234}', '\u{0000}', false),
+    ('\u{f9b2}', '\u{96f6}', '\u{0000}', false),
+    ('\u{f9b3}', '\u{9748}', '\u{0000}', false),
+    ('\u{f9b4}', '\u{9818}', '\u{0000}', false),
+    ('\u{f9b5}', '\u{4f8b}', '\u{0000}', false),
+    ('\u{f9b6}', '\u{79ae}', '\u{0000}', false),
+    ('\u{f9b7}', '\u{91b4}', '\u{0000}', false),
+    ('\u{f9b8}', '\u{96b8}', '\u{0000}', false),
+    ('\u{f9b9}', '\u{60e1}', '\u{0000}', false),
+    ('\u{f9ba}', '\u{4e86}', '\u{0000}', false),
+    ('\u{f9bb}', '\u{50da}', '\u{0000}', false),
+    ('\u{f9bc}', '\u{5bee}', '\u{0000}', false),
+    ('\u{f9bd}', '\u{5c3f}', '\u{0000}', false),
+    ('\u{f9be}', '\u{6599}', '\u{0000}', false),
+    ('\u{f9bf}', '\u{6a02}', '\u{0000}', false),
+    ('\u{f9c0}', '\u{71ce}', '\u{0000}', false),
+    ('\u{f9c1}', '\u{7642}', '\u{0000}', false),
+    ('\u{f9c2}', '\u{84fc}', '\u{0000}', false),
+    ('\u{f9c3}', '\u{907c}', '\u{0000}', false),
+    ('\u{f9c4}', '\u{9f8d}', '\u{0000}', false),
+    ('\u{f9c5}', '\u{6688}', '\u{0000}', false),
+    ('\u{f9c6}', '\u{962e}', '\u{0000}', false),
+    ('\u{f9c7}', '\u{5289}', '\u{0000}', false),
+    ('\u{f9c8}', '\u{677b}', '\u{0000}', false),
+    ('\u{f9c9}', '\u{67f3}', '\u{0000}', false),
+    ('\u{f9ca}', '\u{6d41}', '\u{0000}', false),
+    ('\u{f9cb}', '\u{6e9c}', '\u{0000}', false),
+    ('\u{f9cc}', '\u{7409}', '\u{0000}', false),
+    ('\u{f9cd}', '\u{7559}', '\u{0000}', false),
+    ('\u{f9ce}', '\u{786b}', '\u{0000}', false),
+    ('\u{f9cf}', '\u{7d10}', '\u{0000}', false),
+    ('\u{f9d0}', '\u{985e}', '\u{0000}', false),
+    ('\u{f9d1}', '\u{516d}', '\u{0000}', false),
+    ('\u{f9d2}', '\u{622e}', '\u{0000}', false),
+    ('\u{f9d3}', '\u{9678}', '\u{0000}', false),
+    ('\u{f9d4}', '\u{502b}', '\u{0000}', false),
+    ('\u{f9d5}', '\u{5d19}', '\u{0000}', false),
+    ('\u{f9d6}', '\u{6dea}', '\u{0000}', false),
+    ('\u{f9d7}', '\u{8f2a}', '\u{0000}', false),
+    ('\u{f9d8}', '\u{5f8b}', '\u{0000}', false),
+    ('\u{f9d9}', '\u{6144}', '\u{0000}', false),
+    ('\u{f9da}', '\u{6817}', '\u{0000}', false),
+    ('\u{f9db}', '\u{7387}', '\u{0000}', false),
+    ('\u{f9dc}', '\u{9686}', '\u{0000}', false),
+    ('\u{f9dd}', '\u{5229}', '\u{0000}', false),
+    ('\u{f9de}', '\u{540f}', '\u{0000}', false),
+    ('\u{f9df}', '\u{5c65}', '\u{0000}', false),
+    ('\u{f9e0}', '\u{6613}', '\u{0000}', false),
+    ('\u{f9e1}', '\u{674e}', '\u{0000}', false),
+    ('\u{f9e2}', '\u{68a8}', '\u{0000}', false),
+    ('\u{f9e3}', '\u{6ce5}', '\u{0000}', false),
+    ('\u{f9e4}', '\u{7406}', '\u{0000}', false),
+    ('\u{f9e5}', '\u{75e2}', '\u{0000}', false),
+    ('\u{f9e6}', '\u{7f79}', '\u{0000}', false),
+    ('\u{f9e7}', '\u{88cf}', '\u{0000}', false),
+    ('\u{f9e8}', '\u{88e1}', '\u{0000}', false),
+    ('\u{f9e9}', '\u{91cc}', '\u{0000}', false),
+    ('\u{f9ea}', '\u{96e2}', '\u{0000}', false),
+    ('\u{f9eb}', '\u{533f}', '\u{0000}', false),
+    ('\u{f9ec}', '\u{6eba}', '\u{0000}', false),
+    ('\u{f9ed}', '\u{541d}', '\u{0000}', false),
+    ('\u{f9ee}', '\u{71d0}', '\u{0000}', false),
+    ('\u{f9ef}', '\u{7498}', '\u{0000}', false),
+    ('\u{f9f0}', '\u{85fa}', '\u{0000}', false),
+    ('\u{f9f1}', '\u{96a3}', '\u{0000}', false),
+    ('\u{f9f2}', '\u{9c57}', '\u{0000}', false),
+    ('\u{f9f3}', '\u{9e9f}', '\u{0000}', false),
+    ('\u{f9f4}', '\u{6797}', '\u{0000}', false),
+    ('\u{f9f5}', '\u{6dcb}', '\u{0000}', false),
+    ('\u{f9f6}', '\u{81e8}', '\u{0000}', false),
+    ('\u{f9f7}', '\u{7acb}', '\u{0000}', false),
+    ('\u{f9f8}', '\u{7b20}', '\u{0000}', false),
+    ('\u{f9f9}', '\u{7c92}', '\u{0000}', false),
+    ('\u{f9fa}', '\u{72c0}', '\u{0000}', false),
+    ('\u{f9fb}', '\u{7099}', '\u{0000}', false),
+    ('\u{f9fc}', '\u{8b58}', '\u{0000}', false),
+    ('\u{f9fd}', '\u{4ec0}', '\u{0000}', false),
+    ('\u{f9fe}', '\u{8336}', '\u{0000}', false),
+    ('\u{f9ff}', '\u{523a}', '\u{0000}', false),
+    ('\u{fa00}', '\u{5207}', '\u{0000}', false),
+    ('\u{fa01}', '\u{5ea6}', '\u{0000}', false),
+    ('\u{fa02}', '\u{62d3}', '\u{0000}', false),
+    ('\u{fa03}', '\u{7cd6}', '\u{0000}', false),
+    ('\u{fa04}', '\u{5b85}', '\u{0000}', false),
+    ('\u{fa05}', '\u{6d1e}', '\u{0000}', false),
+    ('\u{fa06}', '\u{66b4}', '\u{0000}', false),
+    ('\u{fa07}', '\u{8f3b}', '\u{0000}', false),
+    ('\u{fa08}', '\u{884c}', '\u{0000}', false),
+    ('\u{fa09}', '\u{964d}', '\u{0000}', false),
+    ('\u{fa0a}', '\u{898b}', '\u{0000}', false),
+    ('\u{fa0b}', '\u{5ed3}', '\u{0000}', false),
+    ('\u{fa0c}', '\u{5140}', '\u{0000}', false),
+    ('\u{fa0d}', '\u{55c0}', '\u{0000}', false),
+    ('\u{fa10}', '\u{585a}', '\u{0000}', false),
+    ('\u{fa12}', '\u{6674}', '\u{0000}', false),
+    ('\u{fa15}', '\u{51de}', '\u{0000}', false),
+    ('\u{fa16}', '\u{732a}', '\u{0000}', false),
+    ('\u{fa17}', '\u{76ca}', '\u{0000}', false),
+    ('\u{fa18}', '\u{793c}', '\u{0000}', false),
+    ('\u{fa19}', '\u{795e}', '\u{0000}', false),
+    ('\u{fa1a}', '\u{7965}', '\u{0000}', false),
+    ('\u{fa1b}', '\u{798f}', '\u{0000}', false),
+    ('\u{fa1c}', '\u{9756}', '\u{0000}', false),
+    ('\u{fa1d}', '\u{7cbe}', '\u{0000}', false),
+    ('\u{fa1e}', '\u{7fbd}', '\u{0000}', false),
+    ('\u{fa20}', '\u{8612}', '\u{0000}', false),
+    ('\u{fa22}', '\u{8af8}', '\u{0000}', false),
+    ('\u{fa25}', '\u{9038}', '\u{0000}', false),
+    ('\u{fa26}', '\u{90fd}', '\u{0000}', false),
+    ('\u{fa2a}', '\u{98ef}', '\u{0000}', false),
+    ('\u{fa2b}', '\u{98fc}', '\u{0000}', false),
+    ('\u{fa2c}', '\u{9928}', '\u{0000}', false),
+    ('\u{fa2d}', '\u{9db4}', '\u{0000}', false),
+    ('\u{fa2e}', '\u{90de}', '\u{0000}', false),
+    ('\u{fa2f}', '\u{96b7}', '\u{0000}', false),
+    ('\u{fa30}', '\u{4fae}', '\u{0000}', false),
+    ('\u{fa31}', '\u{50e7}', '\u{0000}', false),
+    ('\u{fa32}', '\u{514d}', '\u{0000}', false),
+    ('\u{fa33}', '\u{52c9}', '\u{0000}', false),
+    ('\u{fa34}', '\u{52e4}', '\u{0000}', false),
+    ('\u{fa35}', '\u{5351}', '\u{0000}', false),
+    ('\u{fa36}', '\u{559d}', '\u{0000}', false),
+    ('\u{fa37}', '\u{5606}', '\u{0000}', false),
+    ('\u{fa38}', '\u{5668}', '\u{0000}', false),
+    ('\u{fa39}', '\u{5840}', '\u{0000}', false),
+    ('\u{fa3a}', '\u{58a8}', '\u{0000}', false),
+    ('\u{fa3b}', '\u{5c64}', '\u{0000}', false),
+    ('\u{fa3c}', '\u{5c6e}', '\u{0000}', false),
+    ('\u{fa3d}', '\u{6094}', '\u{0000}', false),
+    ('\u{fa3e}', '\u{6168}', '\u{0000}', false),
+    ('\u{fa3f}', '\u{618e}', '\u{0000}', false),
+    ('\u{fa40}', '\u{61f2}', '\u{0000}', false),
+    ('\u{fa41}', '\u{654f}', '\u{0000}', false),
+    ('\u{fa42}', '\u{65e2}', '\u{0000}', false),
+    ('\u{fa43}', '\u{6691}', '\u{0000}', false),
+    ('\u{fa44}', '\u{6885}', '\u{0000}', false),
+    ('\u{fa45}', '\u{6d77}', '\u{0000}', false),
+    ('\u{fa46}', '\u{6e1a}', '\u{0000}', false),
+    ('\u{fa47}', '\u{6f22}', '\u{0000}', false),
+    ('\u{fa48}', '\u{716e}', '\u{0000}', false),
+    ('\u{fa49}', '\u{722b}', '\u{0000}', false),
+    ('\u{fa4a}', '\u{7422}', '\u{0000}', false),
+    ('\u{fa4b}', '\u{7891}', '\u{0000}', false),
+    ('\u{fa4c}', '\u{793e}', '\u{0000}', false),
+    ('\u{fa4d}', '\u{7949}', '\u{0000}', false),
+    ('\u{fa4e}', '\u{7948}', '\u{0000}', false),
+    ('\u{fa4f}', '\u{7950}', '\u{0000}', false),
+    ('\u{fa50}', '\u{7956}', '\u{0000}', false),
+    ('\u{fa51}', '\u{795d}', '\u{0000}', false),
+    ('\u{fa52}', '\u{798d}', '\u{0000}', false),
+    ('\u{fa53}', '\u{798e}', '\u{0000}', false),
+    ('\u{fa54}', '\u{7a40}', '\u{0000}', false),
+    ('\u{fa55}', '\u{7a81}', '\u{0000}', false),
+    ('\u{fa56}', '\u{7bc0}', '\u{0000}', false),
+    ('\u{fa57}', '\u{7df4}', '\u{0000}', false),
+    ('\u{fa58}', '\u{7e09}', '\u{0000}', false),
+    ('\u{fa59}', '\u{7e41}', '\u{0000}', false),
+    ('\u{fa5a}', '\u{7f72}', '\u{0000}', false),
+    ('\u{fa5b}', '\u{8005}', '\u{0000}', false),
+    ('\u{fa5c}', '\u{81ed}', '\u{0000}', false),
+    ('\u{fa5d}', '\u{8279}', '\u{0000}', false),
+    ('\u{fa5e}', '\u{8279}', '\u{0000}', false),
+    ('\u{fa5f}', '\u{8457}', '\u{0000}', false),
+    ('\u{fa60}', '\u{8910}', '\u{0000}', false),
+    ('\u{fa61}', '\u{8996}', '\u{0000}', false),
+    ('\u{fa62}', '\u{8b01}', '\u{0000}', false),
+    ('\u{fa63}', '\u{8b39}', '\u{0000}', false),
+    ('\u{fa64}', '\u{8cd3}', '\u{0000}', false),
+    ('\u{fa65}', '\u{8d08}', '\u{0000}', false),
+    ('\u{fa66}', '\u{8fb6}', '\u{0000}', false),
+    ('\u{fa67}', '\u{9038}', '\u{0000}', false),
+    ('\u{fa68}', '\u{96e3}', '\u{0000}', false),
+    ('\u{fa69}', '\u{97ff}', '\u{0000}', false),
+    ('\u{fa6a}', '\u{983b}', '\u{0000}', false),
+    ('\u{fa6b}', '\u{6075}', '\u{0000}', false),
+    ('\u{fa6c}', '\u{242ee}', '\u{0000}', false),
+    ('\u{fa6d}', '\u{8218}', '\u{0000}', false),
+    ('\u{fa70}', '\u{4e26}', '\u{0000}', false),
+    ('\u{fa71}', '\u{51b5}', '\u{0000}', false),
+    ('\u{fa72}', '\u{5168}', '\u{0000}', false),
+    ('\u{fa73}', '\u{4f80}', '\u{0000}', false),
+    ('\u{fa74}', '\u{5145}', '\u{0000}', false),
+    ('\u{fa75}', '\u{5180}', '\u{0000}', false),
+    ('\u{fa76}', '\u{52c7}', '\u{0000}', false),
+    ('\u{fa77}', '\u{52fa}', '\u{0000}', false),
+    ('\u{fa78}', '\u{559d}', '\u{0000}', false),
+    ('\u{fa79}', '\u{5555}', '\u{0000}', false),
+    ('\u{fa7a}', '\u{5599}', '\u{0000}', false),
+    ('\u{fa7b}', '\u{55e2}', '\u{0000}', false),
+    ('\u{fa7c}', '\u{585a}', '\u{0000}', false),
+    ('\u{fa7d}', '\u{58b3}', '\u{0000}', false),
+    ('\u{fa7e}', '\u{5944}', '\u{0000}', false),
+    ('\u{fa7f}', '\u{5954}', '\u{0000}', false),
+    ('\u{fa80}', '\u{5a62}', '\u{0000}', false),
+    ('\u{fa81}', '\u{5b28}', '\u{0000}', false),
+    ('\u{fa82}', '\u{5ed2}', '\u{0000}', false),
+    ('\u{fa83}', '\u{5ed9}', '\u{0000}', false),
+    ('\u{fa84}', '\u{5f69}', '\u{0000}', false),
+    ('\u{fa85}', '\u{5fad}', '\u{0000}', false),
+    ('\u{fa86}', '\u{60d8}', '\u{0000}', false),
+    ('\u{fa87}', '\u{614e}', '\u{0000}', false),
+    ('\u{fa88}', '\u{6108}', '\u{0000}', false),
+    ('\u{fa89}', '\u{618e}', '\u{0000}', false),
+    ('\u{fa8a}', '\u{6160}', '\u{0000}', false),
+    ('\u{fa8b}', '\u{61f2}', '\u{0000}', false),
+    ('\u{fa8c}', '\u{6234}', '\u{0000}', false),
+    ('\u{fa8d}', '\u{63c4}', '\u{0000}', false),
+    ('\u{fa8e}', '\u{641c}', '\u{0000}', false),
+    ('\u{fa8f}', '\u{6452}', '\u{0000}', false),
+    ('\u{fa90}', '\u{6556}', '\u{0000}', false),
+    ('\u{fa91}', '\u{6674}', '\u{0000}', false),
+    ('\u{fa92}', '\u{6717}', '\u{0000}', false),
+    ('\u{fa93}', '\u{671b}', '\u{0000}', false),
+    ('\u{fa94}', '\u{6756}', '\u{0000}', false),
+    ('\u{fa95}', '\u{6b79}', '\u{0000}', false),
+    ('\u{fa96}', '\u{6bba}', '\u{0000}', false),
+    ('\u{fa97}', '\u{6d41}', '\u{0000}', false),
+    ('\u{fa98}', '\u{6edb}', '\u{0000}', false),
+    ('\u{fa99}', '\u{6ecb}', '\u{0000}', false),
+    ('\u{fa9a}', '\u{6f22}', '\u{0000}', false),
+    ('\u{fa9b}', '\u{701e}', '\u{0000}', false),
+    ('\u{fa9c}', '\u{716e}', '\u{0000}', false),
+    ('\u{fa9d}', '\u{77a7}', '\u{0000}', false),
+    ('\u{fa9e}', '\u{7235}', '\u{0000}', false),
+    ('\u{fa9f}', '\u{72af}', '\u{0000}', false),
+    ('\u{faa0}', '\u{732a}', '\u{0000}', false),
+    ('\u{faa1}', '\u{7471}', '\u{0000}', false),
+    ('\u{faa2}', '\u{7506}', '\u{0000}', false),
+    ('\u{faa3}', '\u{753b}', '\u{0000}', false),
+    ('\u{faa4}', '\u{761d}', '\u{0000}', false),
+    ('\u{faa5}', '\u{761f}', '\u{0000}', false),
+    ('\u{faa6}', '\u{76ca}', '\u{0000}', false),
+    ('\u{faa7}', '\u{76db}', '\u{0000}', false),
+    ('\u{faa8}', '\u{76f4}', '\u{0000}', false),
+    ('\u{faa9}', '\u{774a}', '\u{0000}', false),
+    ('\u{faaa}', '\u{7740}', '\u{0000}', false),
+    ('\u{faab}', '\u{78cc}', '\u{0000}', false),
+    ('\u{faac}', '\u{7ab1}', '\u{0000}', false),
+    ('\u{faad}', '\u{7bc0}', '\u{0000}', false),
+    ('\u{faae}', '\u{7c7b}', '\u{0000}', false),
+    ('\u{faaf}', '\u{7d5b}', '\u{0000}', false),
+    ('\u{fab0}', '\u{7df4}', '\u{0000}', false),
+    ('\u{fab1}', '\u{7f3e}', '\u{0000}', false),
+    ('\u{fab2}', '\u{8005}', '\u{0000}', false),
+    ('\u{fab3}', '\u{8352}', '\u{0000}', false),
+    ('\u{fab4}', '\u{83ef}', '\u{0000}', false),
+    ('\u{fab5}', '\u{8779}', '\u{0000}', false),
+    ('\u{fab6}', '\u{8941}', '\u{0000}', false),
+    ('\u{fab7}', '\u{8986}', '\u{0000}', false),
+    ('\u{fab8}', '\u{8996}', '\u{0000}', false),
+    ('\u{fab9}', '\u{8abf}', '\u{0000}', false),
+    ('\u{faba}', '\u{8af8}', '\u{0000}', false),
+    ('\u{fabb}', '\u{8acb}', '\u{0000}', false),
+    ('\u{fabc}', '\u{8b01}', '\u{0000}', false),
+    ('\u{fabd}', '\u{8afe}', '\u{0000}', false),
+    ('\u{fabe}', '\u{8aed}', '\u{0000}', false),
+    ('\u{fabf}', '\u{8b39}', '\u{0000}', false),
+    ('\u{fac0}', '\u{8b8a}', '\u{0000}', false),
+    ('\u{fac1}', '\u{8d08}', '\u{0000}', false),
+    ('\u{fac2}', '\u{8f38}', '\u{0000}', false),
+    ('\u{fac3}', '\u{9072}', '\u{0000}', false),
+    ('\u{fac4}', '\u{9199}', '\u{0000}', false),
+    ('\u{fac5}', '\u{9276}', '\u{0000}', false),
+    ('\u{fac6}', '\u{967c}', '\u{0000}', false),
+    ('\u{fac7}', '\u{96e3}', '\u{0000}', false),
+    ('\u{fac8}', '\u{9756}', '\u{0000}', false),
+    ('\u{fac9}', '\u{97db}', '\u{0000}', false),
+    ('\u{faca}', '\u{97ff}', '\u{0000}', false),
+    ('\u{facb}', '\u{980b}', '\u{0000}', false),
+    ('\u{facc}', '\u{983b}', '\u{0000}', false),
+    ('\u{facd}', '\u{9b12}', '\u{0000}', false),
+    ('\u{face}', '\u{9f9c}', '\u{0000}', false),
+    ('\u{facf}', '\u{2284a}', '\u{0000}', false),
+    ('\u{fad0}', '\u{22844}', '\u{0000}', false),
+    ('\u{fad1}', '\u{233d5}', '\u{0000}', false),
+    ('\u{fad2}', '\u{3b9d}', '\u{0000}', false),
+    ('\u{fad3}', '\u{4018}', '\u{0000}', false),
+    ('\u{fad4}', '\u{4039}', '\u{0000}', false),
+    ('\u{fad5}', '\u{25249}', '\u{0000}', false),
+    ('\u{fad6}', '\u{25cd0}', '\u{0000}', false),
+    ('\u{fad7}', '\u{27ed3}', '\u{0000}', false),
+    ('\u{fad8}', '\u{9f43}', '\u{0000}', false),
+    ('\u{fad9}', '\u{9f8e}', '\u{0000}', false),
+    ('\u{fb1d}', '\u{05d9}', '\u{05b4}', false),
+    ('\u{fb1f}', '\u{05f2}', '\u{05b7}', false),
+    ('\u{fb2a}', '\u{05e9}', '\u{05c1}', false),
+    ('\u{fb2b}', '\u{05e9}', '\u{05c2}', false),
+    ('\u{fb2c}', '\u{fb49}', '\u{05c1}', false),
+    ('\u{fb2d}', '\u{fb49}', '\u{05c2}', false),
+    ('\u{fb2e}', '\u{05d0}', '\u{05b7}', false),
+    ('\u{fb2f}', '\u{05d0}', '\u{05b8}', false),
+    ('\u{fb30}', '\u{05d0}', '\u{05bc}', false),
+    ('\u{fb31}', '\u{05d1}', '\u{05bc}', false),
+    ('\u{fb32}', '\u{05d2}', '\u{05bc}', false),
+    ('\u{fb33}', '\u{05d3}', '\u{05bc}', false),
+    ('\u{fb34}', '\u{05d4}', '\u{05bc}', false),
+    ('\u{fb35}', '\u{05d5}', '\u{05bc}', false),
+    ('\u{fb36}', '\u{05d6}', '\u{05bc}', false),
+    ('\u{fb38}', '\u{05d8}', '\u{05bc}', false),
+    ('\u{fb39}', '\u{05d9}', '\u{05bc}', false),
+    ('\u{fb3a}', '\u{05da}', '\u{05bc}', false),
+    ('\u{fb3b}', '\u{05db}', '\u{05bc}', false),
+    ('\u{fb3c}', '\u{05dc}', '\u{05bc}', false),
+    ('\u{fb3e}', '\u{05de}', '\u{05bc}', false),
+    ('\u{fb40}', '\u{05e0}', '\u{05bc}', false),
+    ('\u{fb41}', '\u{05e1}', '\u{05bc}', false),
+    ('\u{fb43}', '\u{05e3}', '\u{05bc}', false),
+    ('\u{fb44}', '\u{05e4}', '\u{05bc}', false),
+    ('\u{fb46}', '\u{05e6}', '\u{05bc}', false),
+    ('\u{fb47}', '\u{05e7}', '\u{05bc}', false),
+    ('\u{fb48}', '\u{05e8}', '\u{05bc}', false),
+    ('\u{fb49}', '\u{05e9}', '\u{05bc}', false),
+    ('\u{fb4a}', '\u{05ea}', '\u{05bc}', false),
+    ('\u{fb4b}', '\u{05d5}', '\u{05b9}', false),
+    ('\u{fb4c}', '\u{05d1}', '\u{05bf}', false),
+    ('\u{fb4d}', '\u{05db}', '\u{05bf}', false),
+    ('\u{fb4e}', '\u{05e4}', '\u{05bf}', false),
+];
+
+// Full case folding as (character, folded), sorted by character, e.g.
+// `ß` to `ss` and both sigmas to `σ`. Characters not listed fold to
+// themselves.
+pub(crate) const CASE_FOLDING: &[(char, &str)] = &[
+    ('\u{0041}', "\u{0061}"),
+    ('\u{0042}', "\u{0062}"),
+    ('\u{0043}', "\u{0063}"),
+    ('\u{0044}', "\u{0064}"),
+    ('\u{0045}', "\u{0065}"),
+    ('\u{0046}', "\u{0066}"),
+    ('\u{0047}', "\u{0067}"),
+    ('\u{0048}', "\u{0068}"),
+    ('\u{0049}', "\u{0069}"),
+    ('\u{004a}', "\u{006a}"),
+    ('\u{004b}', "\u{006b}"),
+    ('\u{004c}', "\u{006c}"),
+    ('\u{004d}', "\u{006d}"),
+    ('\u{004e}', "\u{006e}"),
+    ('\u{004f}', "\u{006f}"),
+    ('\u{0050}', "\u{0070}"),
+    ('\u{0051}', "\u{0071}"),
+    ('\u{0052}', "\u{0072}"),
+    ('\u{0053}', "\u{0073}"),
+    ('\u{0054}', "\u{0074}"),
+    ('\u{0055}', "\u{0075}"),
+    ('\u{0056}', "\u{0076}"),
+    ('\u{0057}', "\u{0077}"),
+    ('\u{0058}', "\u{0078}"),
+    ('\u{0059}', "\u{0079}"),
+    ('\u{005a}', "\u{007a}"),
+    ('\u{00b5}', "\u{03bc}"),
+    ('\u{00c0}', "\u{00e0}"),
+    ('\u{00c1}', "\u{00e1}"),
+    ('\u{00c2}', "\u{00e2}"),
+    ('\u{00c3}', "\u{00e3}"),
+    ('\u{00c4}', "\u{00e4}"),
+    ('\u{00c5}', "\u{00e5}"),
+    ('\u{00c6}', "\u{00e6}"),
+    ('\u{00c7}', "\u{00e7}"),
+    ('\u{00c8}', "\u{00e8}"),
+    ('\u{00c9}', "\u{00e9}"),
+    ('\u{00ca}', "\u{00ea}"),
+    ('\u{00cb}', "\u{00eb}"),
+    ('\u{00cc}', "\u{00ec}"),
+    ('\u{00cd}', "\u{00ed}"),
+    ('\u{00ce}', "\u{00ee}"),
+    ('\u{00cf}', "\u{00ef}"),
+    ('\u{00d0}', "\u{00f0}"),
+    ('\u{00d1}', "\u{00f1}"),
+    ('\u{00d2}', "\u{00f2}"),
+    ('\u{00d3}', "\u{00f3}"),
+    ('\u{00d4}', "\u{00f4}"),
+    ('\u{00d5}', "\u{00f5}"),
+    ('\u{00d6}', "\u{00f6}"),
+    ('\u{00d8}', "\u{00f8}"),
+    ('\u{00d9}', "\u{00f9}"),
+    ('\u{00da}', "\u{00fa}"),
+    ('\u{00db}', "\u{00fb}"),
+    ('\u{00dc}', "\u{00fc}"),
+    ('\u{00dd}', "\u{00fd}"),
+    ('\u{00de}', "\u{00fe}"),
+    ('\u{00df}', "\u{0073}\u{0073}"),
+    ('\u{0100}', "\u{0101}"),
+    ('\u{0102}', "\u{0103}"),
+    ('\u{0104}', "\u{0105}"),
+    ('\u{0106}', "\u{0107}"),
+    ('\u{0108}', "\u{0109}"),
+    ('\u{010a}', "\u{010b}"),
+    ('\u{010c}', "\u{010d}"),
+    ('\u{010e}', "\u{010f}"),
+    ('\u{0110}', "\u{0111}"),
+    ('\u{0112}', "\u{0113}"),
+    ('\u{0114}', "\u{0115}"),
+    ('\u{0116}', "\u{0117}"),
+    ('\u{0118}', "\u{0119}"),
+    ('\u{011a}', "\u{011b}"),
+    ('\u{011c}', "\u{011d}"),
+    ('\u{011e}', "\u{011f}"),
+    ('\u{0120}', "\u{0121}"),
+    ('\u{0122}', "\u{0123}"),
+    ('\u{0124}', "\u{0125}"),
+    ('\u{0126}', "\u{0127}"),
+    ('\u{0128}', "\u{0129}"),
+    ('\u{012a}', "\u{012b}"),
+    ('\u{012c}', "\u{012d}"),
+    ('\u{012e}', "\u{012f}"),
+    ('\u{0130}', "\u{0069}\u{0307}"),
+    ('\u{0132}', "\u{0133}"),
+    ('\u{0134}', "\u{0135}"),
+    ('\u{0136}', "\u{0137}"),
+    ('\u{0139}', "\u{013a}"),
+    ('\u{013b}', "\u{013c}"),
+    ('\u{013d}', "\u{013e}"),
+    ('\u{013f}', "\u{0140}"),
+    ('\u{0141}', "\u{0142}"),
+    ('\u{0143}', "\u{0144}"),
+    ('\u{0145}', "\u{0146}"),
+    ('\u{0147}', "\u{0148}"),
+    ('\u{0149}', "\u{02bc}\u{006e}"),
+    ('\u{014a}', "\u{014b}"),
+    ('\u{014c}', "\u{014d}"),
+    ('\u{014e}', "\u{014f}"),
+    ('\u{0150}', "\u{0151}"),
+    ('\u{0152}', "\u{0153}"),
+    ('\u{0154}', "\u{0155}"),
+    ('\u{0156}', "\u{0157}"),
+    ('\u{0158}', "\u{0159}"),
+    ('\u{015a}', "\u{015b}"),
+    ('\u{015c}', "\u{015d}"),
+    ('\u{015e}', "\u{015f}"),
+    ('\u{0160}', "\u{0161}"),
+    ('\u{0162}', "\u{0163}"),
+    ('\u{0164}', "\u{0165}"),
+    ('\u{0166}', "\u{0167}"),
+    ('\u{0168}', "\u{0169}"),
+    ('\u{016a}', "\u{016b}"),
+    ('\u{016c}', "\u{016d}"),
+    ('\u{016e}', "\u{016f}"),
+    ('\u{0170}', "\u{0171}"),
+    ('\u{0172}', "\u{0173}"),
+    ('\u{0174}', "\u{0175}"),
+    ('\u{0176}', "\u{0177}"),
+    ('\u{0178}', "\u{00ff}"),
+    ('\u{0179}', "\u{017a}"),
+    ('\u{017b}', "\u{017c}"),
+    ('\u{017d}', "\u{017e}"),
+    ('\u{017f}', "\u{0073}"),
+    ('\u{0181}', "\u{0253}"),
+    ('\u{0182}', "\u{0183}"),
+    ('\u{0184}', "\u{0185}"),
+    ('\u{0186}', "\u{0254}"),
+    ('\u{0187}', "\u{0188}"),
+    ('\u{0189}', "\u{0256}"),
+    ('\u{018a}', "\u{0257}"),
+    ('\u{018b}', "\u{018c}"),
+    ('\u{018e}', "\u{01dd}"),
+    ('\u{018f}', "\u{0259}"),
+    ('\u{0190}', "\u{025b}"),
+    ('\u{0191}', "\u{0192}"),
+    ('\u{0193}', "\u{0260}"),
+    ('\u{0194}', "\u{0263}"),
+    ('\u{0196}', "\u{0269}"),
+    ('\u{0197}', "\u{0268}"),
+    ('\u{0198}', "\u{0199}"),
+    ('\u{019c}', "\u{026f}"),
+    ('\u{019d}', "\u{0272}"),
+    ('\u{019f}', "\u{0275}"),
+    ('\u{01a0}', "\u{01a1}"),
+    ('\u{01a2}', "\u{01a3}"),
+    ('\u{01a4}', "\u{01a5}"),
+    ('\u{01a6}', "\u{0280}"),
+    ('\u{01a7}', "\u{01a8}"),
+    ('\u{01a9}', "\u{0283}"),
+    ('\u{01ac}', "\u{01ad}"),
+    ('\u{01ae}', "\u{0288}"),
+    ('\u{01af}', "\u{01b0}"),
+    ('\u{01b1}', "\u{028a}"),
+    ('\u{01b2}', "\u{028b}"),
+    ('\u{01b3}', "\u{01b4}"),
+    ('\u{01b5}', "\u{01b6}"),
+    ('\u{01b7}', "\u{0292}"),
+    ('\u{01b8}', "\u{01b9}"),
+    ('\u{01bc}', "\u{01bd}"),
+    ('\u{01c4}', "\u{01c6}"),
+    ('\u{01c5}', "\u{01c6}"),
+    ('\u{01c7}', "\u{01c9}"),
+    ('\u{01c8}', "\u{01c9}"),
+    ('\u{01ca}', "\u{01cc}"),
+    ('\u{01cb}', "\u{01cc}"),
+    ('\u{01cd}', "\u{01ce}"),
+    ('\u{01cf}', "\u{01d0}"),
+    ('\u{01d1}', "\u{01d2}"),
+    ('\u{01d3}', "\u{01d4}"),
+    ('\u{01d5}', "\u{01d6}"),
+    ('\u{01d7}', "\u{01d8}"),
+    ('\u{01d9}', "\u{01da}"),
+    ('\u{01db}', "\u{01dc}"),
+    ('\u{01de}', "\u{01df}"),
+    ('\u{01e0}', "\u{01e1}"),
+    ('\u{01e2}', "\u{01e3}"),
+    ('\u{01e4}', "\u{01e5}"),
+    ('\u{01e6}', "\u{01e7}"),
+    ('\u{01e8}', "\u{01e9}"),
+    ('\u{01ea}', "\u{01eb}"),
+    ('\u{01ec}', "\u{01ed}"),
+    ('\u{01ee}', "\u{01ef}"),
+    ('\u{01f0}', "\u{006a}\u{030c}"),
+    ('\u{01f1}', "\u{01f3}"),
+    ('\u{01f2}', "\u{01f3}"),
+    ('\u{01f4}', "\u{01f5}"),
+    ('\u{01f6}', "\u{0195}"),
+    ('\u{01f7}', "\u{01bf}"),
+    ('\u{01f8}', "\u{01f9}"),
+    ('\u{01fa}', "\u{01fb}"),
+    ('\u{01fc}', "\u{01fd}"),
+    ('\u{01fe}', "\u{01ff}"),
+    ('\u{0200}', "\u{0201}"),
+    ('\u{0202}', "\u{0203}"),
+    ('\u{0204}', "\u{0205}"),
+    ('\u{0206}', "\u{0207}"),
+    ('\u{0208}', "\u{0209}"),
+    ('\u{020a}', "\u{020b}"),
+    ('\u{020c}', "\u{020d}"),
+    ('\u{020e}', "\u{020f}"),
+    ('\u{0210}', "\u{0211}"),
+    ('\u{0212}', "\u{0213}"),
+    ('\u{0214}', "\u{0215}"),
+    ('\u{0216}', "\u{0217}"),
+    ('\u{0218}', "\u{0219}"),
+    ('\u{021a}', "\u{021b}"),
+    ('\u{021c}', "\u{021d}"),
+    ('\u{021e}', "\u{021f}"),
+    ('\u{0220}', "\u{019e}"),
+    ('\u{0222}', "\u{0223}"),
+    ('\u{0224}', "\u{0225}"),
+    ('\u{0226}', "\u{0227}"),
+    ('\u{0228}', "\u{0229}"),
+    ('\u{022a}', "\u{022b}"),
+    ('\u{022c}', "\u{022d}"),
+    ('\u{022e}', "\u{022f}"),
+    ('\u{0230}', "\u{0231}"),
+    ('\u{0232}', "\u{0233}"),
+    ('\u{023a}', "\u{2c65}"),
+    ('\u{023b}', "\u{023c}"),
+    ('\u{023d}', "\u{019a}"),
+    ('\u{023e}', "\u{2c66}"),
+    ('\u{0241}', "\u{0242}"),
+    ('\u{0243}', "\u{0180}"),
+    ('\u{0244}', "\u{0289}"),
+    ('\u{0245}', "\u{028c}"),
+    ('\u{0246}', "\u{0247}"),
+    ('\u{0248}', "\u{0249}"),
+    ('\u{024a}', "\u{024b}"),
+    ('\u{024c}', "\u{024d}"),
+    ('\u{024e}', "\u{024f}"),
+    ('\u{0345}', "\u{03b9}"),
+    ('\u{0370}', "\u{0371}"),
+    ('\u{0372}', "\u{0373}"),
+    ('\u{0376}', "\u{0377}"),
+    ('\u{037f}', "\u{03f3}"),
+    ('\u{0386}', "\u{03ac}"),
+    ('\u{0388}', "\u{03ad}"),
+    ('\u{0389}', "\u{03ae}"),
+    ('\u{038a}', "\u{03af}"),
+    ('\u{038c}', "\u{03cc}"),
+    ('\u{038e}', "\u{03cd}"),
+    ('\u{038f}', "\u{03ce}"),
+    ('\u{0390}', "\u{03b9}\u{0308}\u{0301}"),
+    ('\u{0391}', "\u{03b1}"),
+    ('\u{0392}', "\u{03b2}"),
+    ('\u{0393}', "\u{03b3}"),
+    ('\u{0394}', "\u{03b4}"),
+    ('\u{0395}', "\u{03b5}"),
+    ('\u{0396}', "\u{03b6}"),
+    ('\u{0397}', "\u{03b7}"),
+    ('\u{0398}', "\u{03b8}"),
+    ('\u{0399}', "\u{03b9}"),
+    ('\u{039a}', "\u{03ba}"),
+    ('\u{039b}', "\u{03bb}"),
+    ('\u{039c}', "\u{03bc}"),
+    ('\u{039d}', "\u{03bd}"),
+    ('\u{039e}', "\u{03be}"),
+    ('\u{039f}', "\u{03bf}"),
+    ('\u{03a0}', "\u{03c0}"),
+    ('\u{03a1}', "\u{03c1}"),
+    ('\u{03a3}', "\u{03c3}"),
+    ('\u{03a4}', "\u{03c4}"),
+    ('\u{03a5}', "\u{03c5}"),
+    ('\u{03a6}', "\u{03c6}"),
+    ('\u{03a7}', "\u{03c7}"),
+    ('\u{03a8}', "\u{03c8}"),
+    ('\u{03a9}', "\u{03c9}"),
+    ('\u{03aa}', "\u{03ca}"),
+    ('\u{03ab}', "\u{03cb}"),
+    ('\u{03b0}', "\u{03c5}\u{0308}\u{0301}"),
+    ('\u{03c2}', "\u{03c3}"),
+    ('\u{03cf}', "\u{03d7}"),
+    ('\u{03d0}', "\u{03b2}"),
+    ('\u{03d1}', "\u{03b8}"),
+    ('\u{03d5}', "\u{03c6}"),
+    ('\u{03d6}', "\u{03c0}"),
+    ('\u{03d8}', "\u{03d9}"),
+    ('\u{03da}', "\u{03db}"),
+    ('\u{03dc}', "\u{03dd}"),
+    ('\u{03de}', "\u{03df}"),
+    ('\u{03e0}', "\u{03e1}"),
+    ('\u{03e2}', "\u{03e3}"),
+    ('\u{03e4}', "\u{03e5}"),
+    ('\u{03e6}', "\u{03e7}"),
+    ('\u{03e8}', "\u{03e9}"),
+    ('\u{03ea}', "\u{03eb}"),
+    ('\u{03ec}', "\u{03ed}"),
+    ('\u{03ee}', "\u{03ef}"),
+    ('\u{03f0}', "\u{03ba}"),
+    ('\u{03f1}', "\u{03c1}"),
+    ('\u{03f4}', "\u{03b8}"),
+    ('\u{03f5}', "\u{03b5}"),
+    ('\u{03f7}', "\u{03f8}"),
+    ('\u{03f9}', "\u{03f2}"),
+    ('\u{03fa}', "\u{03fb}"),
+    ('\u{03fd}', "\u{037b}"),
+    ('\u{03fe}', "\u{037c}"),
+    ('\u{03ff}', "\u{037d}"),
+    ('\u{0400}', "\u{0450}"),
+    ('\u{0401}', "\u{0451}"),
+    ('\u{0402}', "\u{0452}"),
+    ('\u{0403}', "\u{0453}"),
+    ('\u{0404}', "\u{0454}"),
+    ('\u{0405}', "\u{0455}"),
+    ('\u{0406}', "\u{0456}"),
+    ('\u{0407}', "\u{0457}"),
+    ('\u{0408}', "\u{0458}"),
+    ('\u{0409}', "\u{0459}"),
+    ('\u{040a}', "\u{045a}"),
+    ('\u{040b}', "\u{045b}"),
+    ('\u{040c}', "\u{045c}"),
+    ('\u{040d}', "\u{045d}"),
+    ('\u{040e}', "\u{045e}"),
+    ('\u{040f}', "\u{045f}"),
+    ('\u{0410}', "\u{0430}"),
+    ('\u{0411}', "\u{0431}"),
+    ('\u{0412}', "\u{0432}"),
+    ('\u{0413}', "\u{0433}"),
+    ('\u{0414}', "\u{0434}"),
+    ('\u{0415}', "\u{0435}"),
+    ('\u{0416}', "\u{0436}"),
+    ('\u{0417}', "\u{0437}"),
+    ('\u{0418}', "\u{0438}"),
+    ('\u{0419}', "\u{0439}"),
+    ('\u{041a}', "\u{043a}"),
+    ('\u{041b}', "\u{043b}"),
+    ('\u{041c}', "\u{043c}"),
+    ('\u{041d}', "\u{043d}"),
+    ('\u{041e}', "\u{043e}"),
+    ('\u{041f}', "\u{043f}"),
+    ('\u{0420}', "\u{0440}"),
+    ('\u{0421}', "\u{0441}"),
+    ('\u{0422}', "\u{0442}"),
+    ('\u{0423}', "\u{0443}"),
+    ('\u{0424}', "\u{0444}"),
+    ('\u{0425}', "\u{0445}"),
+    ('\u{0426}', "\u{0446}"),
+    ('\u{0427}', "\u{0447}"),
+    ('\u{0428}', "\u{0448}"),
+    ('\u{0429}', "\u{0449}"),
+    ('\u{042a}', "\u{044a}"),
+    ('\u{042b}', "\u{044b}"),
+    ('\u{042c}', "\u{044c}"),
+    ('\u{042d}', "\u{044d}"),
+    ('\u{042e}', "\u{044e}"),
+    ('\u{042f}', "\u{044f}"),
+    ('\u{0460}', "\u{0461}"),
+    ('\u{0462}', "\u{0463}"),
+    ('\u{0464}', "\u{0465}"),
+    ('\u{0466}', "\u{0467}"),
+    ('\u{0468}', "\u{0469}"),
+    ('\u{046a}', "\u{046b}"),
+    ('\u{046c}', "\u{046d}"),
+    ('\u{046e}', "\u{046f}"),
+    ('\u{0470}', "\u{0471}"),
+    ('\u{0472}', "\u{0473}"),
+    ('\u{0474}', "\u{0475}"),
+    ('\u{0476}', "\u{0477}"),
+    ('\u{0478}', "\u{0479}"),
+    ('\u{047a}', "\u{047b}"),
+    ('\u{047c}', "\u{047d}"),
+    ('\u{047e}', "\u{047f}"),
+    ('\u{0480}', "\u{0481}"),
+    ('\u{048a}', "\u{048b}"),
+    ('\u{048c}', "\u{048d}"),
+    ('\u{048e}', "\u{048f}"),
+    ('\u{0490}', "\u{0491}"),
+    ('\u{0492}', "\u{0493}"),
+    ('\u{0494}', "\u{0495}"),
+    ('\u{0496}', "\u{0497}"),
+    ('\u{0498}', "\u{0499}"),
+    ('\u{049a}', "\u{049b}"),
+    ('\u{049c}', "\u{049d}"),
+    ('\u{049e}', "\u{049f}"),
+    ('\u{04a0}', "\u{04a1}"),
+    ('\u{04a2}', "\u{04a3}"),
+    ('\u{04a4}', "\u{04a5}"),
+    ('\u{04a6}', "\u{04a7}"),
+    ('\u{04a8}', "\u{04a9}"),
+    ('\u{04aa}', "\u{04ab}"),
+    ('\u{04ac}', "\u{04ad}"),
+    ('\u{04ae}', "\u{04af}"),
+    ('\u{04b0}', "\u{04b1}"),
+    ('\u{04b2}', "\u{04b3}"),
+    ('\u{04b4}', "\u{04b5}"),
+    ('\u{04b6}', "\u{04b7}"),
+    ('\u{04b8}', "\u{04b9}"),
+    ('\u{04ba}', "\u{04bb}"),
+    ('\u{04bc}', "\u{04bd}"),
+    ('\u{04be}', "\u{04bf}"),
+    ('\u{04c0}', "\u{04cf}"),
+    ('\u{04c1}', "\u{04c2}"),
+    ('\u{04c3}', "\u{04c4}"),
+    ('\u{04c5}', "\u{04c6}"),
+    ('\u{04c7}', "\u{04c8}"),
+    ('\u{04c9}', "\u{04ca}"),
+    ('\u{04cb}', "\u{04cc}"),
+    ('\u{04cd}', "\u{04ce}"),
+    ('\u{04d0}', "\u{04d1}"),
+    ('\u{04d2}', "\u{04d3}"),
+    ('\u{04d4}', "\u{04d5}"),
+    ('\u{04d6}', "\u{04d7}"),
+    ('\u{04d8}', "\u{04d9}"),
+    ('\u{04da}', "\u{04db}"),
+    ('\u{04dc}', "\u{04dd}"),
+    ('\u{04de}', "\u{04df}"),
+    ('\u{04e0}', "\u{04e1}"),
+    ('\u{04e2}', "\u{04e3}"),
+    ('\u{04e4}', "\u{04e5}"),
+    ('\u{04e6}', "\u{04e7}"),
+    ('\u{04e8}', "\u{04e9}"),
+    ('\u{04ea}', "\u{04eb}"),
+    ('\u{04ec}', "\u{04ed}"),
+    ('\u{04ee}', "\u{04ef}"),
+    ('\u{04f0}', "\u{04f1}"),
+    ('\u{04f2}', "\u{04f3}"),
+    ('\u{04f4}', "\u{04f5}"),
+    ('\u{04f6}', "\u{04f7}"),
+    ('\u{04f8}', "\u{04f9}"),
+    ('\u{04fa}', "\u{04fb}"),
+    ('\u{04fc}', "\u{04fd}"),
+    ('\u{04fe}', "\u{04ff}"),
+    ('\u{0500}', "\u{0501}"),
+    ('\u{0502}', "\u{0503}"),
+    ('\u{0504}', "\u{0505}"),
+    ('\u{0506}', "\u{0507}"),
+    ('\u{0508}', "\u{0509}"),
+    ('\u{050a}', "\u{050b}"),
+    ('\u{050c}', "\u{050d}"),
+    ('\u{050e}', "\u{050f}"),
+    ('\u{0510}', "\u{0511}"),
+    ('\u{0512}', "\u{0513}"),
+    ('\u{0514}', "\u{0515}"),
+    ('\u{0516}', "\u{0517}"),
+    ('\u{0518}', "\u{0519}"),
+    ('\u{051a}', "\u{051b}"),
+    ('\u{051c}', "\u{051d}"),
+    ('\u{051e}', "\u{051f}"),
+    ('\u{0520}', "\u{0521}"),
+    ('\u{0522}', "\u{0523}"),
+    ('\u{0524}', "\u{0525}"),
+    ('\u{0526}', "\u{0527}"),
+    ('\u{0528}', "\u{0529}"),
+    ('\u{052a}', "\u{052b}"),
+    ('\u{052c}', "\u{052d}"),
+    ('\u{052e}', "\u{052f}"),
+    ('\u{0531}', "\u{0561}"),
+    ('\u{0532}', "\u{0562}"),
+    ('\u{0533}', "\u{0563}"),
+    ('\u{0534}', "\u{0564}"),
+    ('\u{0535}', "\u{0565}"),
+    ('\u{0536}', "\u{0566}"),
+    ('\u{0537}', "\u{0567}"),
+    ('\u{0538}', "\u{0568}"),
+    ('\u{0539}', "\u{0569}"),
+    ('\u{053a}', "\u{056a}"),
+    ('\u{053b}', "\u{056b}"),
+    ('\u{053c}', "\u{056c}"),
+    ('\u{053d}', "\u{056d}"),
+    ('\u{053e}', "\u{056e}"),
+    ('\u{053f}', "\u{056f}"),
+    ('\u{0540}', "\u{0570}"),
+    ('\u{0541}', "\u{0571}"),
+    ('\u{0542}', "\u{0572}"),
+    ('\u{0543}', "\u{0573}"),
+    ('\u{0544}', "\u{0574}"),
+    ('\u{0545}', "\u{0575}"),
+    ('\u{0546}', "\u{0576}"),
+    ('\u{0547}', "\u{0577}"),
+    ('\u{0548}', "\u{0578}"),
+    ('\u{0549}', "\u{0579}"),
+    ('\u{054a}', "\u{057a}"),
+    ('\u{054b}', "\u{057b}"),
+    ('\u{054c}', "\u{057c}"),
+    ('\u{054d}', "\u{057d}"),
+    ('\u{054e}', "\u{057e}"),
+    ('\u{054f}', "\u{057f}"),
+    ('\u{0550}', "\u{0580}"),
+    ('\u{0551}', "\u{0581}"),
+    ('\u{0552}', "\u{0582}"),
+    ('\u{0553}', "\u{0583}"),
+    ('\u{0554}', "\u{0584}"),
+    ('\u{0555}', "\u{0585}"),
+    ('\u{0556}', "\u{0586}"),
+    ('\u{0587}', "\u{0565}\u{0582}"),
+    ('\u{10a0}', "\u{2d00}"),
+    ('\u{10a1}', "\u{2d01}"),
+    ('\u{10a2}', "\u{2d02}"),
+    ('\u{10a3}', "\u{2d03}"),
+    ('\u{10a4}', "\u{2d04}"),
+    ('\u{10a5}', "\u{2d05}"),
+    ('\u{10a6}', "\u{2d06}"),
+    ('\u{10a7}', "\u{2d07}"),
+    ('\u{10a8}', "\u{2d08}"),
+    ('\u{10a9}', "\u{2d09}"),
+    ('\u{10aa}', "\u{2d0a}"),
+    ('\u{10ab}', "\u{2d0b}"),
+    ('\u{10ac}', "\u{2d0c}"),
+    ('\u{10ad}', "\u{2d0d}"),
+    ('\u{10ae}', "\u{2d0e}"),
+    ('\u{10af}', "\u{2d0f}"),
+    ('\u{10b0}', "\u{2d10}"),
+    ('\u{10b1}', "\u{2d11}"),
+    ('\u{10b2}', "\u{2d12}"),
+    ('\u{10b3}', "\u{2d13}"),
+    ('\u{10b4}', "\u{2d14}"),
+    ('\u{10b5}', "\u{2d15}"),
+    ('\u{10b6}', "\u{2d16}"),
+    ('\u{10b7}', "\u{2d17}"),
+    ('\u{10b8}', "\u{2d18}"),
+    ('\u{10b9}', "\u{2d19}"),
+    ('\u{10ba}', "\u{2d1a}"),
+    ('\u{10bb}', "\u{2d1b}"),
+    ('\u{10bc}', "\u{2d1c}"),
+    ('\u{10bd}', "\u{2d1d}"),
+    ('\u{10be}', "\u{2d1e}"),
+    ('\u{10bf}', "\u{2d1f}"),
+    ('\u{10c0}', "\u{2d20}"),
+    ('\u{10c1}', "\u{2d21}"),
+    ('\u{10c2}', "\u{2d22}"),
+    ('\u{10c3}', "\u{2d23}"),
+    ('\u{10c4}', "\u{2d24}"),
+    ('\u{10c5}', "\u{2d25}"),
+    ('\u{10c7}', "\u{2d27}"),
+    ('\u{10cd}', "\u{2d2d}"),
+    ('\u{13f8}', "\u{13f0}"),
+    ('\u{13f9}', "\u{13f1}"),
+    ('\u{13fa}', "\u{13f2}"),
+    ('\u{13fb}', "\u{13f3}"),
+    ('\u{13fc}', "\u{13f4}"),
+    ('\u{13fd}', "\u{13f5}"),
+    ('\u{1c80}', "\u{0432}"),
+    ('\u{1c81}', "\u{0434}"),
+    ('\u{1c82}', "\u{043e}"),
+    ('\u{1c83}', "\u{0441}"),
+    ('\u{1c84}', "\u{0442}"),
+    ('\u{1c85}', "\u{0442}"),
+    ('\u{1c86}', "\u{044a}"),
+    ('\u{1c87}', "\u{0463}"),
+    ('\u{1c88}', "\u{a64b}"),
+    ('\u{1c90}', "\u{10d0}"),
+    ('\u{1c91}', "\u{10d1}"),
+    ('\u{1c92}', "\u{10d2}"),
+    ('\u{1c93}', "\u{10d3}"),
+    ('\u{1c94}', "\u{10d4}"),
+    ('\u{1c95}', "\u{10d5}"),
+    ('\u{1c96}', "\u{10d6}"),
+    ('\u{1c97}', "\u{10d7}"),
+    ('\u{1c98}', "\u{10d8}"),
+    ('\u{1c99}', "\u{10d9}"),
+    ('\u{1c9a}', "\u{10da}"),
+    ('\u{1c9b}', "\u{10db}"),
+    ('\u{1c9c}', "\u{10dc}"),
+    ('\u{1c9d}', "\u{10dd}"),
+    ('\u{1c9e}', "\u{10de}"),
+    ('\u{1c9f}', "\u{10df}"),
+    ('\u{1ca0}', "\u{10e0}"),
+    ('\u{1ca1}', "\u{10e1}"),
+    ('\u{1ca2}', "\u{10e2}"),
+    ('\u{1ca3}', "\u{10e3}"),
+    ('\u{1ca4}', "\u{10e4}"),
+    ('\u{1ca5}', "\u{10e5}"),
+    ('\u{1ca6}', "\u{10e6}"),
+    ('\u{1ca7}', "\u{10e7}"),
+    ('\u{1ca8}', "\u{10e8}"),
+    ('\u{1ca9}', "\u{10e9}"),
+    ('\u{1caa}', "\u{10ea}"),
+    ('\u{1cab}', "\u{10eb}"),
+    ('\u{1cac}', "\u{10ec}"),
+    ('\u{1cad}', "\u{10ed}"),
+    ('\u{1cae}', "\u{10ee}"),
+    ('\u{1caf}', "\u{10ef}"),
+    ('\u{1cb0}', "\u{10f0}"),
+    ('\u{1cb1}', "\u{10f1}"),
+    ('\u{1cb2}', "\u{10f2}"),
+    ('\u{1cb3}', "\u{10f3}"),
+    ('\u{1cb4}', "\u{10f4}"),
+    ('\u{1cb5}', "\u{10f5}"),
+    ('\u{1cb6}', "\u{10f6}"),
+    ('\u{1cb7}', "\u{10f7}"),
+    ('\u{1cb8}', "\u{10f8}"),
+    ('\u{1cb9}', "\u{10f9}"),
+    ('\u{1cba}', "\u{10fa}"),
+    ('\u{1cbd}', "\u{10fd}"),
+    ('\u{1cbe}', "\u{10fe}"),
+    ('\u{1cbf}', "\u{10ff}"),
+    ('\u{1e00}', "\u{1e01}"),
+    ('\u{1e02}', "\u{1e03}"),
+    ('\u{1e04}', "\u{1e05}"),
+    ('\u{1e06}', "\u{1e07}"),
+    ('\u{1e08}', "\u{1e09}"),
+    ('\u{1e0a}', "\u{1e0b}"),
+    ('\u{1e0c}', "\u{1e0d}"),
+    ('\u{1e0e}', "\u{1e0f}"),
+    ('\u{1e10}', "\u{1e11}"),
+    ('\u{1e12}', "\u{1e13}"),
+    ('\u{1e14}', "\u{1e15}"),
+    ('\u{1e16}', "\u{1e17}"),
+    ('\u{1e18}', "\u{1e19}"),
+    ('\u{1e1a}', "\u{1e1b}"),
+    ('\u{1e1c}', "\u{1e1d}"),
+    ('\u{1e1e}', "\u{1e1f}"),
+    ('\u{1e20}', "\u{1e21}"),
+    ('\u{1e22}', "\u{1e23}"),
+    ('\u{1e24}', "\u{1e25}"),
+    ('\u{1e26}', "\u{1e27}"),
+    ('\u{1e28}', "\u{1e29}"),
+    ('\u{1e2a}', "\u{1e2b}"),
+    ('\u{1e2c}', "\u{1e2d}"),
+    ('\u{1e2e}', "\u{1e2f}"),
+    ('\u{1e30}', "\u{1e31}"),
+    ('\u{1e32}', "\u{1e33}"),
+    ('\u{1e34}', "\u{1e35}"),
+    ('\u{1e36}', "\u{1e37}"),
+    ('\u{1e38}', "\u{1e39}"),
+    ('\u{1e3a}', "\u{1e3b}"),
+    ('\u{1e3c}', "\u{1e3d}"),
+    ('\u{1e3e}', "\u{1e3f}"),
+    ('\u{1e40}', "\u{1e41}"),
+    ('\u{1e42}', "\u{1e43}"),
+    ('\u{1e44}', "\u{1e45}"),
+    ('\u{1e46}', "\u{1e47}"),
+    ('\u{1e48}', "\u{1e49}"),
+    ('\u{1e4a}', "\u{1e4b}"),
+    ('\u{1e4c}', "\u{1e4d}"),
+    ('\u{1e4e}', "\u{1e4f}"),
+    ('\u{1e50}', "\u{1e51}"),
+    ('\u{1e52}', "\u{1e53}"),
+    ('\u{1e54}', "\u{1e55}"),
+    ('\u{1e56}', "\u{1e57}"),
+    ('\u{1e58}', "\u{1e59}"),
+    ('\u{1e5a}', "\u{1e5b}"),
+    ('\u{1e5c}', "\u{1e5d}"),
+    ('\u{1e5e}', "\u{1e5f}"),
+    ('\u{1e60}', "\u{1e61}"),
+    ('\u{1e62}', "\u{1e63}"),
+    ('\u{1e64}', "\u{1e65}"),
+    ('\u{1e66}', "\u{1e67}"),
+    ('\u{1e68}', "\u{1e69}"),
+    ('\u{1e6a}', "\u{1e6b}"),
+    ('\u{1e6c}', "\u{1e6d}"),
+    ('\u{1e6e}', "\u{1e6f}"),
+    ('\u{1e70}', "\u{1e71}"),
+    ('\u{1e72}', "\u{1e73}"),
+    ('\u{1e74}', "\u{1e75}"),
+    ('\u{1e76}', "\u{1e77}"),
+    ('\u{1e78}', "\u{1e79}"),
+    ('\u{1e7a}', "\u{1e7b}"),
+    ('\u{1e7c}', "\u{1e7d}"),
+    ('\u{1e7e}', "\u{1e7f}"),
+    ('\u{1e80}', "\u{1e81}"),
+    ('\u{1e82}', "\u{1e83}"),
+    ('\u{1e84}', "\u{1e85}"),
+    ('\u{1e86}', "\u{1e87}"),
+    ('\u{1e88}', "\u{1e89}"),
+    ('\u{1e8a}', "\u{1e8b}"),
+    ('\u{1e8c}', "\u{1e8d}"),
+    ('\u{1e8e}', "\u{1e8f}"),
+    ('\u{1e90}', "\u{1e91}"),
+    ('\u{1e92}', "\u{1e93}"),
+    ('\u{1e94}', "\u{1e95}"),
+    ('\u{1e96}', "\u{0068}\u{0331}"),
+    ('\u{1e97}', "\u{0074}\u{0308}"),
+    ('\u{1e98}', "\u{0077}\u{030a}"),
+    ('\u{1e99}', "\u{0079}\u{030a}"),
+    ('\u{1e9a}', "\u{0061}\u{02be}"),
+    ('\u{1e9b}', "\u{1e61}"),
+    ('\u{1e9e}', "\u{0073}\u{0073}"),
+    ('\u{1ea0}', "\u{1ea1}"),
+    ('\u{1ea2}', "\u{1ea3}"),
+    ('\u{1ea4}', "\u{1ea5}"),
+    ('\u{1ea6}', "\u{1ea7}"),
+    ('\u{1ea8}', "\u{1ea9}"),
+    ('\u{1eaa}', "\u{1eab}"),
+    ('\u{1eac}', "\u{1ead}"),
+    ('\u{1eae}', "\u{1eaf}"),
+    ('\u{1eb0}', "\u{1eb1}"),
+    ('\u{1eb2}', "\u{1eb3}"),
+    ('\u{1eb4}', "\u{1eb5}"),
+    ('\u{1eb6}', "\u{1eb7}"),
+    ('\u{1eb8}', "\u{1eb9}"),
+    ('\u{1eba}', "\u{1ebb}"),
+    ('\u{1ebc}', "\u{1ebd}"),
+    ('\u{1ebe}', "\u{1ebf}"),
+    ('\u{1ec0}', "\u{1ec1}"),
+    ('\u{1ec2}', "\u{1ec3}"),
+    ('\u{1ec4}', "\u{1ec5}"),
+    ('\u{1ec6}', "\u{1ec7}"),
+    ('\u{1ec8}', "\u{1ec9}"),
+    ('\u{1eca}', "\u{1ecb}"),
+    ('\u{1ecc}', "\u{1ecd}"),
+    ('\u{1ece}', "\u{1ecf}"),
+    ('\u{1ed0}', "\u{1ed1}"),
+    ('\u{1ed2}', "\u{1ed3}"),
+    ('\u{1ed4}', "\u{1ed5}"),
+    ('\u{1ed6}', "\u{1ed7}"),
+    ('\u{1ed8}', "\u{1ed9}"),
+    ('\u{1eda}', "\u{1edb}"),
+    ('\u{1edc}', "\u{1edd}"),
+    ('\u{1ede}', "\u{1edf}"),
+    ('\u{1ee0}', "\u{1ee1}"),
+    ('\u{1ee2}', "\u{1ee3}"),
+    ('\u{1ee4}', "\u{1ee5}"),
+    ('\u{1ee6}', "\u{1ee7}"),
+    ('\u{1ee8}', "\u{1ee9}"),
+    ('\u{1eea}', "\u{1eeb}"),
+    ('\u{1eec}', "\u{1eed}"),
+    ('\u{1eee}', "\u{1eef}"),
+    ('\u{1ef0}', "\u{1ef1}"),
+    ('\u{1ef2}', "\u{1ef3}"),
+    ('\u{1ef4}', "\u{1ef5}"),
+    ('\u{1ef6}', "\u{1ef7}"),
+    ('\u{1ef8}', "\u{1ef9}"),
+    ('\u{1efa}', "\u{1efb}"),
+    ('\u{1efc}', "\u{1efd}"),
+    ('\u{1efe}', "\u{1eff}"),
+    ('\u{1f08}', "\u{1f00}"),
+    ('\u{1f09}', "\u{1f01}"),
+    ('\u{1f0a}', "\u{1f02}"),
+    ('\u{1f0b}', "\u{1f03}"),
+    ('\u{1f0c}', "\u{1f04}"),
+    ('\u{1f0d}', "\u{1f05}"),
+    ('\u{1f0e}', "\u{1f06}"),
+    ('\u{1f0f}', "\u{1f07}"),
+    ('\u{1f18}', "\u{1f10}"),
+    ('\u{1f19}', "\u{1f11}"),
+    ('\u{1f1a}', "\u{1f12}"),
+    ('\u{1f1b}', "\u{1f13}"),
+    ('\u{1f1c}', "\u{1f14}"),
+    ('\u{1f1d}', "\u{1f15}"),
+    ('\u{1f28}', "\u{1f20}"),
+    ('\u{1f29}', "\u{1f21}"),
+    ('\u{1f2a}', "\u{1f22}"),
+    ('\u{1f2b}', "\u{1f23}"),
+    ('\u{1f2c}', "\u{1f24}"),
+    ('\u{1f2d}', "\u{1f25}"),
+    ('\u{1f2e}', "\u{1f26}"),
+    ('\u{1f2f}', "\u{1f27}"),
+    ('\u{1f38}', "\u{1f30}"),
+    ('\u{1f39}', "\u{1f31}"),
+    ('\u{1f3a}', "\u{1f32}"),
+    ('\u{1f3b}', "\u{1f33}"),
+    ('\u{1f3c}', "\u{1f34}"),
+    ('\u{1f3d}', "\u{1f35}"),
+    ('\u{1f3e}', "\u{1f36}"),
+    ('\u{1f3f}', "\u{1f37}"),
+    ('\u{1f48}', "\u{1f40}"),
+    ('\u{1f49}', "\u{1f41}"),
+    ('\u{1f4a}', "\u{1f42}"),
+    ('\u{1f4b}', "\u{1f43}"),
+    ('\u{1f4c}', "\u{1f44}"),
+    ('\u{1f4d}', "\u{1f45}"),
+    ('\u{1f50}', "\u{03c5}\u{0313}"),
+    ('\u{1f52}', "\u{03c5}\u{0313}\u{0300}"),
+    ('\u{1f54}', "\u{03c5}\u{0313}\u{0301}"),
+    ('\u{1f56}', "\u{03c5}\u{0313}\u{0342}"),
+    ('\u{1f59}', "\u{1f51}"),
+    ('\u{1f5b}', "\u{1f53}"),
+    ('\u{1f5d}', "\u{1f55}"),
+    ('\u{1f5f}', "\u{1f57}"),
+    ('\u{1f68}', "\u{1f60}"),
+    ('\u{1f69}', "\u{1f61}"),
+    ('\u{1f6a}', "\u{1f62}"),
+    ('\u{1f6b}', "\u{1f63}"),
+    ('\u{1f6c}', "\u{1f64}"),
+    ('\u{1f6d}', "\u{1f65}"),
+    ('\u{1f6e}', "\u{1f66}"),
+    ('\u{1f6f}', "\u{1f67}"),
+    ('\u{1f80}', "\u{1f00}\u{03b9}"),
+    ('\u{1f81}', "\u{1f01}\u{03b9}"),
+    ('\u{1f82}', "\u{1f02}\u{03b9}"),
+    ('\u{1f83}', "\u{1f03}\u{03b9}"),
+    ('\u{1f84}', "\u{1f04}\u{03b9}"),
+    ('\u{1f85}', "\u{1f05}\u{03b9}"),
+    ('\u{1f86}', "\u{1f06}\u{03b9}"),
+    ('\u{1f87}', "\u{1f07}\u{03b9}"),
+    ('\u{1f88}', "\u{1f00}\u{03b9}"),
+    ('\u{1f89}', "\u{1f01}\u{03b9}"),
+    ('\u{1f8a}', "\u{1f02}\u{03b9}"),
+    ('\u{1f8b}', "\u{1f03}\u{03b9}"),
+    ('\u{1f8c}', "\u{1f04}\u{03b9}"),
+    ('\u{1f8d}', "\u{1f05}\u{03b9}"),
+    ('\u{1f8e}', "\u{1f06}\u{03b9}"),
+    ('\u{1f8f}', "\u{1f07}\u{03b9}"),
+    ('\u{1f90}', "\u{1f20}\u{03b9}"),
+    ('\u{1f91}', "\u{1f21}\u{03b9}"),
+    ('\u{1f92}', "\u{1f22}\u{03b9}"),
+    ('\u{1f93}', "\u{1f23}\u{03b9}"),
+    ('\u{1f94}', "\u{1f24}\u{03b9}"),
+    ('\u{1f95}', "\u{1f25}\u{03b9}"),
+    ('\u{1f96}', "\u{1f26}\u{03b9}"),
+    ('\u{1f97}', "\u{1f27}\u{03b9}"),
+    ('\u{1f98}', "\u{1f20}\u{03b9}"),
+    ('\u{1f99}', "\u{1f21}\u{03b9}"),
+    ('\u{1f9a}', "\u{1f22}\u{03b9}"),
+    ('\u{1f9b}', "\u{1f23}\u{03b9}"),
+    ('\u{1f9c}', "\u{1f24}\u{03b9}"),
+    ('\u{1f9d}', "\u{1f25}\u{03b9}"),
+    ('\u{1f9e}', "\u{1f26}\u{03b9}"),
+    ('\u{1f9f}', "\u{1f27}\u{03b9}"),
+    ('\u{1fa0}', "\u{1f60}\u{03b9}"),
+    ('\u{1fa1}', "\u{1f61}\u{03b9}"),
+    ('\u{1fa2}', "\u{1f62}\u{03b9}"),
+    ('\u{1fa3}', "\u{1f63}\u{03b9}"),
+    ('\u{1fa4}', "\u{1f64}\u{03b9}"),
+    ('\u{1fa5}', "\u{1f65}\u{03b9}"),
+    ('\u{1fa6}', "\u{1f66}\u{03b9}"),
+    ('\u{1fa7}', "\u{1f67}\u{03b9}"),
+    ('\u{1fa8}', "\u{1f60}\u{03b9}"),
+    ('\u{1fa9}', "\u{1f61}\u{03b9}"),
+    ('\u{1faa}', "\u{1f62}\u{03b9}"),
+    ('\u{1fab}', "\u{1f63}\u{03b9}"),
+    ('\u{1fac}', "\u{1f64}\u{03b9}"),
+    ('\u{1fad}', "\u{1f65}\u{03b9}"),
+    ('\u{1fae}', "\u{1f66}\u{03b9}"),
+    ('\u{1faf}', "\u{1f67}\u{03b9}"),
+    ('\u{1fb2}', "\u{1f70}\u{03b9}"),
+    ('\u{1fb3}', "\u{03b1}\u{03b9}"),
+    ('\u{1fb4}', "\u{03ac}\u{03b9}"),
+    ('\u{1fb6}', "\u{03b1}\u{0342}"),
+    ('\u{1fb7}', "\u{03b1}\u{0342}\u{03b9}"),
+    ('\u{1fb8}', "\u{1fb0}"),
+    ('\u{1fb9}', "\u{1fb1}"),
+    ('\u{1fba}', "\u{1f70}"),
+    ('\u{1fbb}', "\u{1f71}"),
+    ('\u{1fbc}', "\u{03b1}\u{03b9}"),
+    ('\u{1fbe}', "\u{03b9}"),
+    ('\u{1fc2}', "\u{1f74}\u{03b9}"),
+    ('\u{1fc3}', "\u{03b7}\u{03b9}"),
+    ('\u{1fc4}', "\u{03ae}\u{03b9}"),
+    ('\u{1fc6}', "\u{03b7}\u{0342}"),
+    ('\u{1fc7}', "\u{03b7}\u{0342}\u{03b9}"),
+    ('\u{1fc8}', "\u{1f72}"),
+    ('\u{1fc9}', "\u{1f73}"),
+    ('\u{1fca}', "\u{1f74}"),
+    ('\u{1fcb}', "\u{1f75}"),
+    ('\u{1fcc}', "\u{03b7}\u{03b9}"),
+    ('\u{1fd2}', "\u{03b9}\u{0308}\u{0300}"),
+    ('\u{1fd3}', "\u{03b9}\u{0308}\u{0301}"),
+    ('\u{1fd6}', "\u{03b9}\u{0342}"),
+    ('\u{1fd7}', "\u{03b9}\u{0308}\u{0342}"),
+    ('\u{1fd8}', "\u{1fd0}"),
+    ('\u{1fd9}', "\u{1fd1}"),
+    ('\u{1fda}', "\u{1f76}"),
+    ('\u{1fdb}', "\u{1f77}"),
+    ('\u{1fe2}', "\u{03c5}\u{0308}\u{0300}"),
+    ('\u{1fe3}', "\u{03c5}\u{0308}\u{0301}"),
+    ('\u{1fe4}', "\u{03c1}\u{0313}"),
+    ('\u{1fe6}', "\u{03c5}\u{0342}"),
+    ('\u{1fe7}', "\u{03c5}\u{0308}\u{0342}"),
+    ('\u{1fe8}', "\u{1fe0}"),
+    ('\u{1fe9}', "\u{1fe1}"),
+    ('\u{1fea}', "\u{1f7a}"),
+    ('\u{1feb}', "\u{1f7b}"),
+    ('\u{1fec}', "\u{1fe5}"),
+    ('\u{1ff2}', "\u{1f7c}\u{03b9}"),
+    ('\u{1ff3}', "\u{03c9}\u{03b9}"),
+    ('\u{1ff4}', "\u{03ce}\u{03b9}"),
+    ('\u{1ff6}', "\u{03c9}\u{0342}"),
+    ('\u{1ff7}', "\u{03c9}\u{0342}\u{03b9}"),
+    ('\u{1ff8}', "\u{1f78}"),
+    ('\u{1ff9}', "\u{1f79}"),
+    ('\u{1ffa}', "\u{1f7c}"),
+    ('\u{1ffb}', "\u{1f7d}"),
+    ('\u{1ffc}', "\u{03c9}\u{03b9}"),
+    ('\u{2126}', "\u{03c9}"),
+    ('\u{212a}', "\u{006b}"),
+    ('\u{212b}', "\u{00e5}"),
+    ('\u{2132}', "\u{214e}"),
+    ('\u{2160}', "\u{2170}"),
+    ('\u{2161}', "\u{2171}"),
+    ('\u{2162}', "\u{2172}"),
+    ('\u{2163}', "\u{2173}"),
+    ('\u{2164}', "\u{2174}"),
+    ('\u{2165}', "\u{2175}"),
+    ('\u{2166}', "\u{2176}"),
+    ('\u{2167}', "\u{2177}"),
+    ('\u{2168}', "\u{2178}"),
+    ('\u{2169}', "\u{2179}"),
+    ('\u{216a}', "\u{217a}"),
+    ('\u{216b}', "\u{217b}"),
+    ('\u{216c}', "\u{217c}"),
+    ('\u{216d}', "\u{217d}"),
+    ('\u{216e}', "\u{217e}"),
+    ('\u{216f}', "\u{217f}"),
+    ('\u{2183}', "\u{2184}"),
+    ('\u{24b6}', "\u{24d0}"),
+    ('\u{24b7}', "\u{24d1}"),
+    ('\u{24b8}', "\u{24d2}"),
+    ('\u{24b9}', "\u{24d3}"),
+    ('\u{24ba}', "\u{24d4}"),
+    ('\u{24bb}', "\u{24d5}"),
+    ('\u{24bc}', "\u{24d6}"),
+    ('\u{24bd}', "\u{24d7}"),
+    ('\u{24be}', "\u{24d8}"),
+    ('\u{24bf}', "\u{24d9}"),
+    ('\u{24c0}', "\u{24da}"),
+    ('\u{24c1}', "\u{24db}"),
+    ('\u{24c2}', "\u{24dc}"),
+    ('\u{24c3}', "\u{24dd}"),
+    ('\u{24c4}', "\u{24de}"),
+    ('\u{24c5}', "\u{24df}"),
+    ('\u{24c6}', "\u{24e0}"),
+    ('\u{24c7}', "\u{24e1}"),
+    ('\u{24c8}', "\u{24e2}"),
+    ('\u{24c9}', "\u{24e3}"),
+    ('\u{24ca}', "\u{24e4}"),
+    ('\u{24cb}', "\u{24e5}"),
+    ('\u{24cc}', "\u{24e6}"),
+    ('\u{24cd}', "\u{24e7}"),
+    ('\u{24ce}', "\u{24e8}"),
+    ('\u{24cf}', "\u{24e9}"),
+    ('\u{2c00}', "\u{2c30}"),
+    ('\u{2c01}', "\u{2c31}"),
+    ('\u{2c02}', "\u{2c32}"),
+    ('\u{2c03}', "\u{2c33}"),
+    ('\u{2c04}', "\u{2c34}"),
+    ('\u{2c05}', "\u{2c35}"),
+    ('\u{2c06}', "\u{2c36}"),
+    ('\u{2c07}', "\u{2c37}"),
+    ('\u{2c08}', "\u{2c38}"),
+    ('\u{2c09}', "\u{2c39}"),
+    ('\u{2c0a}', "\u{2c3a}"),
+    ('\u{2c0b}', "\u{2c3b}"),
+    ('\u{2c0c}', "\u{2c3c}"),
+    ('\u{2c0d}', "\u{2c3d}"),
+    ('\u{2c0e}', "\u{2c3e}"),
+    ('\u{2c0f}', "\u{2c3f}"),
+    ('\u{2c10}', "\u{2c40}"),
+    ('\u{2c11}', "\u{2c41}"),
+    ('\u{2c12}', "\u{2c42}"),
+    ('\u{2c13}', "\u{2c43}"),
+    ('\u{2c14}', "\u{2c44}"),
+    ('\u{2c15}', "\u{2c45}"),
+    ('\u{2c16}', "\u{2c46}"),
+    ('\u{2c17}', "\u{2c47}"),
+    ('\u{2c18}', "\u{2c48}"),
+    ('\u{2c19}', "\u{2c49}"),
+    ('\u{2c1a}', "\u{2c4a}"),
+    ('\u{2c1b}', "\u{2c4b}"),
+    ('\u{2c1c}', "\u{2c4c}"),
+    ('\u{2c1d}', "\u{2c4d}"),
+    ('\u{2c1e}', "\u{2c4e}"),
+    ('\u{2c1f}', "\u{2c4f}"),
+    ('\u{2c20}', "\u{2c50}"),
+    ('\u{2c21}', "\u{2c51}"),
+    ('\u{2c22}', "\u{2c52}"),
+    ('\u{2c23}', "\u{2c53}"),
+    ('\u{2c24}', "\u{2c54}"),
+    ('\u{2c25}', "\u{2c55}"),
+    ('\u{2c26}', "\u{2c56}"),
+    ('\u{2c27}', "\u{2c57}"),
+    ('\u{2c28}', "\u{2c58}"),
+    ('\u{2c29}', "\u{2c59}"),
+    ('\u{2c2a}', "\u{2c5a}"),
+    ('\u{2c2b}', "\u{2c5b}"),
+    ('\u{2c2c}', "\u{2c5c}"),
+    ('\u{2c2d}', "\u{2c5d}"),
+    ('\u{2c2e}', "\u{2c5e}"),
+    ('\u{2c2f}', "\u{2c5f}"),
+    ('\u{2c60}', "\u{2c61}"),
+    ('\u{2c62}', "\u{026b}"),
+    ('\u{2c63}', "\u{1d7d}"),
+    ('\u{2c64}', "\u{027d}"),
+    ('\u{2c67}', "\u{2c68}"),
+    ('\u{2c69}', "\u{2c6a}"),
+    ('\u{2c6b}', "\u{2c6c}"),
+    ('\u{2c6d}', "\u{0251}"),
+    ('\u{2c6e}', "\u{0271}"),
+    ('\u{2c6f}', "\u{0250}"),
+    ('\u{2c70}', "\u{0252}"),
+    ('\u{2c72}', "\u{2c73}"),
+    ('\u{2c75}', "\u{2c76}"),
+    ('\u{2c7e}', "\u{023f}"),
+    ('\u{2c7f}', "\u{0240}"),
+    ('\u{2c80}', "\u{2c81}"),
+    ('\u{2c82}', "\u{2c83}"),
+    ('\u{2c84}', "\u{2c85}"),
+    ('\u{2c86}', "\u{2c87}"),
+    ('\u{2c88}', "\u{2c89}"),
+    ('\u{2c8a}', "\u{2c8b}"),
+    ('\u{2c8c}', "\u{2c8d}"),
+    ('\u{2c8e}', "\u{2c8f}"),
+    ('\u{2c90}', "\u{2c91}"),
+    ('\u{2c92}', "\u{2c93}"),
+    ('\u{2c94}', "\u{2c95}"),
+    ('\u{2c96}', "\u{2c97}"),
+    ('\u{2c98}', "\u{2c99}"),
+    ('\u{2c9a}', "\u{2c9b}"),
+    ('\u{2c9c}', "\u{2c9d}"),
+    ('\u{2c9e}', "\u{2c9f}"),
+    ('\u{2ca0}', "\u{2ca1}"),
+    ('\u{2ca2}', "\u{2ca3}"),
+    ('\u{2ca4}', "\u{2ca5}"),
+    ('\u{2ca6}', "\u{2ca7}"),
+    ('\u{2ca8}', "\u{2ca9}"),
+    ('\u{2caa}', "\u{2cab}"),
+    ('\u{2cac}', "\u{2cad}"),
+    ('\u{2cae}', "\u{2caf}"),
+    ('\u{2cb0}', "\u{2cb1}"),
+    ('\u{2cb2}', "\u{2cb3}"),
+    ('\u{2cb4}', "\u{2cb5}"),
+    ('\u{2cb6}', "\u{2cb7}"),
+    ('\u{2cb8}', "\u{2cb9}"),
+    ('\u{2cba}', "\u{2cbb}"),
+    ('\u{2cbc}', "\u{2cbd}"),
+    ('\u{2cbe}', "\u{2cbf}"),
+    ('\u{2cc0}', "\u{2cc1}"),
+    ('\u{2cc2}', "\u{2cc3}"),
+    ('\u{2cc4}', "\u{2cc5}"),
+    ('\u{2cc6}', "\u{2cc7}"),
+    ('\u{2cc8}', "\u{2cc9}"),
+    ('\u{2cca}', "\u{2ccb}"),
+    ('\u{2ccc}', "\u{2ccd}"),
+    ('\u{2cce}', "\u{2ccf}"),
+    ('\u{2cd0}', "\u{2cd1}"),
+    ('\u{2cd2}', "\u{2cd3}"),
+    ('\u{2cd4}', "\u{2cd5}"),
+    ('\u{2cd6}', "\u{2cd7}"),
+    ('\u{2cd8}', "\u{2cd9}"),
+    ('\u{2cda}', "\u{2cdb}"),
+    ('\u{2cdc}', "\u{2cdd}"),
+    ('\u{2cde}', "\u{2cdf}"),
+    ('\u{2ce0}', "\u{2ce1}"),
+    ('\u{2ce2}', "\u{2ce3}"),
+    ('\u{2ceb}', "\u{2cec}"),
+    ('\u{2ced}', "\u{2cee}"),
+    ('\u{2cf2}', "\u{2cf3}"),
+    ('\u{a640}', "\u{a641}"),
+    ('\u{a642}', "\u{a643}"),
+    ('\u{a644}', "\u{a645}"),
+    ('\u{a646}', "\u{a647}"),
+    ('\u{a648}', "\u{a649}"),
+    ('\u{a64a}', "\u{a64b}"),
+    ('\u{a64c}', "\u{a64d}"),
+    ('\u{a64e}', "\u{a64f}"),
+    ('\u{a650}', "\u{a651}"),
+    ('\u{a652}', "\u{a653}"),
+    ('\u{a654}', "\u{a655}"),
+    ('\u{a656}', "\u{a657}"),
+    ('\u{a658}', "\u{a659}"),
+    ('\u{a65a}', "\u{a65b}"),
+    ('\u{a65c}', "\u{a65d}"),
+    ('\u{a65e}', "\u{a65f}"),
+    ('\u{a660}', "\u{a661}"),
+    ('\u{a662}', "\u{a663}"),
+    ('\u{a664}', "\u{a665}"),
+    ('\u{a666}', "\u{a667}"),
+    ('\u{a668}', "\u{a669}"),
+    ('\u{a66a}', "\u{a66b}"),
+    ('\u{a66c}', "\u{a66d}"),
+    ('\u{a680}', "\u{a681}"),
+    ('\u{a682}', "\u{a683}"),
+    ('\u{a684}', "\u{a685}"),
+    ('\u{a686}', "\u{a687}"),
+    ('\u{a688}', "\u{a689}"),
+    ('\u{a68a}', "\u{a68b}"),
+    ('\u{a68c}', "\u{a68d}"),
+    ('\u{a68e}', "\u{a68f}"),
+    ('\u{a690}', "\u{a691}"),
+    ('\u{a692}', "\u{a693}"),
+    ('\u{a694}', "\u{a695}"),
+    ('\u{a696}', "\u{a697}"),
+    ('\u{a698}', "\u{a699}"),
+    ('\u{a69a}', "\u{a69b}"),
+    ('\u{a722}', "\u{a723}"),
+    ('\u{a724}', "\u{a725}"),
+    ('\u{a726}', "\u{a727}"),
+    ('\u{a728}', "\u{a729}"),
+    ('\u{a72a}', "\u{a72b}"),
+    ('\u{a72c}', "\u{a72d}"),
+    ('\u{a72e}', "\u{a72f}"),
+    ('\u{a732}', "\u{a733}"),
+    ('\u{a734}', "\u{a735}"),
+    ('\u{a736}', "\u{a737}"),
+    ('\u{a738}', "\u{a739}"),
+    ('\u{a73a}', "\u{a73b}"),
+    ('\u{a73c}', "\u{a73d}"),
+    ('\u{a73e}', "\u{a73f}"),
+    ('\u{a740}', "\u{a741}"),
+    ('\u{a742}', "\u{a743}"),
+    ('\u{a744}', "\u{a745}"),
+    ('\u{a746}', "\u{a747}"),
+    ('\u{a748}', "\u{a749}"),
+    ('\u{a74a}', "\u{a74b}"),
+    ('\u{a74c}', "\u{a74d}"),
+    ('\u{a74e}', "\u{a74f}"),
+    ('\u{a750}', "\u{a751}"),
+    ('\u{a752}', "\u{a753}"),
+    ('\u{a754}', "\u{a755}"),
+    ('\u{a756}', "\u{a757}"),
+    ('\u{a758}', "\u{a759}"),
+    ('\u{a75a}', "\u{a75b}"),
+    ('\u{a75c}', "\u{a75d}"),
+    ('\u{a75e}', "\u{a75f}"),
+    ('\u{a760}', "\u{a761}"),
+    ('\u{a762}', "\u{a763}"),
+    ('\u{a764}', "\u{a765}"),
+    ('\u{a766}', "\u{a767}"),
+    ('\u{a768}', "\u{a769}"),
+    ('\u{a76a}', "\u{a76b}"),
+    ('\u{a76c}', "\u{a76d}"),
+    ('\u{a76e}', "\u{a76f}"),
+    ('\u{a779}', "\u{a77a}"),
+    ('\u{a77b}', "\u{a77c}"),
+    ('\u{a77d}', "\u{1d79}"),
+    ('\u{a77e}', "\u{a77f}"),
+    ('\u{a780}', "\u{a781}"),
+    ('\u{a782}', "\u{a783}"),
+    ('\u{a784}', "\u{a785}"),
+    ('\u{a786}', "\u{a787}"),
+    ('\u{a78b}', "\u{a78c}"),
+    ('\u{a78d}', "\u{0265}"),
+    ('\u{a790}', "\u{a791}"),
+    ('\u{a792}', "\u{a793}"),
+    ('\u{a796}', "\u{a797}"),
+    ('\u{a798}', "\u{a799}"),
+    ('\u{a79a}', "\u{a79b}"),
+    ('\u{a79c}', "\u{a79d}"),
+    ('\u{a79e}', "\u{a79f}"),
+    ('\u{a7a0}', "\u{a7a1}"),
+    ('\u{a7a2}', "\u{a7a3}"),
+    ('\u{a7a4}', "\u{a7a5}"),
+    ('\u{a7a6}', "\u{a7a7}"),
+    ('\u{a7a8}', "\u{a7a9}"),
+    ('\u{a7aa}', "\u{0266}"),
+    ('\u{a7ab}', "\u{025c}"),
+    ('\u{a7ac}', "\u{0261}"),
+    ('\u{a7ad}', "\u{026c}"),
+    ('\u{a7ae}', "\u{026a}"),
+    ('\u{a7b0}', "\u{029e}"),
+    ('\u{a7b1}', "\u{0287}"),
+    ('\u{a7b2}', "\u{029d}"),
+    ('\u{a7b3}', "\u{ab53}"),
+    ('\u{a7b4}', "\u{a7b5}"),
+    ('\u{a7b6}', "\u{a7b7}"),
+    ('\u{a7b8}', "\u{a7b9}"),
+    ('\u{a7ba}', "\u{a7bb}"),
+    ('\u{a7bc}', "\u{a7bd}"),
+    ('\u{a7be}', "\u{a7bf}"),
+    ('\u{a7c0}', "\u{a7c1}"),
+    ('\u{a7c2}', "\u{a7c3}"),
+    ('\u{a7c4}', "\u{a794}"),
+    ('\u{a7c5}', "\u{0282}"),
+    ('\u{a7c6}', "\u{1d8e}"),
+    ('\u{a7c7}', "\u{a7c8}"),
+    ('\u{a7c9}', "\u{a7ca}"),
+    ('\u{a7d0}', "\u{a7d1}"),
+    ('\u{a7d6}', "\u{a7d7}"),
+    ('\u{a7d8}', "\u{a7d9}"),
+    ('\u{a7f5}', "\u{a7f6}"),
+    ('\u{ab70}', "\u{13a0}"),
+    ('\u{ab71}', "\u{13a1}"),
+    ('\u{ab72}', "\u{13a2}"),
+    ('\u{ab73}', "\u{13a3}"),
+    ('\u{ab74}', "\u{13a4}"),
+    ('\u{ab75}', "\u{13a5}"),
+    ('\u{ab76}', "\u{13a6}"),
+    ('\u{ab77}', "\u{13a7}"),
+    ('\u{ab78}', "\u{13a8}"),
+    ('\u{ab79}', "\u{13a9}"),
+    ('\u{ab7a}', "\u{13aa}"),
+    ('\u{ab7b}', "\u{13ab}"),
+    ('\u{ab7c}', "\u{13ac}"),
+    ('\u{ab7d}', "\u{13ad}"),
+    ('\u{ab7e}', "\u{13ae}"),
+    ('\u{ab7f}', "\u{13af}"),
+    ('\u{ab80}', "\u{13b0}"),
+    ('\u{ab81}', "\u{13b1}"),
+    ('\u{ab82}', "\u{13b2}"),
+    ('\u{ab83}', "\u{13b3}"),
+    ('\u{ab84}', "\u{13b4}"),
+    ('\u{ab85}', "\u{13b5}"),
+    ('\u{ab86}', "\u{13b6}"),
+    ('\u{ab87}', "\u{13b7}"),
+    ('\u{ab88}', "\u{13b8}"),
+    ('\u{ab89}', "\u{13b9}"),
+    ('\u{ab8a}', "\u{13ba}"),
+    ('\u{ab8b}', "\u{13bb}"),
+    ('\u{ab8c}', "\u{13bc}"),
+    ('\u{ab8d}', "\u{13bd}"),
+    ('\u{ab8e}', "\u{13be}"),
+    ('\u{ab8f}', "\u{13bf}"),
+    ('\u{ab90}', "\u{13c0}"),
+    ('\u{ab91}', "\u{13c1}"),
+    ('\u{ab92}', "\u{13c2}"),
+    ('\u{ab93}', "\u{13c3}"),
+    ('\u{ab94}', "\u{13c4}"),
+    ('\u{ab95}', "\u{13c5}"),
+    ('\u{ab96}', "\u{13c6}"),
+    ('\u{ab97}', "\u{13c7}"),
+    ('\u{ab98}', "\u{13c8}"),
+    ('\u{ab99}', "\u{13c9}"),
+    ('\u{ab9a}', "\u{13ca}"),
+    ('\u{ab9b}', "\u{13cb}"),
+    ('\u{ab9c}', "\u{13cc}"),
+    ('\u{ab9d}', "\u{13cd}"),
+    ('\u{ab9e}', "\u{13ce}"),
+    ('\u{ab9f}', "\u{13cf}"),
+    ('\u{aba0}', "\u{13d0}"),
+    ('\u{aba1}', "\u{13d1}"),
+    ('\u{aba2}', "\u{13d2}"),
+    ('\u{aba3}', "\u{13d3}"),
+    ('\u{aba4}', "\u{13d4}"),
+    ('\u{aba5}', "\u{13d5}"),
+    ('\u{aba6}', "\u{13d6}"),
+    ('\u{aba7}', "\u{13d7}"),
+    ('\u{aba8}', "\u{13d8}"),
+    ('\u{aba9}', "\u{13d9}"),
+    ('\u{abaa}', "\u{13da}"),
+    ('\u{abab}', "\u{13db}"),
+    ('\u{abac}', "\u{13dc}"),
+    ('\u{abad}', "\u{13dd}"),
+    ('\u{abae}', "\u{13de}"),
+    ('\u{abaf}', "\u{13df}"),
+    ('\u{abb0}', "\u{13e0}"),
+    ('\u{abb1}', "\u{13e1}"),
+    ('\u{abb2}', "\u{13e2}"),
+    ('\u{abb3}', "\u{13e3}"),
+    ('\u{abb4}', "\u{13e4}"),
+    ('\u{abb5}', "\u{13e5}"),
+    ('\u{abb6}', "\u{13e6}"),
+    ('\u{abb7}', "\u{13e7}"),
+    ('\u{abb8}', "\u{13e8}"),
+    ('\u{abb9}', "\u{13e9}"),
+    ('\u{abba}', "\u{13ea}"),
+    ('\u{abbb}', "\u{13eb}"),
+    ('\u{abbc}', "\u{13ec}"),
+    ('\u{abbd}', "\u{13ed}"),
+    ('\u{abbe}', "\u{13ee}"),
+    ('\u{abbf}', "\u{13ef}"),
+    ('\u{fb00}', "\u{0066}\u{0066}"),
+    ('\u{fb01}', "\u{0066}\u{0069}"),
+    ('\u{fb02}', "\u{0066}\u{006c}"),
+    ('\u{fb03}', "\u{0066}\u{0066}\u{0069}"),
+    ('\u{fb04}', "\u{0066}\u{0066}\u{006c}"),
+    ('\u{fb05}', "\u{0073}\u{0074}"),
+    ('\u{fb06}', "\u{0073}\u{0074}"),
+    ('\u{fb13}', "\u{0574}\u{0576}"),
+    ('\u{fb14}', "\u{0574}\u{0565}"),
+    ('\u{fb15}', "\u{0574}\u{056b}"),
+    ('\u{fb16}', "\u{057e}\u{0576}"),
+    ('\u{fb17}', "\u{0574}\u{056d}"),
+    ('\u{ff21}', "\u{ff41}"),
+    ('\u{ff22}', "\u{ff42}"),
+    ('\u{ff23}', "\u{ff43}"),
+    ('\u{ff24}', "\u{ff44}"),
+    ('\u{ff25}', "\u{ff45}"),
+    ('\u{ff26}', "\u{ff46}"),
+    ('\u{ff27}', "\u{ff47}"),
+    ('\u{ff28}', "\u{ff48}"),
+    ('\u{ff29}', "\u{ff49}"),
+    ('\u{ff2a}', "\u{ff4a}"),
+    ('\u{ff2b}', "\u{ff4b}"),
+    ('\u{ff2c}', "\u{ff4c}"),
+    ('\u{ff2d}', "\u{ff4d}"),
+    ('\u{ff2e}', "\u{ff4e}"),
+    ('\u{ff2f}', "\u{ff4f}"),
+    ('\u{ff30}', "\u{ff50}"),
+    ('\u{ff31}', "\u{ff51}"),
+    ('\u{ff32}', "\u{ff52}"),
+    ('\u{ff33}', "\u{ff53}"),
+    ('\u{ff34}', "\u{ff54}"),
+    ('\u{ff35}', "\u{ff55}"),
+    ('\u{ff36}', "\u{ff56}"),
+    ('\u{ff37}', "\u{ff57}"),
+    ('\u{ff38}', "\u{ff58}"),
+    ('\u{ff39}', "\u{ff59}"),
+    ('\u{ff3a}', "\u{ff5a}"),
+];
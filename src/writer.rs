@@ -0,0 +1,207 @@
+// Serializes a tree back into a Bud1 file in the layout Finder writes: the
+// allocator info block at 0x800, the `DSDB` master block at 0x20 and a
+// single leaf node at 0x1000. Multi-level trees aren't written yet, so the
+// records have to fit into one 4 KiB node.
+
+use std::io::Write;
+
+use crate::ds_store::DsStore;
+use crate::error::DsStoreError;
+use crate::parser::DsStoreParser;
+use crate::record::{Record, Value};
+
+const PAGE_SIZE: usize = 0x1000;
+
+// Finder fills the rest of the header with these unexplained bytes.
+const UNKNOWN_HEADER_BYTES: [u8; 16] = [
+    0x00, 0x00, 0x10, 0x0c, 0x00, 0x00, 0x00, 0x87,
+    0x00, 0x00, 0x20, 0x0b, 0x00, 0x00, 0x00, 0x00,
+];
+
+// Block addresses and their log2 sizes: the allocator info, the master
+// block and the leaf node.
+const INFO_BLOCK: (usize, u32) = (0x800, 11);
+const MASTER_BLOCK: (usize, u32) = (0x20, 5);
+const LEAF_BLOCK: (usize, u32) = (0x1000, 12);
+
+// The allocator reserves address slots in multiples of 256.
+const ADDRESS_SLOTS: usize = 256;
+
+fn push_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+fn push_utf16(out: &mut Vec<u8>, value: &str) {
+    let units = value.encode_utf16().collect::<Vec<_>>();
+    push_u32(out, units.len() as u32);
+
+    for unit in units {
+        out.extend_from_slice(&unit.to_be_bytes());
+    }
+}
+
+fn encode_record(out: &mut Vec<u8>, record: &Record) -> Result<(), DsStoreError> {
+    push_utf16(out, &record.name);
+    out.extend_from_slice(&record.structure_id);
+
+    match &record.value {
+        Value::Long(value) => {
+            out.extend_from_slice(b"long");
+            out.extend_from_slice(&value.to_be_bytes());
+        }
+        Value::Short(value) => {
+            out.extend_from_slice(b"shor");
+            out.extend_from_slice(&(*value as i32).to_be_bytes());
+        }
+        Value::Bool(value) => {
+            out.extend_from_slice(b"bool");
+            out.push(*value as u8);
+        }
+        Value::Blob(value) => {
+            out.extend_from_slice(b"blob");
+            push_u32(out, value.len() as u32);
+            out.extend_from_slice(value);
+        }
+        Value::Ustr(value) => {
+            out.extend_from_slice(b"ustr");
+            push_utf16(out, value);
+        }
+        Value::Type(value) => {
+            out.extend_from_slice(b"type");
+            out.extend_from_slice(value);
+        }
+        Value::Comp(value) => {
+            out.extend_from_slice(b"comp");
+            out.extend_from_slice(&value.to_be_bytes());
+        }
+        Value::Dutc { raw, .. } => {
+            out.extend_from_slice(b"dutc");
+            out.extend_from_slice(&raw.to_be_bytes());
+        }
+        Value::Unknown { raw, .. } => {
+            out.extend_from_slice(record.data_type.tag());
+            out.extend_from_slice(raw);
+        }
+        Value::Skipped => {
+            return Err(
+                DsStoreError::NotDecoded {
+                    name: record.name.clone(),
+                    structure_id: record.structure_id,
+                }
+            );
+        }
+    }
+
+    Ok(())
+}
+
+// Every block of the 2^31 byte address space that isn't allocated, split
+// the buddy way and grouped by log2 size.
+fn free_lists(allocated: &[(usize, u32)]) -> Vec<Vec<u32>> {
+    let mut lists = vec![Vec::new(); 32];
+    let mut pending = vec![(0usize, 31u32)];
+
+    while let Some((address, log_size)) = pending.pop() {
+        if allocated.contains(&(address, log_size)) {
+            continue;
+        }
+
+        let end = address + (1 << log_size);
+        if !allocated.iter().any(|(a, _)| *a >= address && *a < end) {
+            lists[log_size as usize].push(address as u32);
+            continue;
+        }
+
+        // Push the upper half first so the lists come out in address order.
+        let half = 1 << (log_size - 1);
+        pending.push((address + half, log_size - 1));
+        pending.push((address, log_size - 1));
+    }
+
+    lists
+}
+
+impl DsStoreParser {
+    // Writes the live records of `store` as a new file. Recovered records are
+    // left out, and the records are sorted the way Finder expects them: by
+    // name ignoring case, then by structure id.
+    pub fn write(&self, store: &DsStore, w: &mut impl Write) -> Result<(), DsStoreError> {
+        let mut records = store
+            .iter()
+            .map(|(_, record)| record)
+            .filter(|record| !record.is_recovered())
+            .collect::<Vec<_>>();
+        records.sort_by_cached_key(|r| (r.name.to_lowercase(), r.structure_id));
+
+        // A leaf node is its mode (0), the record count and the records.
+        let mut leaf = Vec::new();
+        push_u32(&mut leaf, 0);
+        push_u32(&mut leaf, records.len() as u32);
+        for record in records.iter() {
+            encode_record(&mut leaf, record)?;
+        }
+
+        if leaf.len() > PAGE_SIZE {
+            return Err(
+                DsStoreError::TooLargeToWrite {
+                    size: leaf.len(),
+                    max: PAGE_SIZE,
+                }
+            );
+        }
+
+        // The root node is block 2, and its tree has no internal levels.
+        let mut master = Vec::new();
+        push_u32(&mut master, 2);
+        push_u32(&mut master, 0);
+        push_u32(&mut master, records.len() as u32);
+        push_u32(&mut master, 1);
+        push_u32(&mut master, PAGE_SIZE as u32);
+
+        let blocks = [INFO_BLOCK, MASTER_BLOCK, LEAF_BLOCK];
+
+        let mut info = Vec::new();
+        push_u32(&mut info, blocks.len() as u32);
+        push_u32(&mut info, 0);
+        for (address, log_size) in blocks {
+            push_u32(&mut info, (address | log_size as usize) as u32);
+        }
+        for _ in blocks.len()..ADDRESS_SLOTS {
+            push_u32(&mut info, 0);
+        }
+
+        push_u32(&mut info, 1);
+        info.push(4);
+        info.extend_from_slice(b"DSDB");
+        push_u32(&mut info, 1);
+
+        // The header occupies the first 32 bytes as far as the allocator is
+        // concerned.
+        let mut allocated = blocks.to_vec();
+        allocated.push((0, 5));
+
+        for list in free_lists(&allocated) {
+            push_u32(&mut info, list.len() as u32);
+            for address in list {
+                push_u32(&mut info, address);
+            }
+        }
+
+        // Block addresses don't count the 4 byte prefix before the header.
+        let mut out = vec![0u8; LEAF_BLOCK.0 + PAGE_SIZE + 4];
+
+        out[0..4].copy_from_slice(&1u32.to_be_bytes());
+        out[4..8].copy_from_slice(b"Bud1");
+        out[8..12].copy_from_slice(&(INFO_BLOCK.0 as u32).to_be_bytes());
+        out[12..16].copy_from_slice(&(1u32 << INFO_BLOCK.1).to_be_bytes());
+        out[16..20].copy_from_slice(&(INFO_BLOCK.0 as u32).to_be_bytes());
+        out[20..36].copy_from_slice(&UNKNOWN_HEADER_BYTES);
+
+        for ((address, _), data) in blocks.iter().zip([&info, &master, &leaf]) {
+            out[address + 4..address + 4 + data.len()].copy_from_slice(data);
+        }
+
+        w.write_all(&out)?;
+        Ok(())
+    }
+}
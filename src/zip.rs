@@ -0,0 +1,166 @@
+// Reader for zip archives, enough to pull `.DS_Store` files out of uploaded
+// bundles. Stored and deflated entries are supported; encrypted entries and
+// zip64 archives are not.
+
+use crate::error::DsStoreError;
+use crate::inflate::{crc32, inflate};
+
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+const CENTRAL_DIRECTORY_HEADER: u32 = 0x0201_4b50;
+const LOCAL_FILE_HEADER: u32 = 0x0403_4b50;
+
+const END_OF_CENTRAL_DIRECTORY_LENGTH: usize = 22;
+const CENTRAL_DIRECTORY_HEADER_LENGTH: usize = 46;
+const LOCAL_FILE_HEADER_LENGTH: usize = 30;
+
+const STORED: u16 = 0;
+const DEFLATED: u16 = 8;
+
+const FLAG_ENCRYPTED: u16 = 0x0001;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZipEntry {
+    pub name: String,
+    pub method: u16,
+    pub compressed_size: u32,
+    pub size: u32,
+    pub crc32: u32,
+    pub(crate) flags: u16,
+    pub(crate) header_offset: u32,
+}
+
+impl ZipEntry {
+    // Whether the entry is a `.DS_Store` file, at any depth.
+    pub fn is_ds_store(&self) -> bool {
+        self.name.rsplit('/').next() == Some(".DS_Store")
+    }
+}
+
+pub struct ZipArchive<'a> {
+    buf: &'a [u8],
+    entries: Vec<ZipEntry>,
+}
+
+fn invalid(reason: &'static str) -> DsStoreError {
+    DsStoreError::InvalidZip { reason }
+}
+
+fn u16_at(buf: &[u8], offset: usize) -> Result<u16, DsStoreError> {
+    match buf.get(offset..offset + 2) {
+        Some(bytes) => Ok(u16::from_le_bytes([bytes[0], bytes[1]])),
+        None => Err(invalid("unexpected end of archive")),
+    }
+}
+
+fn u32_at(buf: &[u8], offset: usize) -> Result<u32, DsStoreError> {
+    match buf.get(offset..offset + 4) {
+        Some(bytes) => Ok(u32::from_le_bytes(bytes.try_into().unwrap())),
+        None => Err(invalid("unexpected end of archive")),
+    }
+}
+
+impl<'a> ZipArchive<'a> {
+    pub fn parse(buf: &'a [u8]) -> Result<Self, DsStoreError> {
+        let end = Self::find_end_of_central_directory(buf)?;
+
+        let count = u16_at(buf, end + 10)? as usize;
+        let directory_offset = u32_at(buf, end + 16)?;
+
+        if directory_offset == u32::MAX || count == u16::MAX as usize {
+            return Err(invalid("zip64 archives are not supported"));
+        }
+
+        let mut entries = Vec::new();
+        let mut cursor = directory_offset as usize;
+
+        for _ in 0..count {
+            if u32_at(buf, cursor)? != CENTRAL_DIRECTORY_HEADER {
+                return Err(invalid("corrupt central directory"));
+            }
+
+            let name_length = u16_at(buf, cursor + 28)? as usize;
+            let extra_length = u16_at(buf, cursor + 30)? as usize;
+            let comment_length = u16_at(buf, cursor + 32)? as usize;
+
+            let start = cursor + CENTRAL_DIRECTORY_HEADER_LENGTH;
+            let name = match buf.get(start..start + name_length) {
+                Some(name) => String::from_utf8_lossy(name).into(),
+                None => return Err(invalid("unexpected end of archive")),
+            };
+
+            entries.push(
+                ZipEntry {
+                    name,
+                    method: u16_at(buf, cursor + 10)?,
+                    compressed_size: u32_at(buf, cursor + 20)?,
+                    size: u32_at(buf, cursor + 24)?,
+                    crc32: u32_at(buf, cursor + 16)?,
+                    flags: u16_at(buf, cursor + 8)?,
+                    header_offset: u32_at(buf, cursor + 42)?,
+                }
+            );
+
+            cursor = start + name_length + extra_length + comment_length;
+        }
+
+        Ok(Self { buf, entries })
+    }
+
+    // The end of central directory record is followed by a comment of up to
+    // 64 KiB, so search backwards for its signature.
+    fn find_end_of_central_directory(buf: &[u8]) -> Result<usize, DsStoreError> {
+        if buf.len() < END_OF_CENTRAL_DIRECTORY_LENGTH {
+            return Err(invalid("missing end of central directory"));
+        }
+
+        let last = buf.len() - END_OF_CENTRAL_DIRECTORY_LENGTH;
+        let first = last.saturating_sub(u16::MAX as usize);
+
+        for offset in (first..=last).rev() {
+            if u32_at(buf, offset)? == END_OF_CENTRAL_DIRECTORY {
+                return Ok(offset);
+            }
+        }
+
+        Err(invalid("missing end of central directory"))
+    }
+
+    pub fn entries(&self) -> &[ZipEntry] {
+        &self.entries
+    }
+
+    // Decompresses `entry` and checks it against its CRC-32.
+    pub fn read(&self, entry: &ZipEntry) -> Result<Vec<u8>, DsStoreError> {
+        if entry.flags & FLAG_ENCRYPTED != 0 {
+            return Err(invalid("encrypted entries are not supported"));
+        }
+
+        let offset = entry.header_offset as usize;
+        if u32_at(self.buf, offset)? != LOCAL_FILE_HEADER {
+            return Err(invalid("corrupt local file header"));
+        }
+
+        // The local header repeats the name but may carry different extra
+        // fields than the central directory.
+        let name_length = u16_at(self.buf, offset + 26)? as usize;
+        let extra_length = u16_at(self.buf, offset + 28)? as usize;
+        let start = offset + LOCAL_FILE_HEADER_LENGTH + name_length + extra_length;
+
+        let data = match self.buf.get(start..start + entry.compressed_size as usize) {
+            Some(data) => data,
+            None => return Err(invalid("unexpected end of archive")),
+        };
+
+        let contents = match entry.method {
+            STORED => data.to_vec(),
+            DEFLATED => inflate(data).map_err(invalid)?.0,
+            _ => return Err(invalid("unsupported compression method")),
+        };
+
+        if contents.len() != entry.size as usize || crc32(&contents) != entry.crc32 {
+            return Err(invalid("checksum mismatch"));
+        }
+
+        Ok(contents)
+    }
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::{vsrn, Builder};
+use ds_store_parser::DsStoreParser;
+
+fn names(count: usize) -> Vec<String> {
+    (0..count).map(|i| format!("file_{:03}.txt", i)).collect()
+}
+
+fn parsed_names(buf: &[u8]) -> Vec<String> {
+    DsStoreParser::new()
+        .parse_bytes(buf)
+        .unwrap()
+        .children()
+        .iter()
+        .map(|c| c.name().to_string())
+        .collect()
+}
+
+#[test]
+fn walks_two_level_tree() {
+    let expected = names(40);
+    let buf = Builder::new()
+        .records(expected.iter().map(|n| vsrn(n)))
+        .per_node(8)
+        .build();
+
+    assert_eq!(parsed_names(&buf), expected);
+}
+
+#[test]
+fn walks_three_level_tree() {
+    let expected = names(60);
+    let buf = Builder::new()
+        .records(expected.iter().map(|n| vsrn(n)))
+        .per_node(3)
+        .build();
+
+    assert_eq!(parsed_names(&buf), expected);
+}
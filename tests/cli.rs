@@ -0,0 +1,160 @@
+#![cfg(feature = "fs")]
+
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use common::{vsrn, Builder};
+
+const ICON_VIEW: &str = "tests/fixtures/icon_view.DS_Store";
+const LIST_VIEW: &str = "tests/fixtures/list_view.DS_Store";
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_ds_store_parser"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8(output.stderr.clone()).unwrap()
+}
+
+// A fresh directory per test, so tests running in parallel don't share files.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir()
+        .join(format!("ds_store_parser_cli_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_ds_store(dir: &Path, names: &[&str]) {
+    fs::create_dir_all(dir).unwrap();
+    let buf = Builder::new()
+        .records(names.iter().map(|name| vsrn(name)))
+        .build();
+    fs::write(dir.join(".DS_Store"), buf).unwrap();
+}
+
+#[test]
+fn skips_failed_files_when_others_parse() {
+    let output = run(&["-f", ICON_VIEW, "does/not/exist", "--count"]);
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), format!("{}: 8\n", ICON_VIEW));
+    assert!(stderr(&output).starts_with("ERROR: does/not/exist: I/O error"));
+}
+
+#[test]
+fn fails_with_first_status_when_every_file_fails() {
+    assert_eq!(run(&["-f", "Cargo.toml", "does/not/exist"]).status.code(), Some(3));
+    assert_eq!(run(&["-f", "does/not/exist", "Cargo.toml"]).status.code(), Some(1));
+    assert_eq!(run(&["-f", "Cargo.toml"]).status.code(), Some(3));
+}
+
+#[test]
+fn prints_files_containing_name() {
+    let output = run(&["-f", ICON_VIEW, LIST_VIEW, "--contains", "notes.txt"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), format!("{}\n", ICON_VIEW));
+
+    let output = run(&["-f", ICON_VIEW, LIST_VIEW, "--contains", "notes"]);
+    assert_eq!(output.status.code(), Some(4));
+    assert_eq!(stdout(&output), "");
+}
+
+#[test]
+fn nests_followed_folders() {
+    let dir = temp_dir("follow_names");
+    write_ds_store(&dir, &["Photos", "a.txt"]);
+    write_ds_store(&dir.join("Photos"), &["cat.jpg"]);
+
+    let output = run(&["--follow-names", &dir.to_string_lossy(), "--color", "never"]);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "DSDB\n    Photos:\n        cat.jpg\n    a.txt\n");
+}
+
+#[test]
+fn parses_files_found_recursively() {
+    let dir = temp_dir("recursive");
+    write_ds_store(&dir.join("a"), &["one.txt"]);
+    write_ds_store(&dir.join("a/b"), &["two.txt", "three.txt"]);
+    fs::write(dir.join("a/other.DS_Store"), b"not a DS_Store file").unwrap();
+
+    let root = dir.to_string_lossy().to_string();
+    let output = run(&["-r", &root, "--count"]);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        stdout(&output),
+        format!("{0}/a/.DS_Store: 1\n{0}/a/b/.DS_Store: 2\n", root)
+    );
+}
+
+#[test]
+fn writes_redacted_copy() {
+    let dir = temp_dir("redact");
+    let copy = dir.join("redacted.DS_Store").to_string_lossy().to_string();
+
+    // The bwsp and icvp plists are dropped with the names.
+    let output = run(&["-f", ICON_VIEW, "--redact", &copy, "--count"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "6\n");
+
+    let output = run(&["-f", &copy, "--color", "never"]);
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert!(!stdout(&output).contains("notes.txt"));
+    assert_eq!(stdout(&output).lines().count(), 7);
+}
+
+#[test]
+fn rejects_redact_with_filters_or_partial_decoding() {
+    let output = run(&["-f", ICON_VIEW, "--redact", "unused.DS_Store", "--type", "Iloc"]);
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = run(&["-f", ICON_VIEW, "--redact", "unused.DS_Store", "--decode", "names"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(!Path::new("unused.DS_Store").exists());
+}
+
+#[test]
+fn lists_only_deleted_entries() {
+    let dir = temp_dir("only_recovered");
+    let file = dir.join(".DS_Store");
+    let buf = Builder::new()
+        .record(vsrn("kept.txt"))
+        .stale([vsrn("gone.txt"), vsrn("kept.txt")])
+        .build();
+    fs::write(&file, buf).unwrap();
+
+    let output = run(&["-f", &file.to_string_lossy(), "--only-recovered", "--color", "never"]);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "DSDB\n    gone.txt (recovered)\n");
+}
+
+#[test]
+fn leaves_undecoded_values_empty() {
+    let output = run(&["-f", ICON_VIEW, "--decode", "names", "--format", "csv", "--type", "cmmt"]);
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        stdout(&output),
+        "path,structure_id,data_type,value,source\nnotes.txt,cmmt,ustr,,live\n"
+    );
+
+    let output = run(&["-f", ICON_VIEW, "--format", "csv", "--type", "cmmt"]);
+    assert!(!stdout(&output).contains(",ustr,,"));
+}
@@ -0,0 +1,151 @@
+// Strict JSON syntax check (RFC 8259), so tests don't need a JSON crate.
+
+pub fn is_valid(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    let mut cursor = 0;
+
+    value(bytes, &mut cursor) && {
+        whitespace(bytes, &mut cursor);
+        cursor == bytes.len()
+    }
+}
+
+fn whitespace(bytes: &[u8], cursor: &mut usize) {
+    while matches!(bytes.get(*cursor), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+        *cursor += 1;
+    }
+}
+
+fn literal(bytes: &[u8], cursor: &mut usize, word: &[u8]) -> bool {
+    if bytes[*cursor..].starts_with(word) {
+        *cursor += word.len();
+        true
+    } else {
+        false
+    }
+}
+
+fn value(bytes: &[u8], cursor: &mut usize) -> bool {
+    whitespace(bytes, cursor);
+
+    match bytes.get(*cursor) {
+        Some(b'{') => sequence(bytes, cursor, b'}', |bytes, cursor| {
+            whitespace(bytes, cursor);
+            string(bytes, cursor) && {
+                whitespace(bytes, cursor);
+                literal(bytes, cursor, b":")
+            } && value(bytes, cursor)
+        }),
+        Some(b'[') => sequence(bytes, cursor, b']', value),
+        Some(b'"') => string(bytes, cursor),
+        Some(b't') => literal(bytes, cursor, b"true"),
+        Some(b'f') => literal(bytes, cursor, b"false"),
+        Some(b'n') => literal(bytes, cursor, b"null"),
+        Some(b'-' | b'0'..=b'9') => number(bytes, cursor),
+        _ => false,
+    }
+}
+
+fn sequence(
+    bytes: &[u8],
+    cursor: &mut usize,
+    close: u8,
+    item: fn(&[u8], &mut usize) -> bool,
+) -> bool {
+    *cursor += 1;
+    whitespace(bytes, cursor);
+
+    if bytes.get(*cursor) == Some(&close) {
+        *cursor += 1;
+        return true;
+    }
+
+    loop {
+        if !item(bytes, cursor) {
+            return false;
+        }
+        whitespace(bytes, cursor);
+
+        match bytes.get(*cursor) {
+            Some(b',') => *cursor += 1,
+            Some(c) if *c == close => {
+                *cursor += 1;
+                return true;
+            }
+            _ => return false,
+        }
+    }
+}
+
+fn string(bytes: &[u8], cursor: &mut usize) -> bool {
+    if bytes.get(*cursor) != Some(&b'"') {
+        return false;
+    }
+    *cursor += 1;
+
+    loop {
+        match bytes.get(*cursor) {
+            Some(b'"') => {
+                *cursor += 1;
+                return true;
+            }
+            Some(b'\\') => {
+                *cursor += 1;
+                match bytes.get(*cursor) {
+                    Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => *cursor += 1,
+                    Some(b'u') => {
+                        let hex = bytes.get(*cursor + 1..*cursor + 5);
+                        if !hex.is_some_and(|h| h.iter().all(u8::is_ascii_hexdigit)) {
+                            return false;
+                        }
+                        *cursor += 5;
+                    }
+                    _ => return false,
+                }
+            }
+            Some(c) if *c >= 0x20 => *cursor += 1,
+            _ => return false,
+        }
+    }
+}
+
+fn digits(bytes: &[u8], cursor: &mut usize) -> bool {
+    let start = *cursor;
+    while bytes.get(*cursor).is_some_and(u8::is_ascii_digit) {
+        *cursor += 1;
+    }
+    *cursor > start
+}
+
+fn number(bytes: &[u8], cursor: &mut usize) -> bool {
+    if bytes.get(*cursor) == Some(&b'-') {
+        *cursor += 1;
+    }
+
+    match bytes.get(*cursor) {
+        Some(b'0') => *cursor += 1,
+        Some(b'1'..=b'9') => {
+            digits(bytes, cursor);
+        }
+        _ => return false,
+    }
+
+    if bytes.get(*cursor) == Some(&b'.') {
+        *cursor += 1;
+        if !digits(bytes, cursor) {
+            return false;
+        }
+    }
+
+    if matches!(bytes.get(*cursor), Some(b'e' | b'E')) {
+        *cursor += 1;
+        if matches!(bytes.get(*cursor), Some(b'+' | b'-')) {
+            *cursor += 1;
+        }
+        if !digits(bytes, cursor) {
+            return false;
+        }
+    }
+
+    true
+}
@@ -0,0 +1,350 @@
+//! Builds synthetic Bud1 files laid out the way Finder writes them: the
+//! allocator info block at 0x800, the `DSDB` master block at 0x20 and the
+//! B-tree nodes from 0x1000 onwards.
+
+#![allow(dead_code)]
+
+pub mod json;
+pub mod plist;
+
+pub const UNKNOWN_HEADER_BYTES: [u8; 16] = [
+    0x00, 0x00, 0x10, 0x0c, 0x00, 0x00, 0x00, 0x87,
+    0x00, 0x00, 0x20, 0x0b, 0x00, 0x00, 0x00, 0x00,
+];
+
+pub enum Data {
+    Long(i32),
+    Shor(i16),
+    Bool(bool),
+    Blob(Vec<u8>),
+    Type([u8; 4]),
+    Ustr(String),
+    Comp(u64),
+    Dutc(u64),
+}
+
+pub struct Rec {
+    pub name: String,
+    pub id: [u8; 4],
+    pub data: Data,
+}
+
+pub fn rec(name: &str, id: &[u8; 4], data: Data) -> Rec {
+    Rec {
+        name: name.into(),
+        id: *id,
+        data,
+    }
+}
+
+pub fn vsrn(name: &str) -> Rec {
+    rec(name, b"vSrn", Data::Long(1))
+}
+
+fn push_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+fn push_utf16(out: &mut Vec<u8>, value: &str) {
+    let units = value.encode_utf16().collect::<Vec<_>>();
+    push_u32(out, units.len() as u32);
+
+    for unit in units {
+        out.extend_from_slice(&unit.to_be_bytes());
+    }
+}
+
+pub fn encode_record(record: &Rec) -> Vec<u8> {
+    let mut out = Vec::new();
+    push_utf16(&mut out, &record.name);
+    out.extend_from_slice(&record.id);
+
+    match &record.data {
+        Data::Long(value) => {
+            out.extend_from_slice(b"long");
+            out.extend_from_slice(&value.to_be_bytes());
+        }
+        Data::Shor(value) => {
+            out.extend_from_slice(b"shor");
+            out.extend_from_slice(&(*value as i32).to_be_bytes());
+        }
+        Data::Bool(value) => {
+            out.extend_from_slice(b"bool");
+            out.push(*value as u8);
+        }
+        Data::Blob(value) => {
+            out.extend_from_slice(b"blob");
+            push_u32(&mut out, value.len() as u32);
+            out.extend_from_slice(value);
+        }
+        Data::Type(value) => {
+            out.extend_from_slice(b"type");
+            out.extend_from_slice(value);
+        }
+        Data::Ustr(value) => {
+            out.extend_from_slice(b"ustr");
+            push_utf16(&mut out, value);
+        }
+        Data::Comp(value) => {
+            out.extend_from_slice(b"comp");
+            out.extend_from_slice(&value.to_be_bytes());
+        }
+        Data::Dutc(value) => {
+            out.extend_from_slice(b"dutc");
+            out.extend_from_slice(&value.to_be_bytes());
+        }
+    }
+
+    out
+}
+
+pub struct Builder {
+    records: Vec<Rec>,
+    per_node: usize,
+    extra_toc: Vec<(String, u32)>,
+    stale: Vec<Rec>,
+    orphaned: Vec<Rec>,
+}
+
+struct Block {
+    address: usize,
+    log_size: u32,
+    data: Vec<u8>,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self {
+            records: Vec::new(),
+            per_node: usize::MAX,
+            extra_toc: Vec::new(),
+            stale: Vec::new(),
+            orphaned: Vec::new(),
+        }
+    }
+
+    pub fn record(mut self, record: Rec) -> Self {
+        self.records.push(record);
+        self
+    }
+
+    pub fn records(mut self, records: impl IntoIterator<Item = Rec>) -> Self {
+        self.records.extend(records);
+        self
+    }
+
+    /// Limits the number of records per B-tree node, forcing internal
+    /// nodes once the record count exceeds it.
+    pub fn per_node(mut self, per_node: usize) -> Self {
+        self.per_node = per_node;
+        self
+    }
+
+    /// Adds a table of contents entry after `DSDB`.
+    pub fn toc(mut self, name: &str, block_id: u32) -> Self {
+        self.extra_toc.push((name.into(), block_id));
+        self
+    }
+
+    /// Writes a leaf node holding `records` into a block that is left on the
+    /// free list, as if the records had been deleted.
+    pub fn stale(mut self, records: impl IntoIterator<Item = Rec>) -> Self {
+        self.stale.extend(records);
+        self
+    }
+
+    /// Writes a leaf node holding `records` into an allocated block that the
+    /// B-tree doesn't reference.
+    pub fn orphaned(mut self, records: impl IntoIterator<Item = Rec>) -> Self {
+        self.orphaned.extend(records);
+        self
+    }
+
+    pub fn build(self) -> Vec<u8> {
+        // Block 0 is the allocator info block, block 1 the DSDB master.
+        let mut nodes = Vec::<Vec<u8>>::new();
+        let records = self.records.iter().collect::<Vec<_>>();
+        let (root_node, levels) = build_node(&records, self.per_node, &mut nodes);
+
+        let mut master = Vec::new();
+        push_u32(&mut master, root_node as u32);
+        push_u32(&mut master, levels);
+        push_u32(&mut master, self.records.len() as u32);
+        push_u32(&mut master, nodes.len() as u32);
+        push_u32(&mut master, 0x1000);
+
+        let mut blocks = vec![
+            Block { address: 0x800, log_size: 11, data: Vec::new() },
+            Block { address: 0x20, log_size: 5, data: master },
+        ];
+
+        let mut cursor: usize = 0x1000;
+        for node in nodes {
+            let log_size = node.len().next_power_of_two().max(0x1000).trailing_zeros();
+            let size = 1usize << log_size;
+            let address = cursor.div_ceil(size) * size;
+            cursor = address + size;
+            blocks.push(Block { address, log_size, data: node });
+        }
+
+        if !self.orphaned.is_empty() {
+            let records = self.orphaned.iter().collect::<Vec<_>>();
+            let node = leaf(&records);
+            let address = cursor.div_ceil(0x1000) * 0x1000;
+            cursor = address + 0x1000;
+            blocks.push(Block { address, log_size: 12, data: node });
+        }
+
+        // 2 KiB at 0x800 holds 256 address slots. Larger tables move the
+        // allocator block behind the nodes.
+        let slots = blocks.len().div_ceil(256) * 256;
+        if slots > 256 {
+            let log_size = (8 + slots * 4 + 0x800).next_power_of_two().trailing_zeros();
+            let size = 1usize << log_size;
+            let address = cursor.div_ceil(size) * size;
+            cursor = address + size;
+            blocks[0].address = address;
+            blocks[0].log_size = log_size;
+        }
+
+        let mut allocated = blocks
+            .iter()
+            .map(|b| (b.address, b.log_size))
+            .collect::<Vec<_>>();
+        allocated.push((0, 5));
+
+        // Stale nodes get written to the file but aren't allocated.
+        let mut stale_blocks = Vec::new();
+        if !self.stale.is_empty() {
+            let records = self.stale.iter().collect::<Vec<_>>();
+            let address = cursor.div_ceil(0x1000) * 0x1000;
+            stale_blocks.push(Block { address, log_size: 12, data: leaf(&records) });
+        }
+
+        let mut free_lists = vec![Vec::<usize>::new(); 32];
+        collect_free(0, 31, &allocated, &mut free_lists);
+
+        let mut info = Vec::new();
+        push_u32(&mut info, blocks.len() as u32);
+        push_u32(&mut info, 0);
+        for block in blocks.iter() {
+            push_u32(&mut info, (block.address | block.log_size as usize) as u32);
+        }
+        for _ in blocks.len()..blocks.len().div_ceil(256) * 256 {
+            push_u32(&mut info, 0);
+        }
+        push_u32(&mut info, 1 + self.extra_toc.len() as u32);
+        info.push(4);
+        info.extend_from_slice(b"DSDB");
+        push_u32(&mut info, 1);
+        for (name, block_id) in self.extra_toc.iter() {
+            info.push(name.len() as u8);
+            info.extend_from_slice(name.as_bytes());
+            push_u32(&mut info, *block_id);
+        }
+        for list in free_lists.iter() {
+            push_u32(&mut info, list.len() as u32);
+            for address in list {
+                push_u32(&mut info, *address as u32);
+            }
+        }
+        blocks[0].data = info;
+
+        let end = blocks
+            .iter()
+            .chain(stale_blocks.iter())
+            .map(|b| b.address + (1 << b.log_size))
+            .max()
+            .unwrap();
+        let mut out = vec![0u8; end + 4];
+
+        out[0..4].copy_from_slice(&1u32.to_be_bytes());
+        out[4..8].copy_from_slice(b"Bud1");
+        let (root, root_size) = (blocks[0].address as u32, 1u32 << blocks[0].log_size);
+        out[8..12].copy_from_slice(&root.to_be_bytes());
+        out[12..16].copy_from_slice(&root_size.to_be_bytes());
+        out[16..20].copy_from_slice(&root.to_be_bytes());
+        // Finder fills the rest of the header with these unexplained bytes.
+        out[20..36].copy_from_slice(&UNKNOWN_HEADER_BYTES);
+
+        for block in blocks.iter().chain(stale_blocks.iter()) {
+            assert!(block.data.len() <= 1 << block.log_size, "block overflow");
+            let start = block.address + 4;
+            out[start..start + block.data.len()].copy_from_slice(&block.data);
+        }
+
+        out
+    }
+}
+
+fn leaf(records: &[&Rec]) -> Vec<u8> {
+    let mut node = Vec::new();
+    push_u32(&mut node, 0);
+    push_u32(&mut node, records.len() as u32);
+    for record in records {
+        node.extend(encode_record(record));
+    }
+    node
+}
+
+/// Writes the node for `records` (and any children it needs) into `nodes`,
+/// returning its block id and the height of the subtree.
+fn build_node(records: &[&Rec], per_node: usize, nodes: &mut Vec<Vec<u8>>) -> (usize, u32) {
+    let id = nodes.len() + 2;
+    nodes.push(Vec::new());
+
+    let mut node = Vec::new();
+
+    if records.len() <= per_node {
+        nodes[id - 2] = leaf(records);
+        return (id, 1);
+    }
+
+    // Spread the records over `per_node + 1` subtrees separated by
+    // `per_node` records, which keeps the tree roughly balanced.
+    let separator_count = per_node;
+    let group_total = records.len() - separator_count;
+    let mut children = Vec::new();
+    let mut separators = Vec::new();
+    let mut rest = records;
+    let mut height = 0;
+
+    for i in 0..separator_count {
+        let group = group_total / (separator_count + 1)
+            + usize::from(i < group_total % (separator_count + 1));
+        let (child, child_height) = build_node(&rest[..group], per_node, nodes);
+        children.push(child);
+        separators.push(rest[group]);
+        height = height.max(child_height);
+        rest = &rest[group + 1..];
+    }
+
+    let (last, last_height) = build_node(rest, per_node, nodes);
+    height = height.max(last_height);
+
+    push_u32(&mut node, last as u32);
+    push_u32(&mut node, separators.len() as u32);
+    for (child, separator) in children.iter().zip(separators) {
+        push_u32(&mut node, *child as u32);
+        node.extend(encode_record(separator));
+    }
+    nodes[id - 2] = node;
+
+    (id, height + 1)
+}
+
+fn collect_free(address: usize, log_size: u32, allocated: &[(usize, u32)], lists: &mut [Vec<usize>]) {
+    if allocated.contains(&(address, log_size)) {
+        return;
+    }
+
+    let end = address + (1usize << log_size);
+    if !allocated.iter().any(|(a, _)| *a >= address && *a < end) {
+        lists[log_size as usize].push(address);
+        return;
+    }
+
+    let half = 1usize << (log_size - 1);
+    collect_free(address, log_size - 1, allocated, lists);
+    collect_free(address + half, log_size - 1, allocated, lists);
+}
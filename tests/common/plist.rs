@@ -0,0 +1,105 @@
+// Binary plist encoder covering the object types Finder writes.
+
+pub enum P {
+    Bool(bool),
+    Int(i64),
+    Real(f64),
+    Str(String),
+    Data(Vec<u8>),
+    Array(Vec<P>),
+    Dict(Vec<(String, P)>),
+}
+
+pub fn dict(entries: Vec<(&str, P)>) -> P {
+    P::Dict(entries.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+}
+
+pub fn s(value: &str) -> P {
+    P::Str(value.into())
+}
+
+fn push_length(out: &mut Vec<u8>, marker: u8, length: usize) {
+    if length < 0x0f {
+        out.push(marker | length as u8);
+    } else {
+        out.push(marker | 0x0f);
+        out.push(0x11);
+        out.extend_from_slice(&(length as u16).to_be_bytes());
+    }
+}
+
+fn add(value: &P, objects: &mut Vec<Vec<u8>>) -> usize {
+    let mut out = Vec::new();
+
+    match value {
+        P::Bool(b) => out.push(if *b { 0x09 } else { 0x08 }),
+        P::Int(i) if (0..256).contains(i) => out.extend_from_slice(&[0x10, *i as u8]),
+        P::Int(i) => {
+            out.push(0x13);
+            out.extend_from_slice(&i.to_be_bytes());
+        }
+        P::Real(r) => {
+            out.push(0x23);
+            out.extend_from_slice(&r.to_be_bytes());
+        }
+        P::Str(text) if text.is_ascii() => {
+            push_length(&mut out, 0x50, text.len());
+            out.extend_from_slice(text.as_bytes());
+        }
+        P::Str(text) => {
+            let units = text.encode_utf16().collect::<Vec<_>>();
+            push_length(&mut out, 0x60, units.len());
+            for unit in units {
+                out.extend_from_slice(&unit.to_be_bytes());
+            }
+        }
+        P::Data(data) => {
+            push_length(&mut out, 0x40, data.len());
+            out.extend_from_slice(data);
+        }
+        P::Array(items) => {
+            let refs = items.iter().map(|i| add(i, objects)).collect::<Vec<_>>();
+            push_length(&mut out, 0xa0, refs.len());
+            out.extend(refs.iter().map(|r| *r as u8));
+        }
+        P::Dict(entries) => {
+            let keys = entries
+                .iter()
+                .map(|(k, _)| add(&P::Str(k.clone()), objects))
+                .collect::<Vec<_>>();
+            let values = entries.iter().map(|(_, v)| add(v, objects)).collect::<Vec<_>>();
+            push_length(&mut out, 0xd0, keys.len());
+            out.extend(keys.iter().map(|r| *r as u8));
+            out.extend(values.iter().map(|r| *r as u8));
+        }
+    }
+
+    objects.push(out);
+    assert!(objects.len() < 256, "too many plist objects");
+    objects.len() - 1
+}
+
+pub fn encode(root: &P) -> Vec<u8> {
+    let mut objects = Vec::new();
+    let top = add(root, &mut objects);
+
+    let mut out = b"bplist00".to_vec();
+    let mut offsets = Vec::new();
+    for object in objects.iter() {
+        offsets.push(out.len() as u16);
+        out.extend_from_slice(object);
+    }
+
+    let table_offset = out.len() as u64;
+    for offset in offsets {
+        out.extend_from_slice(&offset.to_be_bytes());
+    }
+
+    out.extend_from_slice(&[0; 6]);
+    out.push(2);
+    out.push(1);
+    out.extend_from_slice(&(objects.len() as u64).to_be_bytes());
+    out.extend_from_slice(&(top as u64).to_be_bytes());
+    out.extend_from_slice(&table_offset.to_be_bytes());
+    out
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use common::{rec, vsrn, Builder, Data};
+use ds_store_parser::{Change, DsStoreParser, FindOptions, Value};
+
+#[test]
+fn diffs_added_removed_and_changed_records() {
+    let old = Builder::new()
+        .record(rec("a.txt", b"cmmt", Data::Ustr("old".into())))
+        .record(vsrn("b.txt"))
+        .build();
+    let new = Builder::new()
+        .record(rec("a.txt", b"cmmt", Data::Ustr("new".into())))
+        .record(rec("c.txt", b"dscl", Data::Bool(true)))
+        .build();
+
+    let parser = DsStoreParser::new();
+    let changes = parser
+        .parse_bytes(&old)
+        .unwrap()
+        .diff(&parser.parse_bytes(&new).unwrap());
+
+    let lines = changes.iter().map(Change::to_string).collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        vec![
+            "~ a.txt [cmmt] old -> new",
+            "- b.txt [vSrn] 1",
+            "+ c.txt [dscl] true",
+        ]
+    );
+}
+
+#[test]
+fn identical_files_have_no_changes() {
+    let buf = Builder::new()
+        .record(vsrn("a.txt"))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    assert!(ds_store.diff(&ds_store).is_empty());
+}
+
+#[test]
+fn matches_names_by_case_and_normalization_when_asked() {
+    let old = Builder::new()
+        .record(rec("README.md", b"cmmt", Data::Ustr("docs".into())))
+        .record(rec("re\u{301}sume\u{301}.pdf", b"cmmt", Data::Ustr("cv".into())))
+        .build();
+    let new = Builder::new()
+        .record(rec("readme.md", b"cmmt", Data::Ustr("docs".into())))
+        .record(rec("r\u{e9}sum\u{e9}.pdf", b"cmmt", Data::Ustr("cv 2".into())))
+        .build();
+
+    let parser = DsStoreParser::new();
+    let (old, new) = (parser.parse_bytes(&old).unwrap(), parser.parse_bytes(&new).unwrap());
+
+    assert_eq!(old.diff(&new).len(), 4);
+
+    let options = FindOptions { ignore_case: true, nfc: true, ..FindOptions::default() };
+    assert_eq!(
+        old.diff_with(&new, options),
+        vec![Change::Changed {
+            path: "r\u{e9}sum\u{e9}.pdf".into(),
+            structure_id: *b"cmmt",
+            old: Value::Ustr("cv".into()),
+            new: Value::Ustr("cv 2".into()),
+        }]
+    );
+}
@@ -0,0 +1,215 @@
+use ds_store_parser::{DisplayOptions, DsStore};
+
+fn nested(depth: usize) -> DsStore {
+    let mut node = DsStore::new("leaf");
+
+    for i in (0..depth).rev() {
+        let mut parent = DsStore::new(&format!("dir_{}", i));
+        parent.push_child(node);
+        node = parent;
+    }
+
+    node
+}
+
+#[test]
+fn prints_deeply_nested_tree() {
+    let ds_store = nested(2_000);
+    let rendered = ds_store.to_string();
+
+    assert_eq!(rendered.lines().count(), 2_001);
+    ds_store.print();
+}
+
+#[test]
+fn renders_indented_tree() {
+    let mut root = DsStore::new("DSDB");
+    let mut dir = DsStore::new("photos");
+    dir.push_child(DsStore::new("beach.jpg"));
+    root.push_child(dir);
+    root.push_child(DsStore::new("notes.txt"));
+
+    assert_eq!(
+        root.to_string(),
+        "DSDB\n    photos:\n        beach.jpg\n    notes.txt\n"
+    );
+}
+
+#[test]
+fn honors_indent_length_at_every_level() {
+    let mut root = DsStore::new("DSDB");
+    let mut dir = DsStore::new("photos");
+    dir.push_child(DsStore::new("beach.jpg"));
+    root.push_child(dir);
+
+    root.set_indent_length(2);
+    assert_eq!(root.to_string(), "DSDB\n  photos:\n    beach.jpg\n");
+
+    root.set_indent_length(0);
+    assert_eq!(root.to_string(), "DSDB\nphotos:\nbeach.jpg\n");
+}
+
+#[test]
+fn renders_graphviz() {
+    let mut root = DsStore::new("DSDB");
+    let mut dir = DsStore::new("dir");
+    dir.push_child(DsStore::new("say \"hi\"\\.txt"));
+    root.push_child(dir);
+    root.push_child(DsStore::new("a.txt"));
+
+    assert_eq!(
+        root.to_dot().lines().collect::<Vec<_>>(),
+        vec![
+            r#"digraph ds_store {"#,
+            r#"    n0 [label="DSDB", shape=box];"#,
+            r#"    n1 [label="dir", shape=box];"#,
+            r#"    n0 -> n1;"#,
+            r#"    n2 [label="say \"hi\"\\.txt", shape=ellipse];"#,
+            r#"    n1 -> n2;"#,
+            r#"    n3 [label="a.txt", shape=ellipse];"#,
+            r#"    n0 -> n3;"#,
+            r#"}"#,
+        ]
+    );
+}
+
+#[test]
+fn renders_tree_connectors() {
+    let mut root = DsStore::new("DSDB");
+    let mut a = DsStore::new("a");
+    let mut b = DsStore::new("b");
+    b.push_child(DsStore::new("c"));
+    a.push_child(b);
+    a.push_child(DsStore::new("d"));
+    root.push_child(a);
+    let mut e = DsStore::new("e");
+    e.push_child(DsStore::new("f"));
+    root.push_child(e);
+
+    let options = DisplayOptions { connectors: true, ..Default::default() };
+    assert_eq!(
+        root.display(options).to_string().lines().collect::<Vec<_>>(),
+        vec![
+            "DSDB",
+            "├── a",
+            "│   ├── b",
+            "│   │   └── c",
+            "│   └── d",
+            "└── e",
+            "    └── f",
+        ]
+    );
+}
+
+#[test]
+fn stops_at_max_depth() {
+    let mut root = DsStore::new("DSDB");
+    let mut a = DsStore::new("a");
+    let mut b = DsStore::new("b");
+    b.push_child(DsStore::new("c"));
+    a.push_child(b);
+    a.push_child(DsStore::new("d"));
+    root.push_child(a);
+    root.push_child(DsStore::new("e"));
+
+    let render = |max_depth, connectors| {
+        let options = DisplayOptions { max_depth: Some(max_depth), connectors, ..Default::default() };
+        root.display(options).to_string()
+    };
+
+    assert_eq!(
+        render(2, false).lines().collect::<Vec<_>>(),
+        vec!["DSDB", "    a:", "        b …", "        d", "    e"]
+    );
+    assert_eq!(
+        render(1, true).lines().collect::<Vec<_>>(),
+        vec!["DSDB", "├── a …", "└── e"]
+    );
+    assert_eq!(render(0, false), "DSDB …\n");
+    assert_eq!(render(3, false), root.to_string());
+}
+
+#[test]
+fn sorts_children_case_insensitively() {
+    let mut root = DsStore::new("DSDB");
+    let mut dir = DsStore::new("b");
+    dir.push_child(DsStore::new("Z"));
+    dir.push_child(DsStore::new("y"));
+    root.push_child(dir);
+    root.push_child(DsStore::new("C"));
+    root.push_child(DsStore::new("a"));
+
+    root.sort_children();
+
+    assert_eq!(
+        root.to_string().lines().collect::<Vec<_>>(),
+        vec!["DSDB", "    a", "    b:", "        y", "        Z", "    C"]
+    );
+}
+
+#[test]
+fn walks_nodes_in_pre_order() {
+    let mut root = DsStore::new("DSDB");
+    let mut a = DsStore::new("a");
+    let mut b = DsStore::new("b");
+    b.push_child(DsStore::new("c"));
+    a.push_child(b);
+    a.push_child(DsStore::new("d"));
+    root.push_child(a);
+    root.push_child(DsStore::new("e"));
+
+    let mut visited = Vec::new();
+    root.walk(&mut |path, node| visited.push((path.join("/"), node.children().len())));
+
+    assert_eq!(
+        visited,
+        [("", 2), ("a", 2), ("a/b", 1), ("a/b/c", 0), ("a/d", 0), ("e", 0)]
+            .map(|(path, children)| (path.to_string(), children))
+    );
+}
+
+#[test]
+fn walks_deeply_nested_tree() {
+    let mut deepest = 0;
+    nested(2_000).walk(&mut |path, _| deepest = deepest.max(path.len()));
+
+    assert_eq!(deepest, 2_000);
+}
+
+#[test]
+fn prefixes_paths_with_containing_directory() {
+    let mut root = DsStore::new("DSDB");
+    root.push_child(DsStore::new("."));
+    root.push_child(DsStore::new("final.psd"));
+
+    root.prefix_paths("");
+    assert_eq!(root.children()[1].name(), "final.psd");
+
+    root.prefix_paths("secret_project/designs/");
+    let names = root.children().iter().map(|c| c.name()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["secret_project/designs", "secret_project/designs/final.psd"]);
+}
+
+#[test]
+fn grafts_subtrees_below_their_folder() {
+    let mut root = DsStore::new("DSDB");
+    root.push_child(DsStore::new("Photos"));
+    root.push_child(DsStore::new("Photos"));
+
+    let mut photos = DsStore::new("DSDB");
+    photos.push_child(DsStore::new("IMG_0001.jpg"));
+    let mut music = DsStore::new("DSDB");
+    music.push_child(DsStore::new("song.mp3"));
+
+    root.graft("Photos", photos);
+    root.graft("Music", music);
+
+    let mut paths = Vec::new();
+    root.walk(&mut |path, _| paths.push(path.join("/")));
+    assert_eq!(
+        paths,
+        vec!["", "Photos", "Photos/IMG_0001.jpg", "Photos", "Music", "Music/song.mp3"]
+    );
+    assert!(root.children()[0].is_folder());
+    assert!(!root.children()[1].is_folder());
+}
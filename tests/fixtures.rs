@@ -0,0 +1,266 @@
+// Tests on the synthetic files in `fixtures/`. They come from the test builder,
+// not from Finder, so they pin down this crate's reading of the format rather
+// than check it against real captures.
+
+use ds_store_parser::{
+    DisplayOptions, DsStore, DsStoreParser, IconLocation, Value, KNOWN_STRUCTURE_IDS,
+};
+
+fn parse(buf: &[u8]) -> DsStore {
+    DsStoreParser::new().parse_bytes(buf).unwrap()
+}
+
+fn ids(ds_store: &DsStore) -> Vec<(String, String)> {
+    ds_store
+        .iter()
+        .map(|(path, record)| (path, record.structure_id_str()))
+        .collect()
+}
+
+fn at(x: i32, y: i32) -> IconLocation {
+    IconLocation { x: Some(x), y: Some(y) }
+}
+
+#[test]
+fn parses_empty_folder() {
+    let buf = include_bytes!("fixtures/empty.DS_Store");
+    let (ds_store, report) = DsStoreParser::new().parse_bytes_with_report(buf).unwrap();
+
+    assert_eq!(ds_store.name(), "DSDB");
+    assert!(ds_store.children().is_empty());
+    assert_eq!(report.record_count, 0);
+    assert_eq!(report.node_count, 1);
+    assert!(report.warnings.is_empty());
+}
+
+#[test]
+fn parses_icon_view_folder() {
+    let ds_store = parse(include_bytes!("fixtures/icon_view.DS_Store"));
+
+    assert_eq!(
+        ids(&ds_store),
+        [
+            (".", "bwsp"),
+            (".", "icvp"),
+            (".", "vSrn"),
+            (".", "vstl"),
+            ("notes.txt", "Iloc"),
+            ("notes.txt", "cmmt"),
+            ("Photos", "Iloc"),
+            ("Report.pdf", "Iloc"),
+        ]
+        .map(|(p, i)| (p.to_string(), i.to_string()))
+    );
+
+    let children = ds_store.children();
+    assert_eq!(children[2].view_settings_version(), Some(1));
+    assert_eq!(children[3].records()[0].value(), &Value::Type(*b"icnv"));
+    assert_eq!(children[3].view_settings_version(), None);
+    assert_eq!(children[4].records()[0].icon_location(), Some(at(440, 60)));
+    assert_eq!(children[5].comment(), Some("Quarterly numbers – draft"));
+    assert_eq!(children[6].records()[0].icon_location(), Some(at(300, 60)));
+    assert_eq!(children[7].records()[0].icon_location(), Some(at(160, 60)));
+}
+
+#[cfg(feature = "plist")]
+#[test]
+fn decodes_icon_view_plists() {
+    let ds_store = parse(include_bytes!("fixtures/icon_view.DS_Store"));
+    let records = ds_store
+        .children()
+        .iter()
+        .flat_map(|c| c.records())
+        .collect::<Vec<_>>();
+
+    let window = records[0].window_settings().unwrap();
+    assert_eq!(window.window_bounds.as_deref(), Some("{{120, 240}, {920, 436}}"));
+    assert_eq!(window.sidebar_width, Some(176.0));
+    assert_eq!(window.show_status_bar, Some(false));
+
+    let icons = records[1].icon_view_settings().unwrap();
+    assert_eq!(icons.icon_size, Some(64.0));
+    assert_eq!(icons.grid_spacing, Some(54.0));
+    assert_eq!(icons.label_on_bottom, Some(true));
+}
+
+#[test]
+fn parses_list_view_folder() {
+    let ds_store = parse(include_bytes!("fixtures/list_view.DS_Store"));
+    let children = ds_store.children();
+
+    assert_eq!(ds_store.paths(), vec![".", "Archive", "Projects"]);
+    assert_eq!(children[2].records()[0].value(), &Value::Type(*b"Nlsv"));
+    assert_eq!(children[3].expanded(), Some(false));
+    assert_eq!(children[4].modified(), Some(1_614_859_200));
+    assert_eq!(children[5].expanded(), Some(true));
+    assert_eq!(children[6].records()[0].value(), &Value::Comp(1_048_576));
+    assert_eq!(children[7].modified(), Some(1_614_862_800));
+}
+
+#[cfg(feature = "plist")]
+#[test]
+fn decodes_list_view_plist() {
+    let ds_store = parse(include_bytes!("fixtures/list_view.DS_Store"));
+    let settings = ds_store.children()[0].records()[0].list_view_settings().unwrap();
+
+    assert_eq!(settings.sort_column.as_deref(), Some("dateModified"));
+    assert_eq!(settings.sort_ascending, Some(false));
+
+    let widths = settings
+        .columns
+        .iter()
+        .map(|c| (c.name.as_str(), c.width))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        widths,
+        vec![("dateModified", Some(181.0)), ("name", Some(300.0)), ("size", Some(97.0))]
+    );
+}
+
+#[test]
+fn parses_multi_level_tree() {
+    let buf = include_bytes!("fixtures/nested.DS_Store");
+    let parser = DsStoreParser::new();
+    let (ds_store, report) = parser.parse_bytes_with_report(buf).unwrap();
+
+    assert_eq!(report.record_count, 13);
+    assert_eq!(report.node_count, 5);
+    assert!(report.warnings.is_empty());
+    assert_eq!(parser.read_master_block(buf).unwrap().levels, 2);
+
+    let mut expected = vec![".".to_string()];
+    expected.extend((0..12).map(|i| format!("IMG_{:04}.jpg", i)));
+    assert_eq!(ds_store.paths(), expected);
+
+    let locations = ds_store.children()[1..]
+        .iter()
+        .map(|c| c.records()[0].icon_location().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(locations[0], at(80, 60));
+    assert_eq!(locations[11], at(380, 260));
+}
+
+#[test]
+fn keeps_descriptor_and_unknown_records() {
+    let ds_store = parse(include_bytes!("fixtures/descriptors.DS_Store"));
+
+    assert_eq!(
+        ids(&ds_store),
+        [
+            (".", "vSrn"),
+            ("Clipping.textClipping", "clip"),
+            ("Clipping.textClipping", "extn"),
+            ("Invoice", "Xtra"),
+            ("Invoice", "extn"),
+            ("Invoice", "fdsc"),
+            ("Notes.rtf", "fdsc"),
+        ]
+        .map(|(p, i)| (p.to_string(), i.to_string()))
+    );
+
+    let children = ds_store.children();
+    assert_eq!(children[1].records()[0].value(), &Value::Blob(vec![0, 0, 0, 1, 0, 0, 0, 0]));
+    assert_eq!(children[2].extension(), Some("textClipping"));
+    assert_eq!(
+        children[3].records()[0].value(),
+        &Value::Unknown { id: *b"Xtra", raw: vec![0, 0, 0, 7] }
+    );
+    assert_eq!(children[4].extension(), Some("pdf"));
+    assert_eq!(children[5].records()[0].value(), &Value::Bool(true));
+    assert_eq!(children[5].extension(), None);
+    assert_eq!(children[6].records()[0].value(), &Value::Type(*b"RTF "));
+
+    for id in ["clip", "extn", "fdsc"] {
+        assert!(KNOWN_STRUCTURE_IDS.contains(&id));
+    }
+    assert!(!KNOWN_STRUCTURE_IDS.contains(&"Xtra"));
+}
+
+#[test]
+fn leaves_unset_icon_coordinates_empty() {
+    let ds_store = parse(include_bytes!("fixtures/positions.DS_Store"));
+    let locations = ds_store
+        .iter()
+        .map(|(path, r)| (path, r.icon_location()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        locations,
+        [
+            (".", None),
+            ("Centered.app", Some(IconLocation { x: None, y: Some(120) })),
+            ("Free.txt", Some(IconLocation { x: None, y: None })),
+            ("Pinned.pdf", Some(at(200, 120))),
+            ("Row.png", Some(IconLocation { x: Some(340), y: None })),
+        ]
+        .map(|(p, l)| (p.to_string(), l))
+    );
+}
+
+#[test]
+fn checks_for_names_without_parsing() {
+    let buf = include_bytes!("fixtures/nested.DS_Store");
+    let parser = DsStoreParser::new();
+
+    for name in [".", "IMG_0000.jpg", "IMG_0006.jpg", "IMG_0011.jpg"] {
+        assert!(parser.contains(buf, name).unwrap(), "{}", name);
+    }
+    for name in ["IMG_0012.jpg", "img_0000.jpg", "IMG_0000", ""] {
+        assert!(!parser.contains(buf, name).unwrap(), "{}", name);
+    }
+
+    let icon_view = include_bytes!("fixtures/icon_view.DS_Store");
+    assert!(parser.contains(icon_view, "Report.pdf").unwrap());
+    assert!(parser.contains(&icon_view[..0x800], "Report.pdf").is_err());
+}
+
+// The tree with structure ids and offsets, followed by the table of values.
+fn render(ds_store: &DsStore) -> String {
+    let options = DisplayOptions { show_ids: true, show_offsets: true, ..Default::default() };
+    let mut table = Vec::new();
+    ds_store.write_table(&mut table, usize::MAX).unwrap();
+
+    format!("{}\n{}", ds_store.display(options), String::from_utf8(table).unwrap())
+}
+
+// Each fixture renders exactly as its checked-in `.expected` file, so a
+// change to how blocks, records or values are read shows up as a diff.
+#[test]
+fn renders_fixtures_as_expected() {
+    let fixtures: [(&str, &[u8], &str); 6] = [
+        (
+            "descriptors",
+            include_bytes!("fixtures/descriptors.DS_Store"),
+            include_str!("fixtures/descriptors.expected"),
+        ),
+        (
+            "empty",
+            include_bytes!("fixtures/empty.DS_Store"),
+            include_str!("fixtures/empty.expected"),
+        ),
+        (
+            "icon_view",
+            include_bytes!("fixtures/icon_view.DS_Store"),
+            include_str!("fixtures/icon_view.expected"),
+        ),
+        (
+            "list_view",
+            include_bytes!("fixtures/list_view.DS_Store"),
+            include_str!("fixtures/list_view.expected"),
+        ),
+        (
+            "nested",
+            include_bytes!("fixtures/nested.DS_Store"),
+            include_str!("fixtures/nested.expected"),
+        ),
+        (
+            "positions",
+            include_bytes!("fixtures/positions.DS_Store"),
+            include_str!("fixtures/positions.expected"),
+        ),
+    ];
+
+    for (name, buf, expected) in fixtures {
+        assert_eq!(render(&parse(buf)), expected, "{}", name);
+    }
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "gzip")]
+
+mod common;
+
+use common::{vsrn, Builder};
+use ds_store_parser::{DsStoreError, DsStoreParser};
+
+// Compressed with `gzip -9`, so it holds a dynamic Huffman block and a
+// file name in the header.
+const SAMPLE: &[u8] = include_bytes!("fixtures/sample.DS_Store.gz");
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+
+    !crc
+}
+
+// Wraps `data` in a gzip member made of uncompressed (stored) blocks.
+fn gzip_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 0xff];
+    let chunks = data.chunks(0x1000).collect::<Vec<_>>();
+
+    for (i, chunk) in chunks.iter().enumerate() {
+        out.push((i == chunks.len() - 1) as u8);
+        out.extend((chunk.len() as u16).to_le_bytes());
+        out.extend((!(chunk.len() as u16)).to_le_bytes());
+        out.extend(*chunk);
+    }
+
+    out.extend(crc32(data).to_le_bytes());
+    out.extend((data.len() as u32).to_le_bytes());
+    out
+}
+
+#[test]
+fn parses_stored_gzip() {
+    let buf = Builder::new()
+        .record(vsrn("."))
+        .record(vsrn("notes.txt"))
+        .build();
+
+    let parser = DsStoreParser::new();
+    let plain = parser.parse_bytes(&buf).unwrap();
+    let compressed = parser.parse_bytes(&gzip_stored(&buf)).unwrap();
+
+    assert_eq!(compressed.to_string(), plain.to_string());
+}
+
+#[test]
+fn parses_compressed_fixture() {
+    let ds_store = DsStoreParser::new().parse_bytes(SAMPLE).unwrap();
+
+    let names = ds_store
+        .children()
+        .iter()
+        .map(|c| c.name())
+        .collect::<Vec<_>>();
+
+    assert_eq!(names, vec![".", "a, \"b\".txt", "a, \"b\".txt", "dir", "dir", "z.bin", "z.bin"]);
+}
+
+#[test]
+fn rejects_checksum_mismatch() {
+    let mut buf = gzip_stored(&Builder::new().record(vsrn(".")).build());
+    let crc = buf.len() - 8;
+    buf[crc] ^= 0xff;
+
+    assert!(matches!(
+        DsStoreParser::new().parse_bytes(&buf),
+        Err(DsStoreError::InvalidGzip { reason: "checksum mismatch" })
+    ));
+}
+
+#[test]
+fn rejects_truncated_stream() {
+    for length in [2, 10, 40, SAMPLE.len() / 2, SAMPLE.len() - 1] {
+        assert!(
+            matches!(
+                DsStoreParser::new().parse_bytes(&SAMPLE[..length]),
+                Err(DsStoreError::InvalidGzip { .. })
+            ),
+            "length {}",
+            length
+        );
+    }
+}
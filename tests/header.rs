@@ -0,0 +1,142 @@
+mod common;
+
+use common::{vsrn, Builder, UNKNOWN_HEADER_BYTES};
+use ds_store_parser::{Bud1Header, DsStoreError, DsStoreParser, TocEntry};
+
+#[test]
+fn reads_table_of_contents_and_free_lists() {
+    let buf = Builder::new()
+        .record(vsrn("a.txt"))
+        .toc("TEST", 7)
+        .build();
+
+    let header = DsStoreParser::new().read_buddy_header(&buf).unwrap();
+
+    assert_eq!(header.block_addresses, vec![0x0000_080b, 0x0000_0025, 0x0000_100c]);
+    assert_eq!(
+        header.toc,
+        vec![
+            TocEntry { name: "DSDB".into(), block_id: 1 },
+            TocEntry { name: "TEST".into(), block_id: 7 },
+        ]
+    );
+    assert_eq!(header.toc_block("DSDB"), Some(1));
+    assert_eq!(header.free_lists.len(), 32);
+
+    // Free and allocated blocks together cover the whole 2 GiB address space.
+    let free = header
+        .free_lists
+        .iter()
+        .enumerate()
+        .map(|(log_size, list)| list.len() << log_size)
+        .sum::<usize>();
+    let allocated = 0x20 + header
+        .block_addresses
+        .iter()
+        .map(|a| 1usize << (a & 0x1f))
+        .sum::<usize>();
+
+    assert_eq!(free + allocated, 1 << 31);
+}
+
+#[test]
+fn reads_table_of_contents_after_more_than_256_addresses() {
+    let buf = Builder::new()
+        .records((0..300).map(|i| vsrn(&format!("{:03}", i))))
+        .per_node(1)
+        .build();
+    let parser = DsStoreParser::new();
+
+    let header = parser.read_buddy_header(&buf).unwrap();
+    assert!(header.block_addresses.len() > 256);
+    assert_eq!(header.toc, vec![TocEntry { name: "DSDB".into(), block_id: 1 }]);
+
+    assert_eq!(parser.parse_bytes(&buf).unwrap().iter().count(), 300);
+}
+
+#[test]
+fn finds_dsdb_anywhere_in_table_of_contents() {
+    let rename = |buf: &mut Vec<u8>, from: &[u8], to: &[u8]| {
+        let at = buf.windows(from.len()).position(|w| w == from).unwrap();
+        buf[at..at + to.len()].copy_from_slice(to);
+    };
+
+    let mut buf = Builder::new()
+        .record(vsrn("a.txt"))
+        .toc("XXXX", 1)
+        .build();
+    rename(&mut buf, b"\x04DSDB", b"\x04YYYY");
+    rename(&mut buf, b"\x04XXXX", b"\x04DSDB");
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    assert_eq!(ds_store.name(), "DSDB");
+    assert_eq!(ds_store.paths(), vec!["a.txt"]);
+
+    rename(&mut buf, b"\x04DSDB", b"\x04XXXX");
+    assert!(matches!(
+        DsStoreParser::new().parse_bytes(&buf),
+        Err(DsStoreError::MissingDsdb)
+    ));
+}
+
+// The block address table starts right after the count and an unused word
+// at 0x80c.
+fn corrupt_block_address(buf: &mut [u8], block_id: usize, address: u32) {
+    let offset = 0x804 + 8 + block_id * 4;
+    buf[offset..offset + 4].copy_from_slice(&address.to_be_bytes());
+}
+
+#[test]
+fn rejects_block_addresses_outside_the_file() {
+    let buf = Builder::new()
+        .record(vsrn("a.txt"))
+        .build();
+
+    for (block_id, address) in [
+        (0, 0x7fff_f00b),
+        (1, 0x0001_0005),
+        (2, 0x0000_200c),
+        (2, 0x4000_001e),
+        (2, 0xffff_ffff),
+    ] {
+        let mut corrupted = buf.clone();
+        corrupt_block_address(&mut corrupted, block_id, address);
+
+        match DsStoreParser::new().parse_bytes(&corrupted) {
+            Err(DsStoreError::BlockOutOfRange { block_id: bad, .. }) => assert_eq!(bad, block_id),
+            other => panic!("block {} = 0x{:x}: unexpected {:?}", block_id, address, other.map(|_| ())),
+        }
+    }
+}
+
+#[test]
+fn reads_bud1_header() {
+    let buf = Builder::new()
+        .record(vsrn("a.txt"))
+        .build();
+
+    assert_eq!(
+        DsStoreParser::new().read_header(&buf).unwrap(),
+        Bud1Header {
+            magic: *b"\x00\x00\x00\x01Bud1",
+            root_block_offset: 0x800,
+            root_block_size: 0x800,
+            root_block_offset_check: 0x800,
+            unknown: UNKNOWN_HEADER_BYTES,
+        }
+    );
+}
+
+#[test]
+fn reads_master_block() {
+    let buf = Builder::new()
+        .records((0..20).map(|i| vsrn(&format!("{:02}", i))))
+        .per_node(4)
+        .build();
+
+    let master = DsStoreParser::new().read_master_block(&buf).unwrap();
+
+    assert_eq!(master.levels, 2);
+    assert_eq!(master.records, 20);
+    assert_eq!(master.page_size, 0x1000);
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "fs")]
+
+mod common;
+
+use common::{vsrn, Builder};
+use ds_store_parser::{DsStoreError, DsStoreParser, MappedFile};
+
+#[test]
+fn parses_from_reader() {
+    let buf = Builder::new()
+        .record(vsrn("."))
+        .record(vsrn("notes.txt"))
+        .build();
+
+    let ds_store = DsStoreParser::new()
+        .parse_reader(std::io::Cursor::new(buf))
+        .unwrap();
+
+    assert_eq!(ds_store.children().len(), 2);
+}
+
+struct FailingReader;
+
+impl std::io::Read for FailingReader {
+    fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("stream reset"))
+    }
+}
+
+#[test]
+fn reports_reader_failure() {
+    assert!(matches!(
+        DsStoreParser::new().parse_reader(FailingReader),
+        Err(DsStoreError::Io(_))
+    ));
+}
+
+#[test]
+fn reports_missing_file() {
+    assert!(matches!(
+        DsStoreParser::new().parse("does/not/exist/.DS_Store"),
+        Err(DsStoreError::Io(_))
+    ));
+}
+
+#[test]
+fn parses_memory_mapped_file() {
+    let buf = Builder::new()
+        .record(vsrn("notes.txt"))
+        .build();
+    let path = std::env::temp_dir().join(format!("ds_store_parser_mmap_{}", std::process::id()));
+    std::fs::write(&path, &buf).unwrap();
+
+    // SAFETY: nothing else writes to the file while it's mapped.
+    let mapped = unsafe { MappedFile::open(&path) }.unwrap();
+    // SAFETY: as above.
+    let ds_store = unsafe { DsStoreParser::new().parse_mmap(&path) }.unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(&mapped[..], &buf[..]);
+    assert_eq!(ds_store.paths(), vec!["notes.txt"]);
+}
@@ -0,0 +1,364 @@
+mod common;
+
+use common::{rec, vsrn, Builder, Data};
+use ds_store_parser::{DsStoreError, DsStoreParser, Warning, WarningCode};
+
+#[test]
+fn parses_in_memory_buffer() {
+    let buf = Builder::new()
+        .record(vsrn("."))
+        .record(vsrn("notes.txt"))
+        .record(vsrn("photo.jpg"))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    let names = ds_store
+        .children()
+        .iter()
+        .map(|c| c.name())
+        .collect::<Vec<_>>();
+
+    assert_eq!(ds_store.name(), "DSDB");
+    assert_eq!(names, vec![".", "notes.txt", "photo.jpg"]);
+}
+
+#[test]
+fn rejects_buffer_without_signature() {
+    let buf = vec![0u8; 64];
+
+    assert!(matches!(
+        DsStoreParser::new().parse_bytes(&buf),
+        Err(DsStoreError::BadMagic { found, .. }) if found == [0; 4]
+    ));
+}
+
+#[test]
+fn warns_on_unexpected_leading_word() {
+    let mut buf = Builder::new().record(vsrn("a")).build();
+    buf[3] = 0x02;
+
+    let (ds_store, report) = DsStoreParser::new().parse_bytes_with_report(&buf).unwrap();
+    assert_eq!(ds_store.paths(), vec!["a"]);
+    assert_eq!(
+        report.warnings,
+        vec![Warning::new(
+            WarningCode::LeadingWord,
+            "Leading word does not match a DS_Store file: expected byte 0x01 at offset 3, got 0x02"
+        )]
+    );
+
+    assert!(matches!(
+        DsStoreParser::new().parse_strict(&buf).map(|_| ()),
+        Err(DsStoreError::BadSignature { offset: 3, expected: 0x01, found: 0x02 })
+    ));
+}
+
+#[test]
+fn rejects_buffer_shorter_than_signature() {
+    assert!(matches!(
+        DsStoreParser::new().parse_bytes(&[0, 0, 0]),
+        Err(DsStoreError::TooShort { length: 3 })
+    ));
+}
+
+#[test]
+fn reports_truncated_buffer() {
+    let mut buf = Builder::new()
+        .record(vsrn("notes.txt"))
+        .build();
+    buf.truncate(0x200);
+
+    assert!(matches!(
+        DsStoreParser::new().parse_bytes(&buf),
+        Err(DsStoreError::OffsetOutOfRange { .. })
+    ));
+}
+
+#[test]
+fn recognizes_apple_double_files() {
+    let mut buf = vec![0x00, 0x05, 0x16, 0x07, 0x00, 0x02, 0x00, 0x00];
+    buf.extend_from_slice(&[0; 16]);
+    buf.extend_from_slice(&[0x00, 0x01]);
+    // A single Finder info entry right after the header.
+    buf.extend_from_slice(&[0, 0, 0, 9, 0, 0, 0, 38, 0, 0, 0, 32]);
+    buf.extend_from_slice(b"TEXTttxt");
+    buf.extend_from_slice(&[0; 24]);
+
+    assert!(matches!(
+        DsStoreParser::new().parse_bytes(&buf),
+        Err(DsStoreError::AppleDouble { entries: 1 })
+    ));
+
+    let apple_double = ds_store_parser::AppleDouble::parse(&buf).unwrap();
+    assert_eq!(apple_double.type_and_creator(&buf), Some((*b"TEXT", *b"ttxt")));
+}
+
+#[test]
+fn reports_parse_statistics() {
+    let buf = Builder::new()
+        .record(vsrn("a"))
+        .record(vsrn("b"))
+        .build();
+
+    let (_, report) = DsStoreParser::new().parse_bytes_with_report(&buf).unwrap();
+
+    assert_eq!(report.record_count, 2);
+    assert_eq!(report.node_count, 1);
+    assert_eq!(report.bytes_parsed, 8 + 2 * 18);
+    assert!(report.warnings.is_empty());
+}
+
+#[test]
+fn warns_about_record_count_mismatch() {
+    let mut buf = Builder::new()
+        .record(vsrn("a"))
+        .build();
+    // The record count is the third word of the master block at 0x20.
+    buf[0x2c..0x30].copy_from_slice(&5u32.to_be_bytes());
+
+    let (_, report) = DsStoreParser::new().parse_bytes_with_report(&buf).unwrap();
+
+    assert_eq!(
+        report.warnings,
+        vec![Warning::new(
+            WarningCode::CountMismatch,
+            "Master block lists 5 records but 1 were found"
+        )]
+    );
+}
+
+#[test]
+fn keeps_partial_tree_in_lenient_mode() {
+    let mut buf = Builder::new()
+        .record(vsrn("a"))
+        .record(vsrn("b"))
+        .record(vsrn("c"))
+        .build();
+    // Cut the leaf off halfway through the third record.
+    buf.truncate(0x1038);
+
+    let parser = DsStoreParser::new();
+    assert!(parser.parse_bytes(&buf).is_err());
+
+    let (ds_store, report) = parser.parse_lenient(&buf).unwrap();
+    assert_eq!(ds_store.paths(), vec!["a", "b"]);
+    assert_eq!(report.record_count, 2);
+    assert_eq!(report.warnings[0].code(), WarningCode::StoppedEarly);
+    assert!(report.warnings[0].message().starts_with("Stopped reading the B-tree early"));
+}
+
+#[test]
+fn reports_duplicate_records() {
+    let buf = Builder::new()
+        .record(rec("a.txt", b"cmmt", Data::Ustr("first".into())))
+        .record(rec("a.txt", b"cmmt", Data::Ustr("second".into())))
+        .record(rec("a.txt", b"cmmt", Data::Ustr("third".into())))
+        .record(vsrn("a.txt"))
+        .record(rec("b.txt", b"cmmt", Data::Ustr("first".into())))
+        .build();
+
+    let parser = DsStoreParser::new();
+    let (ds_store, report) = parser.parse_bytes_with_report(&buf).unwrap();
+
+    assert_eq!(ds_store.children().len(), 5);
+    assert_eq!(
+        report.warnings,
+        vec![Warning::new(
+            WarningCode::DuplicateRecord,
+            "Entry \"a.txt\" has more than one cmmt record"
+        )]
+    );
+
+    assert!(matches!(
+        parser.parse_strict(&buf),
+        Err(DsStoreError::DuplicateRecord { name, structure_id: [b'c', b'm', b'm', b't'] })
+            if name == "a.txt"
+    ));
+}
+
+#[test]
+fn accepts_unique_records_in_strict_mode() {
+    let buf = Builder::new()
+        .record(rec("a.txt", b"cmmt", Data::Ustr("note".into())))
+        .record(vsrn("a.txt"))
+        .record(vsrn("b.txt"))
+        .build();
+
+    let (_, report) = DsStoreParser::new().parse_strict(&buf).unwrap();
+
+    assert!(report.warnings.is_empty());
+}
+
+#[test]
+fn parses_with_custom_signature() {
+    let mut buf = Builder::new().record(vsrn("a")).build();
+    buf[4..8].copy_from_slice(b"Bud2");
+
+    assert!(matches!(
+        DsStoreParser::new().parse_bytes(&buf),
+        Err(DsStoreError::BadMagic { found, .. }) if found == b"Bud2"
+    ));
+
+    let parser = DsStoreParser::new().with_signature(b"\x00\x00\x00\x01Bud2");
+    assert_eq!(parser.parse_bytes(&buf).unwrap().paths(), vec!["a"]);
+}
+
+#[test]
+fn survives_nonstandard_layouts() {
+    let buf = Builder::new().record(vsrn("a")).record(vsrn("b")).build();
+
+    for padding in [0, 1, 0x80, 0x200, usize::MAX] {
+        let _ = DsStoreParser::new().with_index_padding(padding).parse_bytes(&buf);
+    }
+
+    let parser = DsStoreParser::new()
+        .with_root_offset_locations(0x08, 0x10)
+        .with_index_padding(0x100);
+    assert_eq!(parser.parse_bytes(&buf).unwrap().paths(), vec!["a", "b"]);
+}
+
+#[test]
+fn accepts_root_offset_stored_once() {
+    let mut buf = Builder::new().record(vsrn("a")).build();
+    buf[0x10..0x14].copy_from_slice(&[0; 4]);
+
+    let (ds_store, report) = DsStoreParser::new().parse_bytes_with_report(&buf).unwrap();
+
+    assert_eq!(ds_store.paths(), vec!["a"]);
+    assert_eq!(
+        report.warnings,
+        vec![Warning::new(
+            WarningCode::RootOffsetCopyMissing,
+            "Root block offset is stored only once, its copy is zero"
+        )]
+    );
+}
+
+#[test]
+fn rejects_mismatched_root_offsets() {
+    let mut buf = Builder::new().record(vsrn("a")).build();
+    buf[0x10..0x14].copy_from_slice(&0x1000u32.to_be_bytes());
+
+    assert!(matches!(
+        DsStoreParser::new().parse_bytes(&buf),
+        Err(DsStoreError::RootOffsetMismatch { a: 0x804, b: 0x1004 })
+    ));
+}
+
+#[test]
+fn reports_error_offsets() {
+    let buf = Builder::new().record(vsrn("a")).build();
+    let parser = DsStoreParser::new();
+
+    let error = parser.parse_bytes(&buf[..0x900]).err().unwrap();
+    assert_eq!(error.offset(), Some(0xc0c));
+
+    let error = parser.parse_bytes(b"\x00\x00\x00\x01Bud2").err().unwrap();
+    assert_eq!(error.offset(), Some(7));
+
+    assert_eq!(parser.parse_bytes(&[]).err().unwrap().offset(), None);
+}
+
+#[test]
+fn rejects_invalid_names_only_when_asked() {
+    let mut buf = Builder::new().record(vsrn("ab")).record(vsrn("\u{fffd}")).build();
+    // The first record starts at 0x100c; make its first code unit a lone
+    // high surrogate.
+    buf[0x1010..0x1012].copy_from_slice(&[0xd8, 0x00]);
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    assert_eq!(ds_store.paths(), vec!["\u{fffd}b", "\u{fffd}"]);
+
+    match DsStoreParser::new().with_strict_names(true).parse_bytes(&buf) {
+        Err(DsStoreError::InvalidName { offset, name }) => {
+            assert_eq!(offset, 0x100c);
+            assert_eq!(name, "\u{fffd}b");
+        }
+        other => panic!("{:?}", other.map(|_| ())),
+    }
+
+    // A literal replacement character is valid.
+    let buf = Builder::new().record(vsrn("\u{fffd}")).build();
+    assert!(DsStoreParser::new().with_strict_names(true).parse_bytes(&buf).is_ok());
+}
+
+#[test]
+fn rejects_empty_input() {
+    assert!(matches!(
+        DsStoreParser::new().parse_bytes(&[]),
+        Err(DsStoreError::Empty)
+    ));
+}
+
+#[test]
+fn rejects_signature_only_input() {
+    let buf = [0x00, 0x00, 0x00, 0x01, b'B', b'u', b'd', b'1'];
+
+    assert!(matches!(
+        DsStoreParser::new().parse_bytes(&buf),
+        Err(DsStoreError::Truncated { length: 8 })
+    ));
+    assert!(matches!(
+        DsStoreParser::new().read_header(&buf),
+        Err(DsStoreError::Truncated { length: 8 })
+    ));
+}
+
+#[test]
+fn parses_file_without_records() {
+    let buf = Builder::new().build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+
+    assert_eq!(ds_store.name(), "DSDB");
+    assert!(ds_store.children().is_empty());
+}
+
+#[test]
+fn reports_trailing_data_after_the_allocated_blocks() {
+    let mut buf = Builder::new().record(vsrn("a")).build();
+    let length = buf.len();
+
+    let (_, report) = DsStoreParser::new().parse_bytes_with_report(&buf).unwrap();
+    assert_eq!(report.allocated_length, length);
+    assert!(report.warnings.is_empty());
+
+    buf.extend_from_slice(b"appended");
+
+    let (ds_store, report) = DsStoreParser::new().parse_bytes_with_report(&buf).unwrap();
+    assert_eq!(ds_store.paths(), vec!["a"]);
+    assert_eq!(
+        report.warnings,
+        vec![Warning::new(
+            WarningCode::TrailingData,
+            format!("Input has 8 bytes after the end of the last allocated block at 0x{:x}", length)
+        )]
+    );
+
+    match DsStoreParser::new().parse_strict(&buf) {
+        Err(DsStoreError::TrailingData { length: l, allocated_length }) => {
+            assert_eq!((l, allocated_length), (length + 8, length));
+        }
+        _ => panic!("expected TrailingData"),
+    }
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn describes_errors_as_json() {
+    let buf = Builder::new().record(vsrn("a")).build();
+    let parser = DsStoreParser::new();
+
+    let error = parser.parse_bytes(&buf[..0x900]).err().unwrap();
+    assert_eq!(error.kind(), "OffsetOutOfRange");
+    assert_eq!(
+        error.to_json_value().to_string(),
+        format!(r#"{{"error":"OffsetOutOfRange","offset":3084,"message":"{}"}}"#, error)
+    );
+
+    let error = parser.parse_bytes(&[]).err().unwrap();
+    assert_eq!(
+        error.to_json_value().to_string(),
+        r#"{"error":"Empty","message":"Input is empty"}"#
+    );
+}
@@ -0,0 +1,219 @@
+#![cfg(feature = "plist")]
+
+mod common;
+
+use common::plist::{dict, encode, s, P};
+use common::{rec, Builder, Data};
+use ds_store_parser::{
+    DsStore, DsStoreParser, IconViewSettings, ListViewColumn, Record, WindowSettings,
+};
+
+fn parse(buf: &[u8]) -> DsStore {
+    DsStoreParser::new().parse_bytes(buf).unwrap()
+}
+
+fn records(ds_store: &DsStore) -> Vec<&Record> {
+    ds_store
+        .children()
+        .iter()
+        .flat_map(|c| c.records())
+        .collect()
+}
+
+#[test]
+fn decodes_window_settings() {
+    let bwsp = encode(&dict(vec![
+        ("ContainerShowSidebar", P::Bool(true)),
+        ("ShowPathbar", P::Bool(false)),
+        ("ShowSidebar", P::Bool(true)),
+        ("ShowStatusBar", P::Bool(false)),
+        ("ShowTabView", P::Bool(false)),
+        ("ShowToolbar", P::Bool(true)),
+        ("SidebarWidth", P::Int(192)),
+        ("WindowBounds", s("{{412, 172}, {920, 436}}")),
+    ]));
+    let ds_store = parse(
+        &Builder::new()
+            .record(rec(".", b"bwsp", Data::Blob(bwsp)))
+            .build()
+    );
+
+    assert_eq!(
+        records(&ds_store)[0].window_settings(),
+        Some(WindowSettings {
+            window_bounds: Some("{{412, 172}, {920, 436}}".into()),
+            sidebar_width: Some(192.0),
+            show_toolbar: Some(true),
+            show_sidebar: Some(true),
+            show_status_bar: Some(false),
+            show_pathbar: Some(false),
+        })
+    );
+}
+
+#[test]
+fn ignores_malformed_window_settings() {
+    let ds_store = parse(
+        &Builder::new()
+            .record(rec(".", b"bwsp", Data::Blob(b"bplist00garbage".to_vec())))
+            .build()
+    );
+
+    assert_eq!(records(&ds_store)[0].window_settings(), None);
+}
+
+// Mirrors the layout of an lsvp record written by Finder for a list view
+// sorted by date modified, newest first.
+#[test]
+fn decodes_list_view_settings() {
+    let lsvp = encode(&dict(vec![
+        ("calculateAllSizes", P::Bool(false)),
+        ("columns", dict(vec![
+            ("dateModified", dict(vec![
+                ("ascending", P::Bool(false)),
+                ("index", P::Int(1)),
+                ("visible", P::Bool(true)),
+                ("width", P::Int(181)),
+            ])),
+            ("name", dict(vec![
+                ("ascending", P::Bool(true)),
+                ("index", P::Int(0)),
+                ("visible", P::Bool(true)),
+                ("width", P::Int(300)),
+            ])),
+            ("comments", dict(vec![
+                ("ascending", P::Bool(true)),
+                ("index", P::Int(7)),
+                ("visible", P::Bool(false)),
+                ("width", P::Int(300)),
+            ])),
+        ])),
+        ("iconSize", P::Real(16.0)),
+        ("sortColumn", s("dateModified")),
+        ("textSize", P::Real(13.0)),
+        ("useRelativeDates", P::Bool(true)),
+        ("viewOptionsVersion", P::Int(1)),
+    ]));
+    let ds_store = parse(
+        &Builder::new()
+            .record(rec(".", b"lsvp", Data::Blob(lsvp)))
+            .build()
+    );
+
+    let settings = records(&ds_store)[0].list_view_settings().unwrap();
+    assert_eq!(settings.sort_column.as_deref(), Some("dateModified"));
+    assert_eq!(settings.sort_ascending, Some(false));
+    assert_eq!(settings.columns.len(), 3);
+    assert_eq!(
+        settings.columns[2],
+        ListViewColumn {
+            name: "comments".into(),
+            width: Some(300.0),
+            visible: Some(false),
+            ascending: Some(true),
+        }
+    );
+}
+
+#[test]
+fn decodes_list_view_column_arrays() {
+    let lsvp = encode(&dict(vec![
+        ("columns", P::Array(vec![
+            dict(vec![
+                ("ascending", P::Bool(true)),
+                ("identifier", s("name")),
+                ("visible", P::Bool(true)),
+                ("width", P::Int(250)),
+            ]),
+            dict(vec![
+                ("identifier", s("size")),
+                ("visible", P::Bool(false)),
+            ]),
+        ])),
+        ("sortColumn", s("name")),
+    ]));
+    let ds_store = parse(
+        &Builder::new()
+            .record(rec(".", b"lsvP", Data::Blob(lsvp)))
+            .build()
+    );
+
+    let settings = records(&ds_store)[0].list_view_settings().unwrap();
+    let names = settings.columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["name", "size"]);
+    assert_eq!(settings.sort_ascending, Some(true));
+    assert_eq!(settings.columns[1].width, None);
+}
+
+#[test]
+fn decodes_icon_view_settings() {
+    let icvp = encode(&dict(vec![
+        ("arrangeBy", s("none")),
+        ("backgroundType", P::Int(0)),
+        ("gridOffsetX", P::Real(0.0)),
+        ("gridOffsetY", P::Real(0.0)),
+        ("gridSpacing", P::Real(54.0)),
+        ("iconSize", P::Real(64.0)),
+        ("labelOnBottom", P::Bool(true)),
+        ("showIconPreview", P::Bool(true)),
+        ("showItemInfo", P::Bool(false)),
+        ("textSize", P::Real(12.0)),
+        ("viewOptionsVersion", P::Int(1)),
+    ]));
+    let ds_store = parse(
+        &Builder::new()
+            .record(rec(".", b"icvp", Data::Blob(icvp)))
+            .build()
+    );
+
+    assert_eq!(
+        records(&ds_store)[0].icon_view_settings(),
+        Some(IconViewSettings {
+            icon_size: Some(64.0),
+            grid_spacing: Some(54.0),
+            text_size: Some(12.0),
+            label_on_bottom: Some(true),
+            show_item_info: Some(false),
+        })
+    );
+}
+
+// Each array holds a single reference to the next one, ending in an empty
+// array. Written by hand because the common encoder only uses 1-byte refs.
+fn nested_arrays(depth: usize) -> Vec<u8> {
+    let mut buf = b"bplist00".to_vec();
+    let mut offsets = Vec::new();
+
+    for index in 0..depth {
+        offsets.push(buf.len() as u32);
+        buf.push(0xa1);
+        buf.extend_from_slice(&(index as u16 + 1).to_be_bytes());
+    }
+    offsets.push(buf.len() as u32);
+    buf.push(0xa0);
+
+    let table_offset = buf.len() as u64;
+    for offset in &offsets {
+        buf.extend_from_slice(&offset.to_be_bytes());
+    }
+    buf.extend_from_slice(&[0; 6]);
+    buf.extend_from_slice(&[4, 2]);
+    buf.extend_from_slice(&(offsets.len() as u64).to_be_bytes());
+    buf.extend_from_slice(&0u64.to_be_bytes());
+    buf.extend_from_slice(&table_offset.to_be_bytes());
+    buf
+}
+
+#[test]
+fn rejects_deeply_nested_plists() {
+    let ds_store = parse(
+        &Builder::new()
+            .record(rec(".", b"icvp", Data::Blob(nested_arrays(10))))
+            .record(rec("deep", b"icvp", Data::Blob(nested_arrays(10_000))))
+            .build()
+    );
+
+    let records = records(&ds_store);
+    assert!(records[0].plist().is_some());
+    assert_eq!(records[1].plist(), None);
+}
@@ -0,0 +1,304 @@
+mod common;
+
+use common::{rec, vsrn, Builder, Data};
+use ds_store_parser::{
+    image_extension, Background, DsStore, DsStoreParser, IconLocation, Record, ViewStyle, Warning,
+    WarningCode, WindowInfo,
+};
+
+fn parse(buf: &[u8]) -> DsStore {
+    DsStoreParser::new().parse_bytes(buf).unwrap()
+}
+
+fn records(ds_store: &DsStore) -> Vec<&Record> {
+    ds_store
+        .children()
+        .iter()
+        .flat_map(|c| c.records())
+        .collect()
+}
+
+fn iloc(x: i32, y: i32) -> Data {
+    let mut blob = Vec::new();
+    blob.extend_from_slice(&x.to_be_bytes());
+    blob.extend_from_slice(&y.to_be_bytes());
+    blob.extend_from_slice(&[0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    Data::Blob(blob)
+}
+
+#[test]
+fn decodes_icon_location() {
+    let ds_store = parse(
+        &Builder::new()
+            .record(rec("report.pdf", b"Iloc", iloc(100, 200)))
+            .record(vsrn("report.pdf"))
+            .build()
+    );
+    let records = records(&ds_store);
+
+    assert_eq!(
+        records[0].icon_location(),
+        Some(IconLocation { x: Some(100), y: Some(200) })
+    );
+    assert_eq!(records[1].icon_location(), None);
+}
+
+#[test]
+fn keeps_large_icon_coordinates() {
+    let parser = DsStoreParser::new();
+    let ds_store = parser
+        .parse_bytes(&Builder::new().record(rec("wide.png", b"Iloc", iloc(70000, 65535))).build())
+        .unwrap();
+
+    let mut written = Vec::new();
+    parser.write(&ds_store, &mut written).unwrap();
+    let ds_store = parser.parse_bytes(&written).unwrap();
+
+    assert_eq!(
+        records(&ds_store)[0].icon_location(),
+        Some(IconLocation { x: Some(70000), y: None })
+    );
+}
+
+#[test]
+fn decodes_comments_with_non_ascii_text() {
+    let comment = "Entwurf für Q3 – nicht teilen 🔒";
+    let ds_store = parse(
+        &Builder::new()
+            .record(rec("plan.key", b"cmmt", Data::Ustr(comment.into())))
+            .record(rec("plan.key", b"Iloc", iloc(10, 20)))
+            .record(vsrn("other.txt"))
+            .build()
+    );
+    let records = records(&ds_store);
+
+    assert_eq!(records[0].comment(), Some(comment));
+    assert_eq!(records[1].comment(), None);
+    assert_eq!(ds_store.children()[0].comment(), Some(comment));
+    assert_eq!(ds_store.children()[2].comment(), None);
+}
+
+// 2021-03-04 12:00:00 UTC in 1/65536 second ticks since 1904.
+const MODIFIED_TICKS: u64 = 242_332_729_344_000;
+const MODIFIED_UNIX_SECS: i64 = 1_614_859_200;
+
+#[test]
+fn decodes_modification_dates() {
+    let ds_store = parse(
+        &Builder::new()
+            .record(rec("a.txt", b"modD", Data::Dutc(MODIFIED_TICKS)))
+            .record(rec("b.txt", b"moDD", Data::Blob(MODIFIED_TICKS.to_le_bytes().to_vec())))
+            .record(rec("c.txt", b"moDD", Data::Blob(vec![0; 4])))
+            .record(rec("d.txt", b"cmmt", Data::Ustr("note".into())))
+            .build()
+    );
+    let modified = records(&ds_store)
+        .iter()
+        .map(|r| r.modified())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        modified,
+        vec![Some(MODIFIED_UNIX_SECS), Some(MODIFIED_UNIX_SECS), None, None]
+    );
+    assert_eq!(ds_store.children()[1].modified(), Some(MODIFIED_UNIX_SECS));
+}
+
+#[test]
+fn decodes_file_sizes() {
+    let buf = Builder::new()
+        .record(rec("a.mov", b"lg1S", Data::Comp(1_234_567_890_123)))
+        .record(rec("a.mov", b"ph1S", Data::Comp(1_234_567_892_992)))
+        .record(rec("b.txt", b"logS", Data::Comp(42)))
+        .record(rec("b.txt", b"phyS", Data::Blob(4096u64.to_be_bytes().to_vec())))
+        .record(rec("c.txt", b"logS", Data::Blob(vec![0; 4])))
+        .stale(vec![rec("deleted.zip", b"logS", Data::Comp(65_536))])
+        .build();
+    let ds_store = parse(&buf);
+    let sizes = records(&ds_store)
+        .iter()
+        .map(|r| (r.logical_size(), r.physical_size()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        sizes,
+        vec![
+            (Some(1_234_567_890_123), None),
+            (None, Some(1_234_567_892_992)),
+            (Some(42), None),
+            (None, Some(4096)),
+            (None, None),
+        ]
+    );
+    assert_eq!(ds_store.children()[1].physical_size(), Some(1_234_567_892_992));
+
+    // Sizes often outlive the files they describe.
+    let recovered = DsStoreParser::new().recover(&buf);
+    assert_eq!(recovered[0].name(), "deleted.zip");
+    assert_eq!(recovered[0].logical_size(), Some(65_536));
+}
+
+#[test]
+fn decodes_disclosure_state() {
+    let ds_store = parse(
+        &Builder::new()
+            .record(rec("Archive", b"dscl", Data::Bool(false)))
+            .record(rec("Projects", b"dscl", Data::Bool(true)))
+            .record(rec("Projects", b"Iloc", iloc(10, 20)))
+            .build()
+    );
+    let expanded = records(&ds_store)
+        .iter()
+        .map(|r| r.expanded())
+        .collect::<Vec<_>>();
+
+    assert_eq!(expanded, vec![Some(false), Some(true), None]);
+    assert_eq!(ds_store.children()[0].expanded(), Some(false));
+    assert_eq!(ds_store.children()[1].expanded(), Some(true));
+}
+
+#[test]
+fn decodes_grouping() {
+    let ds_store = parse(
+        &Builder::new()
+            .record(rec(".", b"GRP0", Data::Ustr("Kind".into())))
+            .record(rec("a", b"GRP0", Data::Type(*b"none")))
+            .record(rec("b", b"dscl", Data::Bool(true)))
+            .build()
+    );
+    let groupings = records(&ds_store)
+        .iter()
+        .map(|r| r.grouping())
+        .collect::<Vec<_>>();
+
+    assert_eq!(groupings, vec![Some("Kind"), Some("none"), None]);
+    assert_eq!(ds_store.children()[0].grouping(), Some("Kind"));
+}
+
+#[test]
+fn decodes_backgrounds() {
+    let mut color = b"ClrB".to_vec();
+    color.extend_from_slice(&[0xff, 0xff, 0x80, 0x00, 0x00, 0x10, 0x00, 0x00]);
+    let mut picture = b"PctB".to_vec();
+    picture.extend_from_slice(&[0x00, 0x01, 0xe2, 0x40, 0x00, 0x00, 0x00, 0x00]);
+    let mut default = b"DefB".to_vec();
+    default.extend_from_slice(&[0; 8]);
+
+    let ds_store = parse(
+        &Builder::new()
+            .record(rec(".", b"BKGD", Data::Blob(color)))
+            .record(rec("a", b"BKGD", Data::Blob(picture)))
+            .record(rec("b", b"BKGD", Data::Blob(default)))
+            .record(rec("c", b"BKGD", Data::Blob(b"ClrB".to_vec())))
+            .build()
+    );
+    let backgrounds = records(&ds_store)
+        .iter()
+        .map(|r| r.background())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        backgrounds,
+        vec![
+            Some(Background::Color { r: 0xffff, g: 0x8000, b: 0x0010 }),
+            Some(Background::Picture { blob_len: 123_456 }),
+            Some(Background::Default),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn finds_background_pictures() {
+    let png = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR".to_vec();
+    let background = b"PctB\x00\x00\x00\x10\x00\x00\x00\x00".to_vec();
+    let ds_store = parse(
+        &Builder::new()
+            .record(rec(".", b"BKGD", Data::Blob(background)))
+            .record(rec(".", b"pict", Data::Blob(png.clone())))
+            .record(rec("a", b"pict", Data::Blob(vec![])))
+            .record(rec("b", b"cmmt", Data::Ustr("\u{89}PNG".into())))
+            .build()
+    );
+    let pictures = records(&ds_store)
+        .iter()
+        .map(|r| r.picture())
+        .collect::<Vec<_>>();
+
+    assert_eq!(pictures, vec![None, Some(&png[..]), Some(&[][..]), None]);
+    assert_eq!(image_extension(&png), Some("png"));
+    assert_eq!(image_extension(b"\xff\xd8\xff\xe0\x00\x10JFIF"), Some("jpg"));
+    assert_eq!(image_extension(b"GIF89a"), Some("gif"));
+    assert_eq!(image_extension(b"MM\x00*\x00\x00\x00\x08"), Some("tiff"));
+    assert_eq!(image_extension(b"\x00\x00\x00\x00\x00"), None);
+    assert_eq!(image_extension(b""), None);
+}
+
+#[test]
+fn decodes_window_info() {
+    let mut fwi0 = Vec::new();
+    for value in [72i16, 210, 508, 980] {
+        fwi0.extend_from_slice(&value.to_be_bytes());
+    }
+    fwi0.extend_from_slice(b"clmv");
+    fwi0.extend_from_slice(&[0x00, 0x01, 0x00, 0x00]);
+
+    let ds_store = parse(
+        &Builder::new()
+            .record(rec(".", b"fwi0", Data::Blob(fwi0)))
+            .record(rec("a.txt", b"fwi0", Data::Blob(vec![0; 4])))
+            .build()
+    );
+    let records = records(&ds_store);
+
+    assert_eq!(
+        records[0].window_info(),
+        Some(WindowInfo { top: 72, left: 210, bottom: 508, right: 980, view: *b"clmv" })
+    );
+    assert_eq!(records[1].window_info(), None);
+}
+
+fn window(view: &[u8; 4]) -> Data {
+    let mut blob = vec![0; 8];
+    blob.extend_from_slice(view);
+    blob.extend_from_slice(&[0; 4]);
+    Data::Blob(blob)
+}
+
+#[test]
+fn prefers_fwi0_view_style_and_warns_on_conflict() {
+    let buf = Builder::new()
+        .record(rec(".", b"fwi0", window(b"Nlsv")))
+        .record(rec(".", b"vstl", Data::Type(*b"icnv")))
+        .build();
+
+    let (ds_store, report) = DsStoreParser::new().parse_bytes_with_report(&buf).unwrap();
+
+    assert_eq!(ds_store.view_style(), Some(ViewStyle::List));
+    assert_eq!(ds_store.summary().view_style, Some(ViewStyle::List));
+    assert_eq!(
+        report.warnings,
+        vec![Warning::new(
+            WarningCode::ViewStyleConflict,
+            "Folder view style is list according to fwi0 but icon according to vstl"
+        )]
+    );
+}
+
+#[test]
+fn infers_view_style_from_view_settings() {
+    let style = |records: Vec<common::Rec>| {
+        let buf = Builder::new().records(records).build();
+        let (ds_store, report) = DsStoreParser::new().parse_bytes_with_report(&buf).unwrap();
+        assert!(report.warnings.is_empty());
+        ds_store.view_style()
+    };
+
+    assert_eq!(style(vec![rec(".", b"vstl", Data::Type(*b"clmv"))]), Some(ViewStyle::Column));
+    assert_eq!(style(vec![rec(".", b"icvp", Data::Blob(vec![]))]), Some(ViewStyle::Icon));
+    assert_eq!(
+        style(vec![rec(".", b"icvp", Data::Blob(vec![])), rec(".", b"lsvp", Data::Blob(vec![]))]),
+        None
+    );
+    assert_eq!(style(vec![rec("a", b"lsvp", Data::Blob(vec![]))]), None);
+}
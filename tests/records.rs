@@ -0,0 +1,697 @@
+mod common;
+
+use common::{rec, vsrn, Builder, Data};
+#[cfg(feature = "json")]
+use ds_store_parser::JsonValue;
+use ds_store_parser::{
+    DataType, Decode, DisplayOptions, DsStoreParser, FindOptions, Value, ViewStyle,
+};
+
+fn parsed_names(buf: &[u8]) -> Vec<String> {
+    DsStoreParser::new()
+        .parse_bytes(buf)
+        .unwrap()
+        .children()
+        .iter()
+        .map(|c| c.name().to_string())
+        .collect()
+}
+
+// Scanning for a trailing `vSrnlong` used to swallow every record that did
+// not end in one, misreading the following record's length.
+#[test]
+fn decodes_records_not_ending_in_vsrn() {
+    let buf = Builder::new()
+        .record(rec("a.txt", b"Iloc", Data::Blob(vec![0, 0, 0, 100, 0, 0, 0, 200, 0xff, 0xff, 0, 0, 0, 0, 0, 0])))
+        .record(rec("b.txt", b"cmmt", Data::Ustr("a comment".into())))
+        .record(rec("c.txt", b"dscl", Data::Bool(true)))
+        .record(rec("d.txt", b"modD", Data::Dutc(0x0000_cafe_0000_0000)))
+        .record(rec("e.txt", b"ICVO", Data::Type(*b"icv4")))
+        .record(vsrn("f.txt"))
+        .build();
+
+    assert_eq!(
+        parsed_names(&buf),
+        vec!["a.txt", "b.txt", "c.txt", "d.txt", "e.txt", "f.txt"]
+    );
+}
+
+#[test]
+fn captures_structure_ids() {
+    let buf = Builder::new()
+        .record(rec("a.txt", b"Iloc", Data::Blob(vec![0; 16])))
+        .record(rec("a.txt", b"bwsp", Data::Blob(vec![])))
+        .record(vsrn("b.txt"))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    let ids = ds_store
+        .children()
+        .iter()
+        .flat_map(|c| c.records())
+        .map(|r| (r.name().to_string(), r.structure_id()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        ids,
+        vec![
+            ("a.txt".to_string(), *b"Iloc"),
+            ("a.txt".to_string(), *b"bwsp"),
+            ("b.txt".to_string(), *b"vSrn"),
+        ]
+    );
+}
+
+#[test]
+fn decodes_typed_values() {
+    let buf = Builder::new()
+        .record(rec("a", b"bwsp", Data::Blob(vec![1, 2, 3])))
+        .record(rec("b", b"dscl", Data::Bool(true)))
+        .record(rec("c", b"logS", Data::Comp(1 << 40)))
+        .record(rec("d", b"modD", Data::Dutc(0x0000_dc13_6c00_0000)))
+        .record(rec("e", b"vSrn", Data::Long(-7)))
+        .record(rec("f", b"fwvh", Data::Shor(-2)))
+        .record(rec("g", b"vstl", Data::Type(*b"icnv")))
+        .record(rec("h", b"cmmt", Data::Ustr("Grüße".into())))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    let values = ds_store
+        .children()
+        .iter()
+        .flat_map(|c| c.records())
+        .map(|r| (r.data_type(), r.value().clone()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        values,
+        vec![
+            (DataType::Blob, Value::Blob(vec![1, 2, 3])),
+            (DataType::Bool, Value::Bool(true)),
+            (DataType::Comp, Value::Comp(1 << 40)),
+            (DataType::Dutc, Value::dutc(0x0000_dc13_6c00_0000)),
+            (DataType::Long, Value::Long(-7)),
+            (DataType::Shor, Value::Short(-2)),
+            (DataType::Type, Value::Type(*b"icnv")),
+            (DataType::Ustr, Value::Ustr("Grüße".into())),
+        ]
+    );
+}
+
+#[test]
+fn keeps_raw_payload_of_unknown_structure_ids() {
+    let payload = (0..20).collect::<Vec<u8>>();
+    let buf = Builder::new()
+        .record(rec("a", b"zZz9", Data::Blob(payload.clone())))
+        .record(rec("b", b"zZz9", Data::Shor(-2)))
+        .record(vsrn("c"))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    let records = ds_store.iter().map(|(_, r)| r).collect::<Vec<_>>();
+
+    let mut raw = vec![0, 0, 0, 20];
+    raw.extend_from_slice(&payload);
+    assert_eq!(records[0].data_type(), DataType::Blob);
+    assert_eq!(records[0].value(), &Value::Unknown { id: *b"zZz9", raw });
+    assert_eq!(
+        records[0].value_as_string(),
+        "zZz9 00000014000102030405060708090a0b…"
+    );
+    assert_eq!(
+        records[1].value(),
+        &Value::Unknown { id: *b"zZz9", raw: vec![0xff, 0xff, 0xff, 0xfe] }
+    );
+    assert_eq!(records[1].value_as_string(), "zZz9 fffffffe");
+    assert_eq!(records[2].value(), &Value::Long(1));
+}
+
+#[test]
+fn decodes_only_the_requested_values() {
+    let buf = Builder::new()
+        .record(rec("a", b"Iloc", Data::Blob(vec![0, 0, 0, 100, 0, 0, 0, 200])))
+        .record(rec("a", b"cmmt", Data::Ustr("note".into())))
+        .record(rec("a", b"zZz9", Data::Blob(vec![1])))
+        .record(vsrn("b"))
+        .build();
+    let values = |decode| {
+        DsStoreParser::new()
+            .with_decode(decode)
+            .parse_bytes(&buf)
+            .unwrap()
+            .iter()
+            .map(|(path, r)| (path, r.data_type(), r.value().clone()))
+            .collect::<Vec<_>>()
+    };
+
+    let iloc = Value::Blob(vec![0, 0, 0, 100, 0, 0, 0, 200]);
+    assert_eq!(
+        values(Decode::Names),
+        vec![
+            ("a".to_string(), DataType::Blob, Value::Skipped),
+            ("a".to_string(), DataType::Ustr, Value::Skipped),
+            ("a".to_string(), DataType::Blob, Value::Skipped),
+            ("b".to_string(), DataType::Long, Value::Skipped),
+        ]
+    );
+    assert_eq!(
+        values(Decode::Positions).iter().map(|(_, _, v)| v).collect::<Vec<_>>(),
+        vec![&iloc, &Value::Skipped, &Value::Skipped, &Value::Skipped]
+    );
+    assert_eq!(
+        values(Decode::All).iter().map(|(_, _, v)| v).collect::<Vec<_>>(),
+        vec![
+            &iloc,
+            &Value::Ustr("note".into()),
+            &Value::Unknown { id: *b"zZz9", raw: vec![0, 0, 0, 1, 1] },
+            &Value::Long(1),
+        ]
+    );
+}
+
+#[test]
+fn iterates_records_with_paths() {
+    let buf = Builder::new()
+        .record(rec("a.txt", b"Iloc", Data::Blob(vec![0; 16])))
+        .record(rec("a.txt", b"bwsp", Data::Blob(vec![])))
+        .record(vsrn("b.txt"))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    let paths = ds_store
+        .iter()
+        .map(|(path, record)| (path, record.structure_id()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        paths,
+        vec![
+            ("a.txt".to_string(), *b"Iloc"),
+            ("a.txt".to_string(), *b"bwsp"),
+            ("b.txt".to_string(), *b"vSrn"),
+        ]
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn serializes_tree_to_json() {
+    let buf = Builder::new()
+        .record(rec("a \"quoted\" name", b"Iloc", Data::Blob(vec![0, 0, 0, 100])))
+        .record(rec("b.txt", b"cmmt", Data::Ustr("note".into())))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+
+    assert_eq!(
+        ds_store.to_json(),
+        concat!(
+            r#"{"name":"DSDB","records":[],"children":["#,
+            r#"{"name":"a \"quoted\" name","records":[{"name":"a \"quoted\" name","#,
+            r#""structure_id":"Iloc","data_type":"blob","value":"00000064","recovered":false,"#,
+            r#""source":"live"}],"children":[]},"#,
+            r#"{"name":"b.txt","records":[{"name":"b.txt","structure_id":"cmmt","#,
+            r#""data_type":"ustr","value":"note","recovered":false,"source":"live"}],"#,
+            r#""children":[]}]}"#,
+        )
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn builds_json_value_with_decoded_properties() {
+    let mut iloc = vec![0, 0, 0, 100, 0, 0, 0, 200];
+    iloc.extend_from_slice(&[0xff; 8]);
+    let buf = Builder::new()
+        .record(rec("a.txt", b"Iloc", Data::Blob(iloc)))
+        .record(rec("a.txt", b"logS", Data::Comp(42)))
+        .record(rec("b.txt", b"cmmt", Data::Ustr("note".into())))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    let value = ds_store.to_value();
+
+    assert_eq!(
+        value.to_string(),
+        concat!(
+            r#"{"name":"DSDB","records":[],"children":["#,
+            r#"{"name":"a.txt","records":[{"name":"a.txt","structure_id":"Iloc","#,
+            r#""data_type":"blob","value":"00000064000000c8ffffffffffffffff","recovered":false,"#,
+            r#""source":"live","decoded":{"x":100,"y":200}}],"children":[]},"#,
+            r#"{"name":"a.txt","records":[{"name":"a.txt","structure_id":"logS","#,
+            r#""data_type":"comp","value":42,"recovered":false,"source":"live","decoded":42}],"#,
+            r#""children":[]},"#,
+            r#"{"name":"b.txt","records":[{"name":"b.txt","structure_id":"cmmt","#,
+            r#""data_type":"ustr","value":"note","recovered":false,"source":"live"}],"#,
+            r#""children":[]}]}"#,
+        )
+    );
+
+    // Callers can take the value apart and merge it into their own documents.
+    let report = JsonValue::object(vec![("folder", value), ("scanned", JsonValue::Bool(true))]);
+    assert!(report.to_string().ends_with(r#""scanned":true}"#));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn writes_one_json_object_per_record() {
+    let buf = Builder::new()
+        .record(rec("line\nbreak \"quoted\".txt", b"cmmt", Data::Ustr("tab\there".into())))
+        .record(rec("b.txt", b"modD", Data::Dutc(0)))
+        .record(vsrn("b.txt"))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    let mut out = Vec::new();
+    ds_store.write_ndjson(&mut out, Some("a/.DS_Store")).unwrap();
+    let out = String::from_utf8(out).unwrap();
+
+    let lines = out.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), ds_store.iter().count());
+
+    for line in &lines {
+        assert!(common::json::is_valid(line), "{}", line);
+    }
+
+    assert_eq!(
+        lines[2],
+        concat!(
+            r#"{"file":"a/.DS_Store","path":"b.txt","name":"b.txt","structure_id":"vSrn","#,
+            r#""data_type":"long","value":1,"recovered":false,"source":"live"}"#,
+        )
+    );
+}
+
+#[test]
+fn writes_csv_with_escaping() {
+    let buf = Builder::new()
+        .record(rec("budget, \"final\".xlsx", b"Iloc", Data::Blob(vec![0xca, 0xfe])))
+        .record(vsrn("plain.txt"))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    let mut out = Vec::new();
+    ds_store.write_csv(&mut out).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        concat!(
+            "path,structure_id,data_type,value,source\n",
+            "\"budget, \"\"final\"\".xlsx\",Iloc,blob,cafe,live\n",
+            "plain.txt,vSrn,long,1,live\n",
+        )
+    );
+}
+
+fn table(buf: &[u8], width: usize) -> Vec<String> {
+    let ds_store = DsStoreParser::new().parse_bytes(buf).unwrap();
+    let mut out = Vec::new();
+    ds_store.write_table(&mut out, width).unwrap();
+
+    String::from_utf8(out).unwrap().lines().map(String::from).collect()
+}
+
+#[test]
+fn writes_aligned_table() {
+    let buf = Builder::new()
+        .record(rec("写真.jpg", b"cmmt", Data::Ustr("line\nbreak".into())))
+        .record(rec("e\u{301}te\u{301}.txt", b"Iloc", Data::Blob(vec![0xca, 0xfe])))
+        .record(rec("d.txt", b"modD", Data::Dutc(3_692_304_000u64 << 16)))
+        .record(vsrn("notes.txt"))
+        .build();
+
+    // Values are rendered by `value_as_string`, e.g. dates as timestamps.
+    assert_eq!(
+        table(&buf, usize::MAX),
+        vec![
+            "PATH      | TYPE | VALUE",
+            "----------+------+------------------------",
+            "写真.jpg  | cmmt | \"line\\nbreak\"",
+            "e\u{301}te\u{301}.txt   | Iloc | cafe",
+            "d.txt     | modD | 2021-01-01 00:00:00 UTC",
+            "notes.txt | vSrn | 1",
+        ]
+    );
+}
+
+#[test]
+fn truncates_table_to_width() {
+    let buf = Builder::new()
+        .record(rec("a-rather-long-file-name.txt", b"cmmt", Data::Ustr("x".repeat(100))))
+        .record(vsrn("b.txt"))
+        .build();
+
+    let lines = table(&buf, 60);
+
+    assert_eq!(
+        lines,
+        vec![
+            "PATH                      | TYPE | VALUE",
+            "--------------------------+------+--------------------------",
+            &format!("a-rather-long-file-name.… | cmmt | \"{}…", "x".repeat(23)),
+            "b.txt                     | vSrn | 1",
+        ]
+    );
+    assert!(lines.iter().all(|line| line.chars().count() <= 60));
+
+    let lines = table(&buf, 30);
+    assert_eq!(lines[2], "a-rather-… | cmmt | \"xxxxxxxx…");
+}
+
+#[test]
+fn lists_unique_paths() {
+    let buf = Builder::new()
+        .record(rec("a.txt", b"Iloc", Data::Blob(vec![0; 16])))
+        .record(rec("a.txt", b"bwsp", Data::Blob(vec![])))
+        .record(vsrn("b.txt"))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+
+    assert_eq!(ds_store.paths(), vec!["a.txt", "b.txt"]);
+}
+
+#[test]
+fn groups_records_by_entry_name() {
+    let buf = Builder::new()
+        .record(rec("a.txt", b"Iloc", Data::Blob(vec![0; 16])))
+        .record(rec("a.txt", b"cmmt", Data::Ustr("note".into())))
+        .record(vsrn("b.txt"))
+        .record(rec("c.txt", b"dscl", Data::Bool(false)))
+        .record(vsrn("c.txt"))
+        .build();
+
+    let mut ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    assert_eq!(ds_store.children().len(), 5);
+
+    ds_store.group_records();
+
+    let entries = ds_store
+        .children()
+        .iter()
+        .map(|c| {
+            let ids = c.records().iter().map(|r| r.structure_id_str()).collect::<Vec<_>>();
+            (c.name(), ids)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        entries,
+        vec![
+            ("a.txt", vec!["Iloc".to_string(), "cmmt".to_string()]),
+            ("b.txt", vec!["vSrn".to_string()]),
+            ("c.txt", vec!["dscl".to_string(), "vSrn".to_string()]),
+        ]
+    );
+    assert_eq!(ds_store.children()[0].comment(), Some("note"));
+    assert_eq!(ds_store.iter().count(), 5);
+}
+
+#[test]
+fn builds_implied_tree_from_names() {
+    let buf = Builder::new()
+        .record(rec("a", b"cmmt", Data::Ustr("folder".into())))
+        .record(rec("a/b/c.txt", b"Iloc", Data::Blob(vec![0; 16])))
+        .record(rec("a/b/c.txt", b"cmmt", Data::Ustr("file".into())))
+        .record(vsrn("a/f"))
+        .record(vsrn("d.txt"))
+        .build();
+
+    let tree = DsStoreParser::new().parse_bytes(&buf).unwrap().into_implied_tree();
+
+    assert_eq!(
+        format!("{:#}", tree).lines().collect::<Vec<_>>(),
+        vec![
+            "DSDB",
+            "    a [cmmt]:",
+            "        b:",
+            "            c.txt [Iloc, cmmt]",
+            "        f [vSrn]",
+            "    d.txt [vSrn]",
+        ]
+    );
+
+    let a = &tree.children()[0];
+    assert!(a.is_folder() && a.children()[0].is_folder());
+    assert!(!a.children()[1].is_folder());
+    assert_eq!(tree.paths(), vec!["a", "a/b/c.txt", "a/f", "d.txt"]);
+}
+
+#[test]
+fn renders_structure_ids_in_alternate_form() {
+    let buf = Builder::new()
+        .record(rec("a.txt", b"Iloc", Data::Blob(vec![0; 16])))
+        .record(vsrn("b.txt"))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+
+    assert_eq!(ds_store.to_string(), "DSDB\n    a.txt\n    b.txt\n");
+    assert_eq!(
+        format!("{:#}", ds_store),
+        "DSDB\n    a.txt [Iloc]\n    b.txt [vSrn]\n"
+    );
+}
+
+#[test]
+fn converts_dutc_to_unix_seconds() {
+    // 2021-01-01T00:00:00Z is 3692304000 seconds after the Mac epoch.
+    let raw = 3_692_304_000u64 << 16;
+    let buf = Builder::new()
+        .record(rec("a.txt", b"modD", Data::Dutc(raw)))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    let (_, record) = ds_store.iter().next().unwrap();
+
+    assert_eq!(
+        record.value(),
+        &Value::Dutc { raw: raw as i64, unix_secs: 1_609_459_200 }
+    );
+}
+
+#[test]
+fn renders_values_as_strings() {
+    let buf = Builder::new()
+        .record(rec("a", b"vSrn", Data::Long(-7)))
+        .record(rec("b", b"fwvh", Data::Shor(300)))
+        .record(rec("c", b"dscl", Data::Bool(true)))
+        .record(rec("d", b"Iloc", Data::Blob(vec![0x00, 0xca, 0xfe])))
+        .record(rec("e", b"cmmt", Data::Ustr("say \"hi\"\n".into())))
+        .record(rec("f", b"vstl", Data::Type(*b"Nlsv")))
+        .record(rec("g", b"logS", Data::Comp(1 << 40)))
+        .record(rec("h", b"modD", Data::Dutc(3_692_304_000u64 << 16)))
+        .record(rec("i", b"moDD", Data::Dutc(3_696_782_459u64 << 16)))
+        .record(rec("j", b"modD", Data::Dutc(0)))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    let rendered = ds_store
+        .iter()
+        .map(|(_, record)| record.value_as_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        rendered,
+        vec![
+            "-7",
+            "300",
+            "true",
+            "00cafe",
+            "\"say \\\"hi\\\"\\n\"",
+            "Nlsv",
+            "1099511627776",
+            "2021-01-01 00:00:00 UTC",
+            "2021-02-21 20:00:59 UTC",
+            "1904-01-01 00:00:00 UTC",
+        ]
+    );
+}
+
+#[test]
+fn filters_records_by_structure_id() {
+    let buf = Builder::new()
+        .record(rec("a.txt", b"Iloc", Data::Blob(vec![0; 16])))
+        .record(rec("a.txt", b"cmmt", Data::Ustr("note".into())))
+        .record(vsrn("b.txt"))
+        .build();
+
+    let mut ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    ds_store.retain_records(|r| &r.structure_id() == b"Iloc");
+
+    let ids = ds_store
+        .iter()
+        .map(|(path, r)| format!("{}:{}", path, r.structure_id_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["a.txt:Iloc"]);
+}
+
+#[test]
+fn summarizes_records() {
+    let buf = Builder::new()
+        .record(rec(".", b"vstl", Data::Type(*b"clmv")))
+        .record(rec("a.txt", b"Iloc", Data::Blob(vec![0; 16])))
+        .record(rec("a.txt", b"cmmt", Data::Ustr("note".into())))
+        .record(rec("b.txt", b"Iloc", Data::Blob(vec![0; 16])))
+        .build();
+
+    let summary = DsStoreParser::new().parse_bytes(&buf).unwrap().summary();
+
+    assert_eq!(summary.entries, 3);
+    assert_eq!(summary.records, 4);
+    assert_eq!(summary.structure_ids.get("Iloc"), Some(&2));
+    assert_eq!(summary.comments, 1);
+    assert_eq!(summary.icon_locations, 2);
+    assert_eq!(summary.view_style, Some(ViewStyle::Column));
+    assert!(summary.recovered_names.is_empty());
+}
+
+#[test]
+fn finds_records_by_exact_name() {
+    let buf = Builder::new()
+        .record(rec("secret.pdf", b"Iloc", Data::Blob(vec![0; 16])))
+        .record(vsrn("secret.pdf"))
+        .record(vsrn("secret.pdf.bak"))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+
+    assert_eq!(ds_store.find("secret.pdf").len(), 2);
+    assert!(ds_store.find("Secret.pdf").is_empty());
+}
+
+#[test]
+fn finds_records_by_substring() {
+    let buf = Builder::new()
+        .record(vsrn("Quarterly Report.xlsx"))
+        .record(vsrn("report-draft.docx"))
+        .record(vsrn("notes.txt"))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    let names = |options| {
+        ds_store
+            .find_with("report", options)
+            .iter()
+            .map(|r| r.name().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        names(FindOptions { substring: true, ignore_case: false, nfc: false }),
+        vec!["report-draft.docx"]
+    );
+    assert_eq!(
+        names(FindOptions { substring: true, ignore_case: true, nfc: false }),
+        vec!["Quarterly Report.xlsx", "report-draft.docx"]
+    );
+}
+
+#[test]
+fn finds_names_in_composed_and_decomposed_form() {
+    // As written by HFS+: `e` and a combining acute accent, and Hangul jamo.
+    let buf = Builder::new()
+        .record(vsrn("Cafe\u{301} Me\u{301}nu.txt"))
+        .record(vsrn("\u{1112}\u{1161}\u{11ab}.txt"))
+        .record(vsrn("A\u{323}\u{302}.txt"))
+        .build();
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    let count = |pattern, options| ds_store.find_with(pattern, options).len();
+
+    let exact = FindOptions::default();
+    let nfc = FindOptions { nfc: true, ..FindOptions::default() };
+    let folded = FindOptions { nfc: true, ignore_case: true, ..FindOptions::default() };
+
+    assert_eq!(count("Caf\u{e9} M\u{e9}nu.txt", exact), 0);
+    assert_eq!(count("Caf\u{e9} M\u{e9}nu.txt", nfc), 1);
+    assert_eq!(count("CAF\u{c9} M\u{c9}NU.TXT", nfc), 0);
+    assert_eq!(count("CAF\u{c9} M\u{c9}NU.TXT", folded), 1);
+    assert_eq!(count("\u{d55c}.txt", nfc), 1);
+    // Marks are put in canonical order before composing: U+1EAC is A with a
+    // dot below and a circumflex.
+    assert_eq!(count("A\u{302}\u{323}.txt", nfc), 1);
+    assert_eq!(count("\u{1eac}.txt", nfc), 1);
+}
+
+#[test]
+fn finds_names_ignoring_case_beyond_ascii() {
+    let buf = Builder::new()
+        .record(vsrn("Stra\u{df}e.txt"))
+        .record(vsrn("\u{3a3}\u{399}\u{3a3}\u{3a5}\u{3a6}\u{39f}\u{3a3}.pdf"))
+        .build();
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    let count = |pattern, options| ds_store.find_with(pattern, options).len();
+
+    let exact = FindOptions::default();
+    let folded = FindOptions { ignore_case: true, ..FindOptions::default() };
+
+    assert_eq!(count("STRASSE.TXT", exact), 0);
+    assert_eq!(count("STRASSE.TXT", folded), 1);
+    assert_eq!(count("strasse.txt", folded), 1);
+    // Σίσυφος without the accent, with a final sigma.
+    assert_eq!(count("\u{3c3}\u{3b9}\u{3c3}\u{3c5}\u{3c6}\u{3bf}\u{3c2}.pdf", exact), 0);
+    assert_eq!(count("\u{3c3}\u{3b9}\u{3c3}\u{3c5}\u{3c6}\u{3bf}\u{3c2}.pdf", folded), 1);
+}
+
+#[test]
+fn records_their_file_offsets() {
+    let buf = Builder::new()
+        .record(vsrn("a"))
+        .record(vsrn("b"))
+        .build();
+
+    let ds_store = DsStoreParser::new().parse_bytes(&buf).unwrap();
+    let offsets = ds_store.iter().map(|(_, r)| r.offset()).collect::<Vec<_>>();
+
+    // The leaf sits at 0x1000 behind the 4 byte file prefix, followed by its
+    // 8 byte node header. A record for a one character name takes 18 bytes.
+    assert_eq!(offsets, vec![0x100c, 0x100c + 18]);
+
+    let rendered = ds_store
+        .display(DisplayOptions { show_ids: true, show_offsets: true, ..Default::default() })
+        .to_string();
+    assert_eq!(rendered, "DSDB\n    a [vSrn] @ 0x100c\n    b [vSrn] @ 0x101e\n");
+}
+
+fn values(buf: &[u8]) -> Vec<Value> {
+    DsStoreParser::new()
+        .parse_bytes(buf)
+        .unwrap()
+        .iter()
+        .map(|(_, r)| r.value().clone())
+        .collect()
+}
+
+// Each record below is followed by another, so a wrong length would shift
+// the read of the next record.
+#[test]
+fn decodes_empty_ustr() {
+    let buf = Builder::new()
+        .record(rec("a", b"cmmt", Data::Ustr(String::new())))
+        .record(vsrn("b"))
+        .build();
+
+    assert_eq!(values(&buf), vec![Value::Ustr(String::new()), Value::Long(1)]);
+}
+
+#[test]
+fn decodes_ustr_with_surrogate_pairs() {
+    // U+1D11E takes two UTF-16 code units, so the length prefix (5) differs
+    // from the number of characters (4).
+    let buf = Builder::new()
+        .record(rec("a", b"cmmt", Data::Ustr("𝄞 G大".into())))
+        .record(vsrn("b"))
+        .build();
+
+    assert_eq!(values(&buf), vec![Value::Ustr("𝄞 G大".into()), Value::Long(1)]);
+}
+
+#[test]
+fn decodes_blob_with_length_prefix() {
+    let blob = vec![0, 0, 0, 4, b'u', b's', b't', b'r', 0xff];
+    let buf = Builder::new()
+        .record(rec("a", b"Iloc", Data::Blob(blob.clone())))
+        .record(vsrn("b"))
+        .build();
+
+    assert_eq!(values(&buf), vec![Value::Blob(blob), Value::Long(1)]);
+}
@@ -0,0 +1,214 @@
+mod common;
+
+use common::{rec, vsrn, Builder, Data};
+use ds_store_parser::{
+    DisplayOptions, DsStoreError, DsStoreParser, Source, WarningCode, RECONSTRUCTED,
+};
+
+#[test]
+fn recovers_records_from_free_blocks() {
+    let buf = Builder::new()
+        .record(vsrn("kept.txt"))
+        .stale(vec![
+            rec("deleted plan.key", b"Iloc", Data::Blob(vec![0; 16])),
+            vsrn("deleted plan.key"),
+        ])
+        .build();
+
+    let parser = DsStoreParser::new();
+    let live = parser.parse_bytes(&buf).unwrap();
+    let recovered = parser.recover(&buf);
+
+    assert_eq!(live.paths(), vec!["kept.txt"]);
+    assert_eq!(
+        recovered
+            .iter()
+            .map(|r| (r.name(), r.structure_id(), r.is_recovered()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("deleted plan.key", *b"Iloc", true),
+            ("deleted plan.key", *b"vSrn", true),
+        ]
+    );
+}
+
+#[test]
+fn recovers_records_from_unreferenced_blocks() {
+    let buf = Builder::new()
+        .record(vsrn("kept.txt"))
+        .orphaned(vec![rec("old.psd", b"cmmt", Data::Ustr("draft".into()))])
+        .build();
+
+    let recovered = DsStoreParser::new().recover(&buf);
+
+    assert_eq!(recovered.len(), 1);
+    assert_eq!(recovered[0].name(), "old.psd");
+}
+
+#[test]
+fn recovers_nothing_from_clean_file() {
+    let buf = Builder::new()
+        .record(vsrn("kept.txt"))
+        .build();
+
+    assert!(DsStoreParser::new().recover(&buf).is_empty());
+}
+
+#[test]
+fn marks_recovered_entries_in_output() {
+    let buf = Builder::new()
+        .record(vsrn("kept.txt"))
+        .stale(vec![vsrn("gone.txt")])
+        .build();
+
+    let parser = DsStoreParser::new();
+    let mut ds_store = parser.parse_bytes(&buf).unwrap();
+    ds_store.add_records(parser.recover(&buf));
+
+    assert_eq!(
+        ds_store.to_string(),
+        "DSDB\n    kept.txt\n    gone.txt (recovered)\n"
+    );
+}
+
+#[test]
+fn shows_record_sources() {
+    let buf = Builder::new()
+        .record(vsrn("kept.txt"))
+        .stale(vec![vsrn("gone.txt")])
+        .build();
+
+    let parser = DsStoreParser::new();
+    let mut ds_store = parser.parse_bytes(&buf).unwrap();
+    ds_store.add_records(parser.recover(&buf));
+
+    let sources = ds_store.iter().map(|(_, r)| r.source()).collect::<Vec<_>>();
+    assert_eq!(sources, vec![Source::Live, Source::FreeSpace]);
+
+    let rendered = ds_store
+        .display(DisplayOptions { show_sources: true, ..Default::default() })
+        .to_string();
+    assert_eq!(
+        rendered,
+        "DSDB\n    kept.txt from live\n    gone.txt from free-space (recovered)\n"
+    );
+}
+
+#[test]
+fn keeps_only_deleted_entries() {
+    let buf = Builder::new()
+        .record(vsrn("kept.txt"))
+        .stale(vec![
+            rec("kept.txt", b"cmmt", Data::Ustr("old comment".into())),
+            vsrn("gone.txt"),
+        ])
+        .build();
+
+    let parser = DsStoreParser::new();
+    let mut ds_store = parser.parse_bytes(&buf).unwrap();
+    ds_store.add_records(parser.recover(&buf));
+    ds_store.retain_deleted();
+
+    assert_eq!(ds_store.to_string(), "DSDB\n    gone.txt (recovered)\n");
+}
+
+#[test]
+fn summarizes_only_deleted_names() {
+    let buf = Builder::new()
+        .record(vsrn("kept.txt"))
+        .stale(vec![vsrn("kept.txt"), vsrn("gone.txt")])
+        .build();
+
+    let parser = DsStoreParser::new();
+    let mut ds_store = parser.parse_bytes(&buf).unwrap();
+    ds_store.add_records(parser.recover(&buf));
+    let summary = ds_store.summary();
+
+    assert_eq!(summary.records, 3);
+    assert_eq!(summary.recovered_names, vec!["gone.txt"]);
+}
+
+#[test]
+fn colors_folders_and_recovered_entries() {
+    let buf = Builder::new()
+        .record(vsrn("photos/beach.jpg"))
+        .record(vsrn("kept.txt"))
+        .stale(vec![vsrn("gone.txt")])
+        .build();
+
+    let parser = DsStoreParser::new();
+    let mut ds_store = parser.parse_bytes(&buf).unwrap();
+    ds_store.add_records(parser.recover(&buf));
+    let ds_store = ds_store.into_implied_tree();
+
+    let render = |color| {
+        ds_store.display(DisplayOptions { color, ..Default::default() }).to_string()
+    };
+
+    assert_eq!(
+        render(true).lines().collect::<Vec<_>>(),
+        vec![
+            "DSDB",
+            "    \x1b[1m\x1b[34mphotos\x1b[0m:",
+            "        beach.jpg",
+            "    kept.txt",
+            "    \x1b[2m\x1b[31mgone.txt\x1b[0m (recovered)",
+        ]
+    );
+    assert_eq!(render(false), ds_store.to_string());
+}
+
+#[test]
+fn lists_unique_names_across_live_and_recovered_records() {
+    let buf = Builder::new()
+        .record(rec("b.txt", b"Iloc", Data::Blob(vec![0; 16])))
+        .record(rec("b.txt", b"cmmt", Data::Ustr("note".into())))
+        .record(vsrn("a.txt"))
+        .record(vsrn("b.txt"))
+        .stale(vec![
+            rec("Secret Project", b"Iloc", Data::Blob(vec![0; 16])),
+            vsrn("Secret Project"),
+            vsrn("a.txt"),
+        ])
+        .build();
+
+    let parser = DsStoreParser::new();
+    let mut ds_store = parser.parse_bytes(&buf).unwrap();
+    ds_store.add_records(parser.recover(&buf));
+
+    assert_eq!(
+        ds_store.unique_names().into_iter().collect::<Vec<_>>(),
+        vec!["Secret Project", "a.txt", "b.txt"]
+    );
+}
+
+#[test]
+fn carves_nodes_without_a_header() {
+    let names = (0..20).map(|i| format!("file_{:02}.txt", i)).collect::<Vec<_>>();
+    let buf = Builder::new().records(names.iter().map(|n| vsrn(n))).per_node(6).build();
+
+    // Drop the header, allocator and master block, and misalign the rest.
+    let mut carved = vec![0xaa; 3];
+    carved.extend_from_slice(&buf[0x1000..]);
+
+    assert!(DsStoreParser::new().parse_bytes(&carved).is_err());
+
+    let (ds_store, report) = DsStoreParser::new().parse_carved(&carved).unwrap();
+    let mut found = ds_store.paths();
+    found.sort();
+
+    assert_eq!(ds_store.name(), RECONSTRUCTED);
+    assert_eq!(found, names);
+    assert_eq!(report.record_count, 20);
+    assert_eq!(report.warnings[0].code(), WarningCode::Reconstructed);
+    assert!(report.warnings[0].message().starts_with("Reconstructed from"));
+    assert!(ds_store.iter().all(|(_, r)| r.source() == Source::Carved && !r.is_recovered()));
+}
+
+#[test]
+fn carves_nothing_from_noise() {
+    assert!(matches!(
+        DsStoreParser::new().parse_carved(&[0; 4096]),
+        Err(DsStoreError::NothingCarved { length: 4096 })
+    ));
+}
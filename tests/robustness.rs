@@ -0,0 +1,198 @@
+mod common;
+
+use common::{rec, vsrn, Builder, Data};
+use ds_store_parser::{DsStoreError, DsStoreParser};
+
+// A small xorshift generator so the mutations are the same on every run.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 as usize
+    }
+}
+
+fn sample() -> Vec<u8> {
+    Builder::new()
+        .records((0..6).map(|i| vsrn(&format!("file {}", i))))
+        .record(rec("notes.txt", b"cmmt", Data::Ustr("a comment".into())))
+        .record(rec("notes.txt", b"Iloc", Data::Blob(vec![0; 16])))
+        .per_node(3)
+        .stale(vec![vsrn("deleted.txt")])
+        .build()
+}
+
+fn parse_everything(buf: &[u8]) {
+    let parser = DsStoreParser::new();
+    let _ = parser.parse_bytes(buf);
+    let _ = parser.parse_lenient(buf);
+    let _ = parser.read_header(buf);
+    let _ = parser.recover(buf);
+}
+
+#[test]
+fn survives_truncation() {
+    let buf = sample();
+
+    for length in (0..buf.len()).step_by(31) {
+        parse_everything(&buf[..length]);
+    }
+}
+
+#[test]
+fn survives_random_corruption() {
+    let buf = sample();
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..500 {
+        let mut corrupted = buf.clone();
+        for _ in 0..1 + rng.next() % 8 {
+            let i = rng.next() % corrupted.len();
+            corrupted[i] = rng.next() as u8;
+        }
+        parse_everything(&corrupted);
+    }
+}
+
+#[test]
+fn rejects_references_to_unallocated_blocks() {
+    let mut buf = Builder::new()
+        .record(vsrn("a"))
+        .build();
+    // Point the master block's root node at a block id that doesn't exist.
+    buf[0x24..0x28].copy_from_slice(&99u32.to_be_bytes());
+
+    assert!(matches!(
+        DsStoreParser::new().parse_bytes(&buf),
+        Err(DsStoreError::UnknownBlock { block_id: 99 })
+    ));
+}
+
+#[test]
+fn rejects_root_offset_inside_header() {
+    for offset in [0u32, 0x10] {
+        let mut buf = Builder::new()
+            .record(vsrn("a"))
+            .build();
+        // Both copies, so the mismatch check doesn't catch it first.
+        buf[0x08..0x0c].copy_from_slice(&offset.to_be_bytes());
+        buf[0x10..0x14].copy_from_slice(&offset.to_be_bytes());
+
+        let parser = DsStoreParser::new();
+        assert!(matches!(
+            parser.parse_bytes(&buf),
+            Err(DsStoreError::InvalidRootOffset { offset: o }) if o == offset as usize
+        ));
+        assert!(parser.recover(&buf).is_empty());
+    }
+}
+
+#[test]
+fn rejects_oversized_block_count() {
+    let mut buf = Builder::new()
+        .record(vsrn("a"))
+        .build();
+    // The block count is the first field of the allocator's info block.
+    buf[0x804..0x808].copy_from_slice(&u32::MAX.to_be_bytes());
+
+    let parser = DsStoreParser::new();
+    match parser.parse_bytes(&buf) {
+        Err(DsStoreError::TooManyBlocks { count, max }) => {
+            assert_eq!(count, u32::MAX as usize);
+            assert_eq!(max, (buf.len() - 0x80c) / 4);
+        }
+        other => panic!("{:?}", other.map(|_| ())),
+    }
+    assert!(parser.parse_lenient(&buf).is_err());
+}
+
+#[test]
+fn rejects_name_running_past_end_of_file() {
+    let mut buf = Builder::new()
+        .record(vsrn("a"))
+        .record(vsrn("bb"))
+        .build();
+    // The second record starts at 0x101e, right after the 18 bytes of the
+    // first. End the file one byte into its name, leaving half a code unit.
+    buf.truncate(0x101e + 4 + 1);
+
+    let parser = DsStoreParser::new();
+    assert!(matches!(
+        parser.parse_bytes(&buf),
+        Err(DsStoreError::OffsetOutOfRange { offset: 0x1022 })
+    ));
+
+    let (ds_store, _) = parser.parse_lenient(&buf).unwrap();
+    assert_eq!(ds_store.paths(), vec!["a"]);
+}
+
+// Lengths of 2^31 code units or more overflow `usize` when doubled on 32 bit
+// targets, and must fail like any other out of range read.
+#[test]
+fn rejects_huge_name_and_text_lengths() {
+    let buf = Builder::new()
+        .record(rec("a", b"cmmt", Data::Ustr("note".into())))
+        .build();
+    let parser = DsStoreParser::new();
+
+    // The node starts at 0x1004 with its mode and record count, so the name
+    // length is at 0x100c and the comment's length at 0x101a.
+    for (offset, length) in [(0x100c, 0x8000_0000u32), (0x100c, u32::MAX), (0x101a, u32::MAX)] {
+        let mut corrupted = buf.clone();
+        corrupted[offset..offset + 4].copy_from_slice(&length.to_be_bytes());
+
+        assert!(matches!(
+            parser.parse_bytes(&corrupted),
+            Err(DsStoreError::OffsetOutOfRange { .. })
+        ));
+        assert!(parser.contains(&corrupted, "b").is_err());
+    }
+}
+
+// Cuts the file in the middle of each field of the allocator header and the
+// tree, checking that every read reports where it failed.
+#[test]
+fn reports_truncation_before_each_header_field() {
+    let buf = Builder::new()
+        .record(vsrn("a"))
+        .build();
+    let parser = DsStoreParser::new();
+
+    let cases = [
+        // Block address count.
+        (0x806, 0x804),
+        // Table of contents count, name, block id and the first free list.
+        (0xc0e, 0xc0c),
+        (0xc12, 0xc11),
+        (0xc17, 0xc15),
+        (0xc1b, 0xc19),
+    ];
+
+    for (length, offset) in cases {
+        match parser.parse_bytes(&buf[..length]) {
+            Err(DsStoreError::OffsetOutOfRange { offset: failed }) => {
+                assert_eq!(failed, offset, "truncated at 0x{:x}", length)
+            }
+            other => panic!("truncated at 0x{:x}: {:?}", length, other.map(|_| ())),
+        }
+    }
+
+    // The block addresses don't fit into what is left of the file.
+    assert!(matches!(
+        parser.parse_bytes(&buf[..0x80e]),
+        Err(DsStoreError::TooManyBlocks { count: 3, max: 0 })
+    ));
+    // The leaf node's block starts past the end.
+    assert!(matches!(
+        parser.parse_bytes(&buf[..0x1006]),
+        Err(DsStoreError::BlockOutOfRange { block_id: 2, .. })
+    ));
+    // Its record count is cut off.
+    assert!(matches!(
+        parser.parse_bytes(&buf[..0x100a]),
+        Err(DsStoreError::OffsetOutOfRange { offset: 0x1008 })
+    ));
+}
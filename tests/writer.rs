@@ -0,0 +1,155 @@
+mod common;
+
+use common::{rec, vsrn, Builder, Data};
+use ds_store_parser::{Decode, DsStore, DsStoreError, DsStoreParser, Value};
+
+fn records(ds_store: &DsStore) -> Vec<(String, String, Value)> {
+    ds_store
+        .iter()
+        .map(|(_, r)| (r.name().to_string(), r.structure_id_str(), r.value().clone()))
+        .collect()
+}
+
+fn round_trip(buf: &[u8]) -> Vec<u8> {
+    let parser = DsStoreParser::new();
+    let mut written = Vec::new();
+    parser.write(&parser.parse_bytes(buf).unwrap(), &mut written).unwrap();
+    written
+}
+
+#[test]
+fn round_trips_synthetic_fixtures() {
+    let fixtures: [&[u8]; 4] = [
+        include_bytes!("fixtures/descriptors.DS_Store"),
+        include_bytes!("fixtures/empty.DS_Store"),
+        include_bytes!("fixtures/icon_view.DS_Store"),
+        include_bytes!("fixtures/list_view.DS_Store"),
+    ];
+    let parser = DsStoreParser::new();
+
+    for buf in fixtures {
+        let written = round_trip(buf);
+        let (reparsed, report) = parser.parse_bytes_with_report(&written).unwrap();
+
+        assert_eq!(records(&reparsed), records(&parser.parse_bytes(buf).unwrap()));
+        assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+        assert_eq!(round_trip(&written), written);
+    }
+}
+
+#[test]
+fn writes_every_value_type() {
+    let buf = Builder::new()
+        .record(rec("a", b"vSrn", Data::Long(-1)))
+        .record(rec("b", b"fwvh", Data::Shor(-2)))
+        .record(rec("c", b"dscl", Data::Bool(true)))
+        .record(rec("d", b"Iloc", Data::Blob(vec![1, 2, 3])))
+        .record(rec("e", b"vstl", Data::Type(*b"clmv")))
+        .record(rec("f", b"cmmt", Data::Ustr("𝄞 note".into())))
+        .record(rec("g", b"logS", Data::Comp(1 << 40)))
+        .record(rec("h", b"modD", Data::Dutc(3_692_304_000 << 16)))
+        .build();
+    let parser = DsStoreParser::new();
+
+    let reparsed = parser.parse_bytes(&round_trip(&buf)).unwrap();
+
+    assert_eq!(records(&reparsed), records(&parser.parse_bytes(&buf).unwrap()));
+}
+
+#[test]
+fn sorts_records_and_drops_recovered_ones() {
+    let buf = Builder::new()
+        .record(vsrn("b"))
+        .record(rec("A", b"cmmt", Data::Ustr("x".into())))
+        .record(rec("A", b"Iloc", Data::Blob(vec![0; 16])))
+        .stale(vec![vsrn("gone")])
+        .build();
+    let parser = DsStoreParser::new();
+    let mut ds_store = parser.parse_bytes(&buf).unwrap();
+    ds_store.add_records(parser.recover(&buf));
+
+    let mut written = Vec::new();
+    parser.write(&ds_store, &mut written).unwrap();
+
+    let ids = parser
+        .parse_bytes(&written)
+        .unwrap()
+        .iter()
+        .map(|(path, r)| format!("{} {}", path, r.structure_id_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["A Iloc", "A cmmt", "b vSrn"]);
+    assert!(parser.recover(&written).is_empty());
+}
+
+#[test]
+fn rejects_records_larger_than_one_node() {
+    let buf = Builder::new()
+        .records((0..300).map(|i| vsrn(&format!("file {:03}", i))))
+        .per_node(50)
+        .build();
+    let parser = DsStoreParser::new();
+
+    let mut written = Vec::new();
+    assert!(matches!(
+        parser.write(&parser.parse_bytes(&buf).unwrap(), &mut written),
+        Err(DsStoreError::TooLargeToWrite { max: 0x1000, .. })
+    ));
+}
+
+#[test]
+fn rejects_values_that_were_not_decoded() {
+    let buf = Builder::new()
+        .record(rec("a.txt", b"cmmt", Data::Ustr("note".into())))
+        .build();
+    let parser = DsStoreParser::new().with_decode(Decode::Names);
+
+    let mut written = Vec::new();
+    assert!(matches!(
+        parser.write(&parser.parse_bytes(&buf).unwrap(), &mut written),
+        Err(DsStoreError::NotDecoded { structure_id: [b'c', b'm', b'm', b't'], .. })
+    ));
+}
+
+#[test]
+fn writes_redacted_copy() {
+    // Aliases in pict records hold the volume name and the picture's path.
+    let alias = b"\0\0\0\0\x01\x2aVault\0/Volumes/Vault/Users/alice/beach.png".to_vec();
+    let buf = Builder::new()
+        .record(rec(".", b"pict", Data::Blob(alias)))
+        .record(vsrn("."))
+        .record(rec("secret.pdf", b"Iloc", Data::Blob(vec![0; 16])))
+        .record(rec("secret.pdf", b"cmmt", Data::Ustr("note about plans".into())))
+        .record(rec("secret.pdf", b"ptbL", Data::Ustr("Users/alice/Desktop/".into())))
+        .record(rec("secret.pdf", b"ptbN", Data::Ustr("secret.pdf".into())))
+        .record(rec("secret.pdf", b"zZz9", Data::Ustr("secret.pdf".into())))
+        .record(vsrn("plans"))
+        .build();
+    let parser = DsStoreParser::new();
+    let mut ds_store = parser.parse_bytes(&buf).unwrap();
+
+    ds_store.redact_names();
+    let mut written = Vec::new();
+    parser.write(&ds_store, &mut written).unwrap();
+    let redacted = records(&parser.parse_bytes(&written).unwrap());
+
+    let ids = redacted.iter().map(|(_, id, _)| id.as_str()).collect::<Vec<_>>();
+    assert_eq!(ids.len(), 6);
+    assert!(ids.contains(&"cmmt") && ids.contains(&"Iloc") && ids.contains(&"ptbN"));
+    assert!(!ids.contains(&"zZz9") && !ids.contains(&"pict"));
+
+    let names = redacted.iter().map(|(name, _, _)| name.as_str()).collect::<Vec<_>>();
+    assert!(names.contains(&"."));
+    assert_eq!(names.iter().collect::<std::collections::HashSet<_>>().len(), 3);
+
+    // The put-back name hashes to the same value as the entry name.
+    let put_back = redacted.iter().find(|(_, id, _)| id == "ptbN").unwrap();
+    assert_eq!(put_back.2, Value::Ustr(put_back.0.clone()));
+
+    let utf16 = |name: &str| name.encode_utf16().flat_map(u16::to_be_bytes).collect::<Vec<_>>();
+    for name in ["secret", "plans", "alice", "note"] {
+        assert!(!written.windows(utf16(name).len()).any(|w| w == utf16(name)), "{}", name);
+    }
+    for text in ["Vault", "/Users/"] {
+        assert!(!written.windows(text.len()).any(|w| w == text.as_bytes()), "{}", text);
+    }
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "zip")]
+
+use ds_store_parser::{DsStoreError, DsStoreParser, ZipArchive};
+
+// Written with Python's zipfile: a deflated and a stored `.DS_Store`, a
+// deflated text file, a look-alike name and an archive comment.
+const SAMPLE: &[u8] = include_bytes!("fixtures/sample.zip");
+
+#[test]
+fn lists_entries() {
+    let archive = ZipArchive::parse(SAMPLE).unwrap();
+
+    let entries = archive
+        .entries()
+        .iter()
+        .map(|e| (e.name.as_str(), e.method, e.is_ds_store()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        entries,
+        vec![
+            ("bundle/.DS_Store", 8, true),
+            ("bundle/readme.txt", 8, false),
+            ("bundle/notes.DS_Store.bak", 0, false),
+            ("bundle/sub/.DS_Store", 0, true),
+        ]
+    );
+}
+
+#[test]
+fn parses_stored_and_deflated_entries() {
+    let archive = ZipArchive::parse(SAMPLE).unwrap();
+    let parser = DsStoreParser::new();
+
+    let paths = archive
+        .entries()
+        .iter()
+        .filter(|e| e.is_ds_store())
+        .map(|e| parser.parse_bytes(&archive.read(e).unwrap()).unwrap().paths())
+        .collect::<Vec<_>>();
+
+    assert_eq!(paths.len(), 2);
+    assert_eq!(paths[0], vec![".", "a, \"b\".txt", "dir", "z.bin"]);
+    assert_eq!(paths[0], paths[1]);
+
+    assert_eq!(archive.read(&archive.entries()[1]).unwrap(), b"hi\n");
+}
+
+#[test]
+fn rejects_corrupted_entry() {
+    let mut buf = SAMPLE.to_vec();
+    // Inside the deflated data of the first entry, after its 46 byte header.
+    buf[100] ^= 0xff;
+
+    let archive = ZipArchive::parse(&buf).unwrap();
+
+    assert!(matches!(
+        archive.read(&archive.entries()[0]),
+        Err(DsStoreError::InvalidZip { .. })
+    ));
+}
+
+#[test]
+fn rejects_truncated_archive() {
+    for length in [0, 21, SAMPLE.len() / 2, SAMPLE.len() - 30] {
+        assert!(
+            matches!(
+                ZipArchive::parse(&SAMPLE[..length]),
+                Err(DsStoreError::InvalidZip { .. })
+            ),
+            "length {}",
+            length
+        );
+    }
+}